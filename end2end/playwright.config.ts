@@ -36,7 +36,9 @@ export default defineConfig({
     /* Maximum time each action such as `click()` can take. Defaults to 0 (no limit). */
     actionTimeout: 0,
     /* Base URL to use in actions like `await page.goto('/')`. */
-    // baseURL: 'http://localhost:3000',
+    baseURL: "http://localhost:3000",
+    /* The ui language is taken from the browser */
+    locale: "en-US",
 
     /* Collect trace when retrying the failed test. See https://playwright.dev/docs/trace-viewer */
     trace: "on-first-retry",
@@ -97,9 +99,12 @@ export default defineConfig({
   /* Folder for test artifacts such as screenshots, videos, traces, etc. */
   // outputDir: 'test-results/',
 
-  /* Run your local dev server before starting the tests */
-  // webServer: {
-  //   command: 'npm run start',
-  //   port: 3000,
-  // },
+  /* Builds and starts the server on a fresh database with config_example.yaml */
+  webServer: {
+    command: "sh ../scripts/e2e-server.sh",
+    url: "http://localhost:3000",
+    reuseExistingServer: !process.env.CI,
+    /* the first build takes a while */
+    timeout: 15 * 60 * 1000,
+  },
 });
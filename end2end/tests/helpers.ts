@@ -0,0 +1,79 @@
+import { expect, Locator, Page } from "@playwright/test";
+
+/** All tests share one database, so every test creates its own users and articles */
+export function uniqueName(prefix: string): string {
+  return `${prefix}${Date.now().toString(36)}${Math.floor(Math.random() * 1000)}`;
+}
+
+export function uniqueBarcode(): string {
+  return `${Date.now()}${Math.floor(Math.random() * 1000)}`;
+}
+
+/** Creates a user on the home page and returns its id */
+export async function createUser(page: Page, nickname: string): Promise<number> {
+  await page.goto("/");
+
+  await page.getByRole("button", { name: "+", exact: true }).click();
+  await page.locator('input[name="username"]').fill(nickname);
+  await page.getByRole("button", { name: "Create User" }).click();
+
+  await page.waitForURL(/\/user\/\d+$/);
+
+  return Number(new URL(page.url()).pathname.split("/").pop());
+}
+
+/** Creates an article with a barcode and returns its id */
+export async function createArticle(
+  page: Page,
+  name: string,
+  cost: string,
+  barcode: string,
+): Promise<number> {
+  await page.goto("/articles/create");
+
+  await page.getByLabel("Name").fill(name);
+  await page.getByLabel("Cost").fill(cost);
+  await page.getByRole("button", { name: "Create Article" }).click();
+
+  await page.waitForURL(/\/articles\/\d+$/);
+  const articleId = Number(new URL(page.url()).pathname.split("/").pop());
+
+  const addBarcode = page.getByRole("button", { name: "Add Barcode" });
+  await addBarcode.locator("xpath=../preceding-sibling::input").fill(barcode);
+  await addBarcode.click();
+  await expect(page.getByText(barcode, { exact: true })).toBeVisible();
+
+  const updated = page.waitForResponse((response) => response.url().includes("/api/update_article"));
+  await page.getByRole("button", { name: "Update article" }).click();
+  await updated;
+
+  return articleId;
+}
+
+/** The balance shown below the nickname on the user page */
+export function balance(page: Page): Locator {
+  return page.locator("p.text-center.text-\\[2em\\]").nth(1);
+}
+
+export async function openUser(page: Page, userId: number) {
+  await page.goto(`/user/${userId}`);
+  await expect(balance(page)).toBeVisible();
+}
+
+/** Uses one of the quick-amount buttons, e.g. "+5.00€" or "-0.50€" */
+export async function changeMoney(page: Page, userId: number, button: string) {
+  await openUser(page, userId);
+  await page.getByRole("button", { name: button, exact: true }).click();
+}
+
+/** Types the code like a barcode scanner does: one keydown per character, followed by Enter */
+export async function scan(page: Page, code: string) {
+  // the scanner is ignored while an input has the focus
+  await page.evaluate(() => (document.activeElement as HTMLElement | null)?.blur());
+
+  for (const key of code) {
+    await page.keyboard.press(key);
+  }
+
+  await page.keyboard.press("Enter");
+}
@@ -0,0 +1,46 @@
+import { test, expect } from "@playwright/test";
+import { createUser, uniqueName } from "./helpers";
+
+test("homepage has title and navigation", async ({ page }) => {
+  await page.goto("/");
+
+  await expect(page).toHaveTitle("Strichliste-rs");
+
+  await expect(page.locator('a[href="/"]').first()).toHaveText("Strichliste");
+  await expect(page.locator('a[href="/articles"]')).toBeVisible();
+});
+
+test("creating a user opens its page", async ({ page }) => {
+  const nickname = uniqueName("alice");
+  const userId = await createUser(page, nickname);
+
+  await expect(page.getByText(nickname, { exact: true })).toBeVisible();
+  await expect(page.getByRole("button", { name: "+5.00€", exact: true })).toBeVisible();
+
+  await page.goto("/");
+  await expect(page.locator(`a[href="/user/${userId}"]`)).toBeVisible();
+});
+
+test("the alphabet filters users by their first letter", async ({ page }) => {
+  const zora = uniqueName("zora");
+  const quinn = uniqueName("quinn");
+  const digit = uniqueName("7even");
+
+  for (const nickname of [zora, quinn, digit]) {
+    await createUser(page, nickname);
+  }
+
+  await page.goto("/");
+  await page.getByRole("link", { name: "Z", exact: true }).click();
+  await expect(page).toHaveURL(/\?filter=z$/);
+
+  await expect(page.getByText(zora, { exact: true })).toBeVisible();
+  await expect(page.getByText(quinn, { exact: true })).toBeHidden();
+  await expect(page.getByText(digit, { exact: true })).toBeHidden();
+
+  await page.getByRole("link", { name: "#", exact: true }).click();
+  await expect(page).toHaveURL(/\?filter=!$/);
+
+  await expect(page.getByText(digit, { exact: true })).toBeVisible();
+  await expect(page.getByText(zora, { exact: true })).toBeHidden();
+});
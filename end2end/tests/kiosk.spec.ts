@@ -0,0 +1,59 @@
+import { test, expect } from "@playwright/test";
+import {
+  balance,
+  changeMoney,
+  createArticle,
+  createUser,
+  openUser,
+  scan,
+  uniqueBarcode,
+  uniqueName,
+} from "./helpers";
+
+test("deposits and withdrawals change the balance", async ({ page }) => {
+  const userId = await createUser(page, uniqueName("dora"));
+
+  await changeMoney(page, userId, "+5.00€");
+  await expect(balance(page)).toHaveText("+5.00€");
+
+  await page.getByRole("button", { name: "-0.50€", exact: true }).click();
+  await expect(balance(page)).toHaveText("+4.50€");
+
+  // the balance is stored, not just shown
+  await openUser(page, userId);
+  await expect(balance(page)).toHaveText("+4.50€");
+});
+
+test("scanning a barcode buys the article", async ({ page }) => {
+  const name = uniqueName("Mate");
+  const barcode = uniqueBarcode();
+  await createArticle(page, name, "1.50", barcode);
+
+  const userId = await createUser(page, uniqueName("sam"));
+  await changeMoney(page, userId, "+5.00€");
+  await expect(balance(page)).toHaveText("+5.00€");
+
+  await scan(page, barcode);
+
+  await expect(balance(page)).toHaveText("+3.50€");
+  await expect(page.getByText(name).first()).toBeVisible();
+});
+
+test("undo reverts a purchase", async ({ page }) => {
+  const barcode = uniqueBarcode();
+  await createArticle(page, uniqueName("Club"), "2.00", barcode);
+
+  const userId = await createUser(page, uniqueName("uma"));
+  await changeMoney(page, userId, "+5.00€");
+  await expect(balance(page)).toHaveText("+5.00€");
+
+  await scan(page, barcode);
+  await expect(balance(page)).toHaveText("+3.00€");
+
+  // the newest transaction is on top
+  await page.getByRole("button", { name: "Undo" }).first().click();
+  await expect(balance(page)).toHaveText("+5.00€");
+
+  await openUser(page, userId);
+  await expect(balance(page)).toHaveText("+5.00€");
+});
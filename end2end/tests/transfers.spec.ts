@@ -0,0 +1,61 @@
+import { test, expect } from "@playwright/test";
+import { balance, changeMoney, createUser, openUser, uniqueName } from "./helpers";
+
+test("money can be sent to another user", async ({ page }) => {
+  const sender = await createUser(page, uniqueName("sid"));
+  const receiverName = uniqueName("rita");
+  const receiver = await createUser(page, receiverName);
+
+  await changeMoney(page, sender, "+5.00€");
+  await expect(balance(page)).toHaveText("+5.00€");
+
+  await page.goto(`/user/${sender}/send_money`);
+  await page.getByPlaceholder("Receiving User").fill(receiverName);
+  await page.getByLabel("Amount").fill("2");
+  await page.getByRole("button", { name: "Send money" }).click();
+
+  await page.waitForURL(`/user/${sender}`);
+  await expect(balance(page)).toHaveText("+3.00€");
+
+  await openUser(page, receiver);
+  await expect(balance(page)).toHaveText("+2.00€");
+});
+
+test("costs can be split between users", async ({ page }) => {
+  const payerName = uniqueName("paula");
+  const firstName = uniqueName("finn");
+  const secondName = uniqueName("sven");
+
+  const payer = await createUser(page, payerName);
+  const first = await createUser(page, firstName);
+  const second = await createUser(page, secondName);
+
+  // the example config doesn't allow negative balances
+  for (const userId of [first, second]) {
+    await changeMoney(page, userId, "+5.00€");
+    await expect(balance(page)).toHaveText("+5.00€");
+  }
+
+  await page.goto("/split_cost");
+  await page.getByLabel("Who are you?").fill(payerName);
+
+  const others = page.getByLabel("Who do you want to split the cost with?");
+  for (const nickname of [firstName, secondName]) {
+    await others.fill(nickname);
+    await page.getByRole("button", { name: "Add User" }).click();
+  }
+
+  await page.getByLabel("How much?").fill("3");
+
+  const split = page.waitForResponse((response) => response.url().includes("/api/split_cost"));
+  await page.getByRole("button", { name: "Split cost" }).click();
+  await split;
+
+  await openUser(page, payer);
+  await expect(balance(page)).toHaveText("+2.00€");
+
+  for (const userId of [first, second]) {
+    await openUser(page, userId);
+    await expect(balance(page)).toHaveText("+4.00€");
+  }
+});
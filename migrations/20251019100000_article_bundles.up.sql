@@ -0,0 +1,29 @@
+-- Add up migration script here
+create table ArticleBundleComponents (
+  bundle_id integer not null,
+  article_id integer not null,
+  quantity integer not null,
+  primary key (bundle_id, article_id),
+  foreign key (bundle_id) references Articles (id),
+  foreign key (article_id) references Articles (id)
+);
+
+create table TransactionArticleMap (
+  transaction_id integer not null,
+  article_id integer not null,
+  amount integer not null,
+  primary key (transaction_id, article_id),
+  foreign key (transaction_id) references Transactions (id),
+  foreign key (article_id) references Articles (id)
+);
+
+insert into
+  TransactionArticleMap (transaction_id, article_id, amount)
+select
+  id,
+  t_type_data,
+  1
+from
+  Transactions
+where
+  t_type_data is not null;
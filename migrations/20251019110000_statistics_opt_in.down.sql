@@ -0,0 +1,3 @@
+-- Add down migration script here
+alter table Users
+drop column show_in_stats;
@@ -0,0 +1,3 @@
+-- Add up migration script here
+alter table Users
+add column show_in_stats boolean not null default false;
@@ -0,0 +1,17 @@
+-- Add up migration script here
+create table UserEmailMap (
+  user_id integer not null,
+  email text not null,
+  reminders_opt_out boolean not null default false,
+  opt_out_token text not null unique,
+  primary key (user_id),
+  foreign key (user_id) references Users (id)
+);
+
+create table DebtReminders (
+  user_id integer not null,
+  below_threshold_since date,
+  last_sent date,
+  primary key (user_id),
+  foreign key (user_id) references Users (id)
+);
@@ -0,0 +1,15 @@
+-- Add up migration script here
+create table WebhookDeliveries (
+  id integer not null,
+  url text not null,
+  event text not null,
+  payload text not null,
+  -- pending, delivered or failed
+  status text not null default 'pending',
+  attempts integer not null default 0,
+  response_status integer,
+  last_error text,
+  created_at date not null,
+  next_attempt date not null,
+  primary key (id)
+);
@@ -0,0 +1,6 @@
+-- Add up migration script here
+alter table Users
+add column lower_limit integer;
+
+alter table Users
+add column upper_limit integer;
@@ -0,0 +1,6 @@
+-- Add down migration script here
+drop table ArticleSounds;
+
+drop table SoundEvents;
+
+drop table Sounds;
@@ -0,0 +1,23 @@
+-- Add up migration script here
+create table Sounds (
+  -- ids are never reused, the kiosks cache sounds by their id
+  id integer primary key autoincrement not null,
+  name text not null,
+  data blob not null
+);
+
+create table SoundEvents (
+  sound_id integer not null,
+  -- generic, failed, refused, deposit or withdraw
+  event text not null,
+  primary key (sound_id, event),
+  foreign key (sound_id) references Sounds (id)
+);
+
+create table ArticleSounds (
+  sound_id integer not null,
+  article_id integer not null,
+  primary key (sound_id, article_id),
+  foreign key (sound_id) references Sounds (id),
+  foreign key (article_id) references Articles (id)
+);
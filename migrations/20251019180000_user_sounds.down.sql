@@ -0,0 +1,6 @@
+-- Add down migration script here
+alter table Users
+drop column deposit_sound_id;
+
+alter table Users
+drop column purchase_sound_id;
@@ -0,0 +1,6 @@
+-- Add up migration script here
+alter table Users
+add column purchase_sound_id integer references Sounds (id);
+
+alter table Users
+add column deposit_sound_id integer references Sounds (id);
@@ -0,0 +1,3 @@
+-- Add up migration script here
+alter table Transactions
+add column undone_at timestamp;
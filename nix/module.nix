@@ -60,8 +60,9 @@ in
     settings = mkSubmoduleOption {
       accounts = mkSubmoduleOption {
         upper_limit = mkOption {
-          type = types.ints.unsigned;
-          description = "The upper account limit in cents. If set to 0, it will be disabled.";
+          type = types.nullOr types.ints.positive;
+          default = null;
+          description = "The upper account limit in cents. If set to null, there is no upper limit.";
         };
 
         lower_limit = mkOption {
@@ -70,6 +71,320 @@ in
           };
           description = "The lower account limit in cents. Must be <= 0.";
         };
+
+        low_balance_threshold = mkOption {
+          type = types.int;
+          default = 0;
+          description = "A low_balance event is sent when a balance drops below this amount of cents";
+        };
+      };
+
+      smtp = mkOption {
+        description = "The smtp server used to send debt reminders";
+        default = null;
+        type = types.nullOr (
+          types.submodule {
+            options = {
+              host = mkOption { type = types.str; };
+              port = mkOption {
+                type = types.nullOr types.port;
+                default = null;
+                description = "Defaults to the standard port of the encryption";
+              };
+              encryption = mkOption {
+                type = types.enum [
+                  "none"
+                  "starttls"
+                  "tls"
+                ];
+                default = "starttls";
+              };
+              from = mkOption {
+                type = types.str;
+                example = "Strichliste <strichliste@example.org>";
+              };
+              username = mkOption {
+                type = types.nullOr types.str;
+                default = null;
+              };
+              password = mkOption {
+                type = types.nullOr types.str;
+                default = null;
+                description = "Warning: this will end up in the nix store";
+              };
+            };
+          }
+        );
+      };
+
+      reminders = mkOption {
+        description = "Send debt reminders via email. Requires smtp to be set.";
+        default = null;
+        type = types.nullOr (
+          types.submodule {
+            options = {
+              threshold = mkOption {
+                type = types.int;
+                description = "Reminders are sent if the balance is below this amount of cents";
+              };
+              days = mkOption {
+                type = types.ints.unsigned;
+                description = "How many days the balance has to stay below the threshold";
+              };
+              repeat_days = mkOption {
+                type = types.ints.unsigned;
+                default = 7;
+                description = "The minimum amount of days between two reminders to the same user";
+              };
+              max_per_run = mkOption {
+                type = types.ints.unsigned;
+                default = 20;
+                description = "The maximum amount of reminders sent per check";
+              };
+              check_interval_minutes = mkOption {
+                type = types.ints.positive;
+                default = 60;
+              };
+              base_url = mkOption {
+                type = types.str;
+                description = "The public url of the instance, used for the opt-out link";
+                example = "https://strichliste.example.org";
+              };
+            };
+          }
+        );
+      };
+
+      webhooks = mkOption {
+        description = "Webhooks that receive events as signed json";
+        default = [ ];
+        type = types.listOf (
+          types.submodule {
+            options = {
+              url = mkOption { type = types.str; };
+              secret = mkOption {
+                type = types.str;
+                description = "Used to sign the payload with hmac-sha256. Warning: this will end up in the nix store";
+              };
+              events = mkOption {
+                type = types.listOf (
+                  types.enum [
+                    "purchase"
+                    "deposit"
+                    "withdraw"
+                    "transfer"
+                    "undo"
+                    "user_created"
+                    "low_balance"
+                  ]
+                );
+              };
+            };
+          }
+        );
+      };
+
+      locale = mkOption {
+        description = "Forces the language of the ui, otherwise it is taken from the browser";
+        default = null;
+        type = types.nullOr (
+          types.enum [
+            "en"
+            "de"
+          ]
+        );
+      };
+
+      currency = mkSubmoduleOption {
+        symbol = mkOption {
+          type = types.str;
+          default = "€";
+        };
+        symbol_position = mkOption {
+          type = types.enum [
+            "before"
+            "after"
+          ];
+          default = "after";
+        };
+        symbol_spacing = mkOption {
+          type = types.bool;
+          default = false;
+          description = "Put a space between the symbol and the amount";
+        };
+        decimal_separator = mkOption {
+          type = types.str;
+          default = ".";
+        };
+        thousands_separator = mkOption {
+          type = types.str;
+          default = "";
+          description = "Groups the amount by thousands, disabled if empty";
+        };
+        minor_digits = mkOption {
+          type = types.ints.between 0 4;
+          default = 2;
+          description = "The digits after the decimal separator. All amounts are configured in these minor units";
+        };
+      };
+
+      kiosk = mkSubmoduleOption {
+        deposit_buttons = mkOption {
+          type = types.listOf types.ints.positive;
+          default = [ 50 100 200 500 1000 2000 5000 ];
+          description = "The amounts of the quick deposit buttons in minor units";
+        };
+        withdraw_buttons = mkOption {
+          type = types.listOf types.ints.positive;
+          default = [ 50 100 200 500 1000 2000 5000 ];
+          description = "The amounts of the quick withdraw buttons in minor units";
+        };
+        return_to_main_view_timeout_sec = mkOption {
+          type = types.ints.positive;
+          default = 15;
+          description = "The user view returns to the user list after this many seconds of inactivity";
+        };
+        prefix_filter_clear_timeout_sec = mkOption {
+          type = types.ints.positive;
+          default = 15;
+          description = "The prefix filter of the user list is cleared after this many seconds of inactivity";
+        };
+        input_clear_timeout_sec = mkOption {
+          type = types.ints.positive;
+          default = 30;
+          description = "Scanned input is discarded if nothing was typed for this many seconds";
+        };
+      };
+
+      mqtt = mkOption {
+        description = "Publish events to an mqtt broker";
+        default = null;
+        type = types.nullOr (
+          types.submodule {
+            options = {
+              host = mkOption { type = types.str; };
+              port = mkOption {
+                type = types.port;
+                default = 1883;
+              };
+              client_id = mkOption {
+                type = types.str;
+                default = "strichliste-rs";
+              };
+              username = mkOption {
+                type = types.nullOr types.str;
+                default = null;
+              };
+              password = mkOption {
+                type = types.nullOr types.str;
+                default = null;
+                description = "Warning: this will end up in the nix store";
+              };
+              topics = mkSubmoduleOption {
+                purchase = mkOption {
+                  type = types.str;
+                  default = "strichliste/purchase";
+                };
+                deposit = mkOption {
+                  type = types.str;
+                  default = "strichliste/deposit";
+                };
+                undo = mkOption {
+                  type = types.str;
+                  default = "strichliste/undo";
+                };
+                sales = mkOption {
+                  type = types.str;
+                  default = "strichliste/sales";
+                  description = "The sales of every article are published under <sales>/<article id>";
+                };
+                command = mkOption {
+                  type = types.nullOr types.str;
+                  default = null;
+                  description = "Purchases requested on this topic are executed";
+                  example = "strichliste/command/buy";
+                };
+              };
+            };
+          }
+        );
+      };
+
+      backups = mkOption {
+        description = "Periodically write a consistent copy of the database into a directory";
+        default = null;
+        type = types.nullOr (
+          types.submodule {
+            options = {
+              directory = mkOption {
+                type = types.str;
+                default = "${cfg.dataDir}/backups";
+                description = "Should be on a different device than the database. Has to be writable by the service";
+              };
+              interval_minutes = mkOption {
+                type = types.ints.positive;
+                default = 360;
+              };
+              keep_last = mkOption {
+                type = types.ints.positive;
+                default = 7;
+                description = "The amount of newest backups that are always kept";
+              };
+              keep_daily = mkOption {
+                type = types.ints.unsigned;
+                default = 0;
+                description = "Additionally keep the newest backup of each of the last days that have one";
+              };
+              gzip = mkOption {
+                type = types.bool;
+                default = false;
+              };
+            };
+          }
+        );
+      };
+
+      speech = mkOption {
+        description = "Announce transactions with text-to-speech";
+        default = null;
+        type = types.nullOr (
+          types.submodule {
+            options = {
+              engine = mkOption {
+                type = types.enum [
+                  "browser"
+                  "server"
+                ];
+                default = "browser";
+                description = "Speak with the speech synthesis of the browser or render the speech with command on the server";
+              };
+              always = mkOption {
+                type = types.bool;
+                default = false;
+                description = "Speak instead of the sounds, otherwise only if no sound fits or it can't be fetched";
+              };
+              command = mkOption {
+                type = types.str;
+                default = "${pkgs.espeak-ng}/bin/espeak-ng";
+                description = "Gets the text on stdin and writes a wav file to stdout";
+              };
+              args = mkOption {
+                type = types.listOf types.str;
+                default = [ "--stdout" ];
+                example = [ "--stdout" "-v" "de" ];
+              };
+              templates = mkOption {
+                type = types.attrsOf types.str;
+                default = { };
+                description = "What is said per playback (bought, deposit, withdraw, sent, undo, failed, refused). {user}, {article}, {amount} and {balance} are replaced";
+                example = {
+                  bought = "{user} bought {article}, balance {balance}";
+                  failed = "Sorry {user}, that did not work";
+                };
+              };
+            };
+          }
+        );
       };
 
       sounds = mkSubmoduleOption {
@@ -15,6 +15,7 @@ let
       ../public
       ../migrations
       ../.sqlx
+      ../tests
     ];
   };
 
@@ -135,6 +136,14 @@ let
     }
   );
 
+  testBackend = craneLib.cargoTest (
+    commonArgs
+    // {
+      cargoArtifacts = backendArtifacts;
+      cargoTestExtraArgs = "-F ssr";
+    }
+  );
+
   migrate = pkgs.stdenv.mkDerivation {
     name = "migrate";
     propagatedBuildInputs = with pkgs; [ python3 ];
@@ -145,5 +154,5 @@ in
 {
   packages.default = package;
   packages.migrate = migrate;
-  checks = { inherit clippyFrontend clippybackend; };
+  checks = { inherit clippyFrontend clippybackend testBackend; };
 }
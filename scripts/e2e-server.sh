@@ -0,0 +1,13 @@
+#!/usr/bin/env sh
+# Starts the server on a fresh database for the end 2 end tests, see end2end/playwright.config.ts
+cd "$(dirname "$0")" || exit
+cd ..
+
+export SQLX_OFFLINE="${SQLX_OFFLINE:-true}"
+
+tmp_dir="$(mktemp -d)"
+trap 'rm -rf "$tmp_dir"' EXIT INT TERM
+
+cargo leptos build || exit 1
+./target/debug/strichliste-rs -d "$tmp_dir/db.sqlite" -c ./config_example.yaml --create || exit 1
+cargo leptos serve -- -d "$tmp_dir/db.sqlite" -c ./config_example.yaml
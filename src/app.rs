@@ -9,22 +9,37 @@ use leptos_router::{
 
 use thaw::{ssr::SSRMountStyleProvider, ConfigProvider, Theme, ToasterProvider};
 
-use crate::frontend::{
-    component::{self, error_popup::ErrorDisplay, error_soft::ErrorSoftDisplay},
-    model::{
-        caching_layer::CachingLayer,
-        frontend_store::FrontendStore,
-        scaninput_manager::ScanInputManager,
-        throw_error::{ThrowError, THROW_ERROR_HARD, THROW_ERROR_SOFT},
+use crate::{
+    backend::core::behaviour::kiosk_settings_get::get_kiosk_settings,
+    frontend::{
+        component::{self, error_popup::ErrorDisplay, error_soft::ErrorSoftDisplay},
+        model::{
+            caching_layer::CachingLayer,
+            frontend_store::FrontendStore,
+            kiosk_settings_resource::KioskSettingsResource,
+            scaninput_manager::ScanInputManager,
+            throw_error::{ThrowError, THROW_ERROR_HARD, THROW_ERROR_SOFT},
+        },
+        route::{self},
+        shared::{current_currency, current_locale, throw_error, use_texts},
     },
-    route::{self},
 };
 
 pub fn shell(options: LeptosOptions) -> impl IntoView {
+    let currency = current_currency();
+
     view! {
         <SSRMountStyleProvider>
             <!DOCTYPE html>
-            <html lang="en">
+            <html
+                lang=current_locale().code()
+                data-currency-symbol=currency.symbol
+                data-currency-symbol-position=currency.symbol_position.code()
+                data-currency-symbol-spacing=currency.symbol_spacing.to_string()
+                data-currency-decimal-separator=currency.decimal_separator
+                data-currency-thousands-separator=currency.thousands_separator
+                data-currency-minor-digits=currency.minor_digits.to_string()
+            >
                 <head>
                     <meta charset="utf-8" />
                     <meta name="viewport" content="width=device-width, initial-scale=1" />
@@ -48,17 +63,23 @@ pub fn App() -> impl IntoView {
 
     use reactive_stores::Store;
     let audio_ref = NodeRef::<leptos::html::Audio>::new();
+    let cachinglayer = RwSignal::new(CachingLayer::default());
     let store = Store::new(FrontendStore {
         cached_sounds: Default::default(),
+        speech_url: None,
         audio_ref,
-        cachinglayer: RwSignal::new(CachingLayer::default()),
+        cachinglayer,
     });
     let soft_error = Store::new(ThrowError::<THROW_ERROR_SOFT>::default());
     let hard_error = Store::new(ThrowError::<THROW_ERROR_HARD>::default());
+    CachingLayer::subscribe_to_events(cachinglayer);
     provide_context(store);
     provide_context(soft_error);
     provide_context(hard_error);
 
+    let kiosk_settings: KioskSettingsResource = OnceResource::new(get_kiosk_settings());
+    provide_context(kiosk_settings);
+
     let colors = RwSignal::new(HashMap::from([
         (10, "#010304"),
         (20, "#0F181E"),
@@ -85,6 +106,14 @@ pub fn App() -> impl IntoView {
 
     let scaninput_manager = Store::new(ScanInputManager::default());
 
+    let texts = use_texts();
+
+    Effect::new(move || {
+        if let Some(Err(e)) = kiosk_settings.get() {
+            throw_error((texts.failed_to_fetch_kiosk_settings)(&e.to_string()));
+        }
+    });
+
     view! {
         // injects a stylesheet into the document <head>
         // id=leptos means cargo-leptos will hot-reload this stylesheet
@@ -106,14 +135,19 @@ pub fn App() -> impl IntoView {
                         ScanInputManager::setup(scaninput_manager);
                         provide_context(scaninput_manager);
                     }
-                    <Routes fallback=|| {
+                    <Routes fallback=move || {
                         view! {
-                            <h1 class="text-white text-center bg-red-400">"Page not found!"</h1>
+                            <h1 class="text-white text-center bg-red-400">{texts.page_not_found}</h1>
                         }
                     }>
                         <Route path=path!("/") view=route::home::View />
                         <Route path=path!("/user/:id") view=route::user::ShowUser />
                         <Route path=path!("/user/:id/settings") view=route::user::settings::Show />
+                        <Route path=path!("/user/:id/stats") view=route::user::stats::Show />
+                        <Route
+                            path=path!("/user/:id/statements")
+                            view=route::user::statements::Show
+                        />
                         <Route
                             path=path!("/user/:id/send_money")
                             view=route::user::send_money::Show
@@ -125,7 +159,9 @@ pub fn App() -> impl IntoView {
                         />
                         <Route path=path!("/articles/:article_id") view=route::articles::Edit />
 
+                        <Route path=path!("/sounds") view=route::sounds::Show />
                         <Route path=path!("/split_cost") view=route::split_cost::Show />
+                        <Route path=path!("/stats") view=route::stats::Show />
                     </Routes>
                 </Router>
             </ToasterProvider>
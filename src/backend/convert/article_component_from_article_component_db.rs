@@ -0,0 +1,10 @@
+use crate::backend::{core::ArticleComponent, database::ArticleComponentDB};
+
+impl From<ArticleComponentDB> for ArticleComponent {
+    fn from(value: ArticleComponentDB) -> Self {
+        Self {
+            article_id: value.article_id,
+            quantity: value.quantity,
+        }
+    }
+}
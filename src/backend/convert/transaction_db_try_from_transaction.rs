@@ -2,10 +2,12 @@
 
 use crate::{
     backend::database::{TransactionDB, DBGROUP_AUFLADUNG_ID, DBGROUP_SNACKBAR_ID},
-    model::Transaction,
+    model::{MoneyError, Transaction},
 };
-impl From<Transaction> for TransactionDB {
-    fn from(value: Transaction) -> Self {
+impl TryFrom<Transaction> for TransactionDB {
+    type Error = MoneyError;
+
+    fn try_from(value: Transaction) -> Result<Self, Self::Error> {
         use crate::model::TransactionType;
 
         let Transaction {
@@ -28,7 +30,7 @@ impl From<Transaction> for TransactionDB {
             TransactionType::SentAndReceived(to) => (group_id, to),
         };
 
-        Self {
+        Ok(Self {
             id,
             sender: sender.0,
             receiver: receiver.0,
@@ -41,9 +43,11 @@ impl From<Transaction> for TransactionDB {
                 TransactionType::Bought(var) => Some(var),
                 _ => None,
             },
-            money: money.value as u64,
+            money: money.try_into()?,
             description,
             timestamp,
-        }
+            // the client side transaction doesn't know when it was undone
+            undone_at: None,
+        })
     }
 }
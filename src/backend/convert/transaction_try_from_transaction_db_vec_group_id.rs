@@ -28,6 +28,7 @@ where
                 money,
                 description,
                 timestamp,
+                undone_at: _,
             },
             group_ids,
         ) = self;
@@ -70,7 +71,7 @@ where
                     },
                 }
             },
-            money: money.into(),
+            money: money.try_into().map_err(DBError::new)?,
             description,
             timestamp,
             is_undone_signal: RwSignal::new(is_undone), // might fail on server
@@ -6,15 +6,26 @@ use crate::backend::core::misc::custom_binary_encoding::Binary;
 use leptos::prelude::*;
 
 #[cfg(feature = "ssr")]
-use crate::backend::{
-    core::Barcode,
-    database::{ArticleDB, DatabaseResponse, DB},
+use {
+    crate::backend::{
+        core::Barcode,
+        database::{ArticleDB, DatabaseResponse, DatabaseType, DB},
+    },
+    sqlx::Executor,
 };
 #[cfg(feature = "ssr")]
 impl Article {
     pub async fn get(db: &DB, id: i64) -> DatabaseResponse<Option<Self>> {
         let mut conn = db.get_conn().await?;
 
+        Article::get_single(&mut *conn, id).await
+    }
+
+    /// Like [`Article::get`], but reads through `conn`, e.g. inside an open transaction
+    pub async fn get_single<T>(conn: &mut T, id: i64) -> DatabaseResponse<Option<Self>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
         match ArticleDB::get_single(&mut *conn, id).await? {
             Some(article) => {
                 let article_barcodes = ArticleDB::get_barcodes(&mut *conn, article.id)
@@ -25,6 +36,12 @@ impl Article {
 
                 let cost = ArticleDB::get_latest_cost(&mut *conn, article.id).await?;
 
+                let components = ArticleDB::get_components(&mut *conn, article.id)
+                    .await?
+                    .into_iter()
+                    .map(From::from)
+                    .collect();
+
                 let ArticleDB {
                     id,
                     name,
@@ -36,6 +53,7 @@ impl Article {
                     cost: cost.into(),
                     barcodes: article_barcodes,
                     is_disabled,
+                    components,
                 }))
             }
             None => Ok(None),
@@ -31,6 +31,11 @@ impl Article {
                 .map(|elem| Barcode(elem.barcode_content))
                 .collect();
             let cost = ArticleDB::get_latest_cost(&mut *conn, id).await?;
+            let components = ArticleDB::get_components(&mut *conn, id)
+                .await?
+                .into_iter()
+                .map(From::from)
+                .collect();
 
             article_no_db.push(Article {
                 id,
@@ -38,6 +43,7 @@ impl Article {
                 cost: cost.into(),
                 barcodes: article_barcodes,
                 is_disabled,
+                components,
             });
         }
         Ok(article_no_db)
@@ -26,6 +26,7 @@ impl Article {
 #[server]
 pub async fn create_article(name: String, cost: String) -> Result<(), ServerFnError> {
     use crate::backend::core::ServerState;
+    use crate::model::ServerEvent;
     use axum::http::StatusCode;
     use leptos_axum::redirect;
     use leptos_axum::ResponseOptions;
@@ -45,7 +46,7 @@ pub async fn create_article(name: String, cost: String) -> Result<(), ServerFnEr
         return Err(ServerFnError::new("Cost cannot be empty!"));
     }
 
-    let money: Money = match cost.try_into() {
+    let money: Money = match (cost.as_str(), &state.settings.load().currency).try_into() {
         Ok(value) => value,
         Err(err) => {
             response_opts.set_status(StatusCode::BAD_REQUEST);
@@ -64,6 +65,10 @@ pub async fn create_article(name: String, cost: String) -> Result<(), ServerFnEr
         }
     };
 
+    state.events.publish(ServerEvent::ArticleUpdated {
+        article_id: article.id,
+    });
+
     redirect(&format!("/articles/{}", article.id));
 
     Ok(())
@@ -0,0 +1,33 @@
+#![cfg(feature = "ssr")]
+use sqlx::Executor;
+
+use crate::backend::{
+    core::{Article, ArticleComponent},
+    database::{ArticleDB, DatabaseResponse, DatabaseType},
+};
+
+impl Article {
+    pub async fn set_components<T>(
+        &mut self,
+        conn: &mut T,
+        components: Vec<ArticleComponent>,
+    ) -> DatabaseResponse<()>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        let pairs = components
+            .iter()
+            .map(|elem| (elem.article_id, elem.quantity))
+            .collect::<Vec<(i64, i64)>>();
+
+        ArticleDB::set_components(&mut *conn, self.id, &pairs).await?;
+
+        self.components = ArticleDB::get_components(&mut *conn, self.id)
+            .await?
+            .into_iter()
+            .map(From::from)
+            .collect();
+
+        Ok(())
+    }
+}
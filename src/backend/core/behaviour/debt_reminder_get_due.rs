@@ -0,0 +1,92 @@
+#![cfg(feature = "ssr")]
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::backend::{
+    core::{DebtReminder, ReminderSettings, User},
+    database::{DatabaseResponse, DebtReminderDB, UserEmailDB, DB},
+};
+
+impl DebtReminder {
+    /// Tracks since when the balance of every user is below the threshold
+    /// and returns the reminders that should be sent now.
+    pub async fn get_due(
+        db: &DB,
+        settings: &ReminderSettings,
+        now: DateTime<Utc>,
+    ) -> DatabaseResponse<Vec<Self>> {
+        let users = User::get_all(db).await?;
+
+        let mut conn = db.get_conn().await?;
+
+        let mut emails = UserEmailDB::get_all(&mut *conn)
+            .await?
+            .into_iter()
+            .map(|email| (email.user_id, email))
+            .collect::<HashMap<_, _>>();
+
+        let mut states = DebtReminderDB::get_all(&mut *conn)
+            .await?
+            .into_iter()
+            .map(|state| (state.user_id, state))
+            .collect::<HashMap<_, _>>();
+
+        let mut result = Vec::<Self>::new();
+
+        for user in users.into_iter() {
+            let mut state = states.remove(&user.id.0).unwrap_or(DebtReminderDB {
+                user_id: user.id.0,
+                below_threshold_since: None,
+                last_sent: None,
+            });
+
+            let below_threshold_since = match user.money.value < settings.threshold {
+                true => state.below_threshold_since.or(Some(now)),
+                false => None,
+            };
+
+            if state.below_threshold_since != below_threshold_since {
+                state.below_threshold_since = below_threshold_since;
+                state.set(&mut *conn).await?;
+            }
+
+            let below_threshold_since = match below_threshold_since {
+                None => continue,
+                Some(value) => value,
+            };
+
+            if now - below_threshold_since < Duration::days(settings.days) {
+                continue;
+            }
+
+            if let Some(last_sent) = state.last_sent {
+                if now - last_sent < Duration::days(settings.repeat_days) {
+                    continue;
+                }
+            }
+
+            let email = match emails.remove(&user.id.0) {
+                Some(email) if !email.reminders_opt_out => email,
+                _ => continue,
+            };
+
+            if result.len() >= settings.max_per_run {
+                // the remaining users will be reminded during the next check
+                continue;
+            }
+
+            result.push(DebtReminder {
+                user_id: user.id,
+                nickname: user.nickname,
+                email: email.email,
+                money: user.money,
+                below_threshold_since,
+                opt_out_token: email.opt_out_token,
+            });
+        }
+
+        Ok(result)
+    }
+}
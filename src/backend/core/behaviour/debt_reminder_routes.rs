@@ -0,0 +1,64 @@
+#![cfg(feature = "ssr")]
+
+use axum::{
+    extract::Path,
+    http::StatusCode,
+    response::{Html, IntoResponse, Response},
+    Extension,
+};
+use tracing::{error, info};
+
+use crate::backend::{core::ServerState, database::UserEmailDB};
+
+/// submits to the same url, so the token doesn't have to be embedded
+const CONFIRM_OPT_OUT_PAGE: &str = r#"<!DOCTYPE html>
+<html>
+<body>
+<form method="post">
+<p>Do you want to stop receiving debt reminders?</p>
+<button type="submit">Stop the reminders</button>
+</form>
+</body>
+</html>"#;
+
+/// GET /reminders/opt_out/{token}
+/// Mail scanners open the links of a mail, so opening the link only asks for a confirmation.
+pub async fn confirm_opt_out_of_reminders() -> Html<&'static str> {
+    Html(CONFIRM_OPT_OUT_PAGE)
+}
+
+/// POST /reminders/opt_out/{token}
+pub async fn opt_out_of_reminders(
+    Extension(state): Extension<ServerState>,
+    Path(token): Path<String>,
+) -> Response {
+    let db = state.db.lock().await;
+
+    let mut conn = match db.get_conn().await {
+        Ok(value) => value,
+        Err(e) => {
+            error!("Failed to get database handle: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to get database handle!",
+            )
+                .into_response();
+        }
+    };
+
+    match UserEmailDB::opt_out(&mut *conn, &token).await {
+        Ok(Some(user_id)) => {
+            info!("User {} opted out of debt reminders", user_id);
+            Html("You will not receive any more debt reminders.").into_response()
+        }
+        Ok(None) => (StatusCode::NOT_FOUND, "Invalid opt-out link!").into_response(),
+        Err(e) => {
+            error!("Failed to opt out of reminders: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to opt out of reminders!",
+            )
+                .into_response()
+        }
+    }
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use sqlx::Executor;
+
+use crate::{
+    backend::{
+        core::{misc::Mailer, DebtReminder, ReminderSettings},
+        database::{DatabaseResponse, DatabaseType, DebtReminderDB},
+    },
+    model::Currency,
+};
+
+impl DebtReminder {
+    pub fn subject(&self) -> String {
+        String::from("Reminder: your balance on the Strichliste")
+    }
+
+    pub fn body(&self, settings: &ReminderSettings, currency: &Currency) -> String {
+        format!(
+            "Hi {},\n\n\
+            your balance on the Strichliste is {} and has been below {} since {}.\n\
+            Please top up your account the next time you are around.\n\n\
+            If you don't want to receive these reminders anymore, open this link:\n\
+            {}/reminders/opt_out/{}\n",
+            self.nickname,
+            self.money.format(currency),
+            currency.format(settings.threshold),
+            self.below_threshold_since.format("%Y-%m-%d"),
+            settings.base_url.trim_end_matches('/'),
+            self.opt_out_token,
+        )
+    }
+
+    pub async fn send(
+        &self,
+        mailer: &Mailer,
+        settings: &ReminderSettings,
+        currency: &Currency,
+    ) -> Result<(), String> {
+        mailer
+            .send(&self.email, &self.subject(), self.body(settings, currency))
+            .await
+    }
+
+    pub async fn mark_sent<T>(&self, conn: &mut T, now: DateTime<Utc>) -> DatabaseResponse<()>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        DebtReminderDB {
+            user_id: self.user_id.0,
+            below_threshold_since: Some(self.below_threshold_since),
+            last_sent: Some(now),
+        }
+        .set(&mut *conn)
+        .await
+    }
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "ssr")]
+
+use std::convert::Infallible;
+
+use axum::{
+    response::sse::{Event, KeepAlive, Sse},
+    Extension,
+};
+use futures::{stream, Stream};
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{error, warn};
+
+use crate::backend::core::ServerState;
+
+/// GET /events
+///
+/// Streams every [`crate::model::ServerEvent`] as json. If a client falls behind, the stream is closed,
+/// so the client reconnects and refetches everything.
+pub async fn event_stream(
+    Extension(state): Extension<ServerState>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let receiver = state.events.subscribe();
+
+    let stream = stream::unfold(receiver, |mut receiver| async move {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => match Event::default().json_data(&event) {
+                    Ok(value) => return Some((Ok(value), receiver)),
+                    Err(e) => {
+                        error!("Failed to encode event: {}", e);
+                        continue;
+                    }
+                },
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("Event subscriber lagged behind by {} events", skipped);
+                    return None;
+                }
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    });
+
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
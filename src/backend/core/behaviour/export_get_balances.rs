@@ -0,0 +1,143 @@
+#![cfg(feature = "ssr")]
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use sqlx::Executor;
+
+use crate::{
+    backend::{
+        core::{ExportBalance, ExportTransaction, Group, User},
+        database::{DBError, DatabaseResponse, DatabaseType, TransactionDB, DB},
+    },
+    model::{GroupId, Money, Transaction, UserId},
+};
+
+/// The summed up deltas per user of the transactions that are not undone
+fn later_deltas(transactions: &[ExportTransaction]) -> DatabaseResponse<HashMap<i64, Money>> {
+    let mut deltas = HashMap::<i64, Money>::new();
+
+    for transaction in transactions.iter() {
+        if transaction.is_undone {
+            continue;
+        }
+
+        let delta = deltas.entry(transaction.user_id).or_default();
+        *delta = delta
+            .checked_add(transaction.delta.into())
+            .map_err(DBError::new)?;
+    }
+
+    Ok(deltas)
+}
+
+/// The summed up deltas per user of transactions that were undone since
+async fn undone_deltas<T>(
+    conn: &mut T,
+    transactions: Vec<TransactionDB>,
+) -> DatabaseResponse<HashMap<i64, Money>>
+where
+    for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+{
+    let mut result = HashMap::<i64, Money>::new();
+
+    for transaction in transactions.into_iter() {
+        let sender_group = Group::get(&mut *conn, GroupId(transaction.sender)).await?;
+        let receiver_group = Group::get(&mut *conn, GroupId(transaction.receiver)).await?;
+
+        let deltas = Transaction::get_transaction_delta(
+            &mut *conn,
+            &sender_group,
+            &receiver_group,
+            &transaction,
+        )
+        .await
+        .map_err(DBError::new)?;
+
+        for (user, delta) in deltas.into_iter() {
+            let entry = result.entry(user.id.0).or_default();
+            *entry = entry
+                .checked_add(delta.delta.into())
+                .map_err(DBError::new)?;
+        }
+    }
+
+    Ok(result)
+}
+
+impl ExportBalance {
+    /// Returns the balance of every user at the given point in time.
+    /// The current balance is rolled back by every transaction that happened afterwards, transactions
+    /// that were made before but undone after `at` are booked again. Transactions that were
+    /// undone before the undo time was recorded are treated as if they never happened.
+    pub async fn get_at(db: &DB, at: DateTime<Utc>) -> DatabaseResponse<Vec<Self>> {
+        let undone_deltas = {
+            let mut conn = db.get_conn().await?;
+            let transactions = TransactionDB::get_undone_since(&mut *conn, at).await?;
+
+            undone_deltas(&mut *conn, transactions).await?
+        };
+
+        let users = User::get_all(db).await?;
+
+        let later_transactions =
+            ExportTransaction::get_in_range(db, at, Utc::now() + Duration::days(1)).await?;
+        let later_deltas = later_deltas(&later_transactions)?;
+
+        users
+            .into_iter()
+            .map(|user| ExportBalance::roll_back(user, at, &later_deltas, &undone_deltas))
+            .collect()
+    }
+
+    /// Like [`ExportBalance::get_at`], but only reads the transactions of the given user
+    pub async fn get_user_at(
+        db: &DB,
+        user_id: UserId,
+        at: DateTime<Utc>,
+    ) -> DatabaseResponse<Option<Self>> {
+        let (user, undone_deltas) = {
+            let mut conn = db.get_conn().await?;
+
+            let user = match User::get(&mut *conn, user_id).await? {
+                Some(user) => user,
+                None => return Ok(None),
+            };
+
+            let transactions =
+                TransactionDB::get_user_undone_since(&mut *conn, user_id, at).await?;
+
+            (user, undone_deltas(&mut *conn, transactions).await?)
+        };
+
+        let later_transactions =
+            ExportTransaction::get_user_in_range(db, user_id, at, Utc::now() + Duration::days(1))
+                .await?;
+        let later_deltas = later_deltas(&later_transactions)?;
+
+        ExportBalance::roll_back(user, at, &later_deltas, &undone_deltas).map(Some)
+    }
+
+    fn roll_back(
+        user: User,
+        at: DateTime<Utc>,
+        later_deltas: &HashMap<i64, Money>,
+        undone_deltas: &HashMap<i64, Money>,
+    ) -> DatabaseResponse<Self> {
+        let later = later_deltas.get(&user.id.0).copied().unwrap_or_default();
+        let undone = undone_deltas.get(&user.id.0).copied().unwrap_or_default();
+
+        let balance = user
+            .money
+            .checked_sub(later)
+            .and_then(|money| money.checked_add(undone))
+            .map_err(DBError::new)?;
+
+        Ok(ExportBalance {
+            user_id: user.id.0,
+            balance: balance.value,
+            nickname: user.nickname,
+            at,
+        })
+    }
+}
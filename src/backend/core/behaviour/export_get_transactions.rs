@@ -0,0 +1,157 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use sqlx::Executor;
+
+use crate::{
+    backend::{
+        core::{misc::EffectivePriceCache, ExportTransaction, Group},
+        database::{
+            ArticleDB, DBError, DatabaseResponse, DatabaseType, TransactionDB, DB,
+            DBGROUP_AUFLADUNG_ID, DBGROUP_SNACKBAR_ID, DBUSER_AUFLADUNG_ID, DBUSER_SNACKBAR_ID,
+        },
+    },
+    model::{GroupId, Money, Transaction, UserId},
+};
+
+impl ExportTransaction {
+    /// Returns all transactions with `from <= timestamp < until`, split up into one entry per involved user
+    pub async fn get_in_range(
+        db: &DB,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> DatabaseResponse<Vec<Self>> {
+        let mut conn = db.get_conn().await?;
+
+        let transactions = TransactionDB::get_in_range(&mut *conn, from, until).await?;
+
+        ExportTransaction::from_transactions(&mut *conn, transactions).await
+    }
+
+    /// Like [`ExportTransaction::get_in_range`], but only the entries of the given user
+    pub async fn get_user_in_range(
+        db: &DB,
+        user_id: UserId,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> DatabaseResponse<Vec<Self>> {
+        let mut conn = db.get_conn().await?;
+
+        let transactions =
+            TransactionDB::get_user_in_range(&mut *conn, user_id, from, until).await?;
+
+        let mut result = ExportTransaction::from_transactions(&mut *conn, transactions).await?;
+        result.retain(|row| row.user_id == user_id.0);
+
+        Ok(result)
+    }
+
+    /// The entries of the given user for the transactions that were undone with
+    /// `from <= undone_at < until`
+    pub async fn get_user_undone_in_range(
+        db: &DB,
+        user_id: UserId,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> DatabaseResponse<Vec<Self>> {
+        let mut conn = db.get_conn().await?;
+
+        let transactions =
+            TransactionDB::get_user_undone_in_range(&mut *conn, user_id, from, until).await?;
+
+        let mut result = ExportTransaction::from_transactions(&mut *conn, transactions).await?;
+        result.retain(|row| row.user_id == user_id.0);
+
+        Ok(result)
+    }
+
+    async fn from_transactions<T>(
+        conn: &mut T,
+        transactions: Vec<TransactionDB>,
+    ) -> DatabaseResponse<Vec<Self>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        let mut prices = EffectivePriceCache::default();
+        let mut result = Vec::<Self>::new();
+
+        for transaction in transactions.into_iter() {
+            let sender_group = Group::get(&mut *conn, GroupId(transaction.sender)).await?;
+            let receiver_group = Group::get(&mut *conn, GroupId(transaction.receiver)).await?;
+
+            let kind = if transaction.sender == DBGROUP_AUFLADUNG_ID.0 {
+                "deposit"
+            } else if transaction.receiver == DBGROUP_AUFLADUNG_ID.0 {
+                "withdraw"
+            } else if transaction.receiver == DBGROUP_SNACKBAR_ID.0 {
+                "purchase"
+            } else {
+                "transfer"
+            };
+
+            let (article, effective_price) = match transaction.t_type_data {
+                None => (None, None),
+                Some(article_id) => {
+                    let name = ArticleDB::get_single(&mut *conn, article_id)
+                        .await?
+                        .map(|article| article.name);
+
+                    let price = prices
+                        .get(&mut *conn, article_id, transaction.timestamp)
+                        .await?;
+
+                    (name, Some(price))
+                }
+            };
+
+            let deltas = Transaction::get_transaction_delta(
+                &mut *conn,
+                &sender_group,
+                &receiver_group,
+                &transaction,
+            )
+            .await
+            .map_err(DBError::new)?;
+
+            let amount = Money::try_from(transaction.money).map_err(DBError::new)?;
+
+            let senders = sender_group
+                .members
+                .iter()
+                .map(|member| member.nickname.clone())
+                .collect::<Vec<String>>();
+
+            let receivers = receiver_group
+                .members
+                .iter()
+                .map(|member| member.nickname.clone())
+                .collect::<Vec<String>>();
+
+            let mut rows = deltas
+                .into_iter()
+                .filter(|(user, _)| user.id != DBUSER_AUFLADUNG_ID && user.id != DBUSER_SNACKBAR_ID)
+                .map(|(user, delta)| ExportTransaction {
+                    transaction_id: transaction.id,
+                    timestamp: transaction.timestamp,
+                    kind,
+                    user_id: user.id.0,
+                    nickname: user.nickname,
+                    senders: senders.clone(),
+                    receivers: receivers.clone(),
+                    article: article.clone(),
+                    effective_price,
+                    amount: amount.value,
+                    delta: delta.delta,
+                    is_undone: transaction.is_undone,
+                    undone_at: transaction.undone_at,
+                    description: transaction.description.clone(),
+                })
+                .collect::<Vec<ExportTransaction>>();
+
+            rows.sort_by_key(|row| row.user_id);
+            result.append(&mut rows);
+        }
+
+        Ok(result)
+    }
+}
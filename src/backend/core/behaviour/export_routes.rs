@@ -0,0 +1,94 @@
+#![cfg(feature = "ssr")]
+
+use axum::{
+    extract::Query,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Extension,
+};
+use serde::Serialize;
+use tracing::error;
+
+use crate::backend::core::{
+    misc::{encode_export, CsvRecord},
+    ExportBalance, ExportParams, ExportTransaction, ServerState,
+};
+
+fn export_response<T: CsvRecord + Serialize>(
+    name: &str,
+    params: ExportParams,
+    records: &[T],
+) -> Response {
+    match encode_export(params.format, records) {
+        Ok(body) => (
+            [
+                (
+                    header::CONTENT_TYPE,
+                    params.format.content_type().to_string(),
+                ),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!(
+                        "attachment; filename=\"{}.{}\"",
+                        name,
+                        params.format.extension()
+                    ),
+                ),
+            ],
+            body,
+        )
+            .into_response(),
+        Err(e) => {
+            error!("Failed to encode export: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to encode export!",
+            )
+                .into_response()
+        }
+    }
+}
+
+/// GET /export/transactions?from=YYYY-MM-DD&until=YYYY-MM-DD&format=csv|json
+pub async fn export_transactions(
+    Extension(state): Extension<ServerState>,
+    Query(params): Query<ExportParams>,
+) -> Response {
+    let (from, until) = params.range();
+
+    let db = state.db.lock().await;
+
+    match ExportTransaction::get_in_range(&db, from, until).await {
+        Ok(transactions) => export_response("transactions", params, &transactions),
+        Err(e) => {
+            error!("Failed to export transactions: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to export transactions!",
+            )
+                .into_response()
+        }
+    }
+}
+
+/// GET /export/balances?until=YYYY-MM-DD&format=csv|json
+pub async fn export_balances(
+    Extension(state): Extension<ServerState>,
+    Query(params): Query<ExportParams>,
+) -> Response {
+    let (_, until) = params.range();
+
+    let db = state.db.lock().await;
+
+    match ExportBalance::get_at(&db, until).await {
+        Ok(balances) => export_response("balances", params, &balances),
+        Err(e) => {
+            error!("Failed to export balances: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to export balances!",
+            )
+                .into_response()
+        }
+    }
+}
@@ -1,110 +1,238 @@
 use leptos::prelude::*;
 
-use crate::model::AudioPlayback;
+use crate::model::{AudioPlayback, AudioSource, DatabaseId, UserId};
 
 #[cfg(not(debug_assertions))]
 use crate::backend::core::misc::custom_binary_encoding::Binary;
 
-#[cfg_attr(not(debug_assertions), server(input=Binary, output=Binary))]
-#[cfg_attr(debug_assertions, server)]
-pub async fn get_item_sound_name(audio: AudioPlayback) -> Result<String, ServerFnError> {
-    use crate::backend::core::{
-        behaviour::article_get::get_article, misc::choose_random_item, ServerState,
-    };
-    use axum::http::StatusCode;
-    use leptos_axum::ResponseOptions;
-    use tracing::{debug, error};
+#[cfg(feature = "ssr")]
+use crate::{
+    backend::{
+        core::{
+            misc::{choose_random_item, synthesize_speech, SpeechValues},
+            Settings, Sound, SpeechEngine, User,
+        },
+        database::{ArticleDB, DBError, DatabaseResponse, SoundDB, UserDB, DB},
+    },
+    model::SoundEvent,
+};
+
+#[cfg(feature = "ssr")]
+impl Sound {
+    /// Picks a random sound for the playback. The purchase and deposit sounds the user picked
+    /// come first, articles without an own sound use the generic sounds and refused purchases
+    /// without a sound use the failed sounds.
+    pub async fn choose_for(
+        db: &DB,
+        audio: AudioPlayback,
+        user_id: Option<UserId>,
+    ) -> DatabaseResponse<Option<DatabaseId>> {
+        let mut conn = db.get_conn().await?;
+
+        if let Some(user_id) = user_id {
+            let (purchase_sound_id, deposit_sound_id) =
+                UserDB::get_sound_ids(&mut *conn, user_id.0).await?;
+
+            let own_sound = match audio {
+                AudioPlayback::Bought(_) => purchase_sound_id,
+                AudioPlayback::Deposit(_) => deposit_sound_id,
+                _ => None,
+            };
 
-    let response_opts: ResponseOptions = expect_context();
+            if own_sound.is_some() {
+                return Ok(own_sound);
+            }
+        }
 
-    let state: ServerState = expect_context();
+        let (article_id, event) = match audio {
+            AudioPlayback::Failed => (None, SoundEvent::Failed),
+            AudioPlayback::Refused => (None, SoundEvent::Refused),
+            AudioPlayback::Undo => (None, SoundEvent::Generic),
+            AudioPlayback::Deposit(_) => (None, SoundEvent::Deposit),
+            AudioPlayback::Sent(_) => (None, SoundEvent::Generic),
+            AudioPlayback::Withdraw(_) => (None, SoundEvent::Withdraw),
+            AudioPlayback::Bought(article_id) => (Some(article_id), SoundEvent::Generic),
+        };
+
+        let mut candidates = match article_id {
+            Some(article_id) => SoundDB::get_ids_for_article(&mut *conn, article_id).await?,
+            None => Vec::new(),
+        };
+
+        if candidates.is_empty() {
+            candidates = SoundDB::get_ids_for_event(&mut *conn, event.name()).await?;
+        }
 
-    let sounds = &state.settings.sounds;
+        if candidates.is_empty() && event == SoundEvent::Refused {
+            candidates = SoundDB::get_ids_for_event(&mut *conn, SoundEvent::Failed.name()).await?;
+        }
 
-    let file = match audio {
-        AudioPlayback::Failed => choose_random_item(&sounds.failed),
-        AudioPlayback::Undo => choose_random_item(&sounds.generic),
-        AudioPlayback::Deposit(_) => choose_random_item(&sounds.deposit),
-        AudioPlayback::Sent(_) => choose_random_item(&sounds.generic),
-        AudioPlayback::Withdraw(_) => choose_random_item(&sounds.withdraw),
-        AudioPlayback::Bought(article_id) => {
-            let article = get_article(article_id).await?;
+        Ok(choose_random_item(&candidates).copied())
+    }
 
-            let sounds = match sounds.articles.get(&article.name) {
-                Some(sounds) => sounds,
-                None => &sounds.generic,
-            };
+    /// Fills the speech template of the playback, `None` if speech is disabled or the template
+    /// is empty
+    pub async fn speech_for(
+        db: &DB,
+        settings: &Settings,
+        audio: AudioPlayback,
+        user_id: Option<UserId>,
+    ) -> DatabaseResponse<Option<String>> {
+        let Some(speech) = &settings.speech else {
+            return Ok(None);
+        };
+
+        let templates = &speech.templates;
+        let (template, amount, article_id) = match audio {
+            AudioPlayback::Failed => (&templates.failed, None, None),
+            AudioPlayback::Refused => (&templates.refused, None, None),
+            AudioPlayback::Undo => (&templates.undo, None, None),
+            AudioPlayback::Deposit(money) => (&templates.deposit, Some(money), None),
+            AudioPlayback::Sent(money) => (&templates.sent, Some(money), None),
+            AudioPlayback::Withdraw(money) => (&templates.withdraw, Some(money), None),
+            AudioPlayback::Bought(article_id) => (&templates.bought, None, Some(article_id)),
+        };
+
+        if template.trim().is_empty() {
+            return Ok(None);
+        }
 
-            debug!("Picking random sound from {sounds:#?}");
+        let mut conn = db.get_conn().await?;
 
-            let sound = choose_random_item(sounds);
+        let article = match article_id {
+            Some(article_id) => ArticleDB::get_single(&mut *conn, article_id).await?,
+            None => None,
+        };
 
-            debug!("Picked sound {sound:#?}");
+        let user = match user_id {
+            Some(user_id) => User::get(&mut *conn, user_id).await?,
+            None => None,
+        };
 
-            sound
-        }
+        let values = SpeechValues {
+            user: user.as_ref().map(|user| user.nickname.clone()),
+            article: article.map(|article| article.name),
+            amount,
+            balance: user.map(|user| user.money),
+        };
+
+        Ok(Some(values.render(template, &settings.currency)))
+    }
+}
+
+/// Renders the speech on the server if it is configured that way, the browser speaks it
+/// otherwise or if that fails
+#[cfg(feature = "ssr")]
+async fn speak(settings: &Settings, text: String) -> AudioSource {
+    use tracing::warn;
+
+    let speech = match &settings.speech {
+        Some(value) if value.engine == SpeechEngine::Server => value,
+        _ => return AudioSource::Speech(text),
     };
 
-    Ok(match file {
-        None => {
-            error!("Failed to choose a random sound file");
-            response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-            return Err(ServerFnError::new("Failed to get sound file"));
+    match synthesize_speech(speech, &text).await {
+        Ok(data) => AudioSource::RenderedSpeech(data),
+        Err(e) => {
+            warn!("{e}, the browser speaks instead");
+            AudioSource::Speech(text)
         }
-        Some(val) => val.to_string(),
-    })
+    }
 }
 
+/// Returns what to play, stored sounds are fetched separately so that they can be cached.
+/// `user_id` is the user the sound is played for, if any.
 #[cfg_attr(not(debug_assertions), server(input=Binary, output=Binary))]
 #[cfg_attr(debug_assertions, server)]
-pub async fn get_item_sound_data(sound: String) -> Result<Vec<u8>, ServerFnError> {
+pub async fn get_item_sound(
+    audio: AudioPlayback,
+    user_id: Option<UserId>,
+) -> Result<AudioSource, ServerFnError> {
     use crate::backend::core::ServerState;
     use axum::http::StatusCode;
     use leptos_axum::ResponseOptions;
-    use std::path::PathBuf;
-    use std::str::FromStr;
-    use tracing::error;
+    use tracing::{debug, error};
 
     let response_opts: ResponseOptions = expect_context();
 
     let state: ServerState = expect_context();
+    let settings = state.settings.load_full();
 
-    let sounds = &state.settings.sounds;
+    let chosen = async {
+        let db = state.db.lock().await;
 
-    let all_sounds = sounds.get_all_sounds();
+        let sound = Sound::choose_for(&db, audio, user_id).await?;
+        let speech = Sound::speech_for(&db, &settings, audio, user_id).await?;
 
-    // somewhat of an expensive check, but otherwise we could allow arbitrary file reads
-    if !all_sounds.contains(&sound) {
-        response_opts.set_status(StatusCode::BAD_REQUEST);
-        return Err(ServerFnError::new(
-            "Tried to fetch a sound that doesn't exist!",
-        ));
+        Ok::<_, DBError>((sound, speech))
     }
+    .await;
 
-    let path = PathBuf::from_str(&sound);
-    let path = match path {
-        Ok(val) => val,
+    let (sound, speech) = match chosen {
+        Ok(value) => value,
         Err(e) => {
-            error!("Failed to create PathBuf: {e}");
+            error!("Failed to choose a sound: {e}");
             response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
             return Err(ServerFnError::new("Failed to get sound file"));
         }
     };
 
-    if !path.exists() {
-        error!("Path '{}' does not exists!", path.to_str().unwrap());
-        response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-        return Err(ServerFnError::new("Failed to get sound file"));
+    let always_speak = settings.speech.as_ref().is_some_and(|speech| speech.always);
+
+    let source = match (sound, speech) {
+        (None, None) => {
+            error!("There is no sound assigned for {audio:?}");
+            response_opts.set_status(StatusCode::NOT_FOUND);
+            return Err(ServerFnError::new("No sound is assigned for this"));
+        }
+        (Some(sound_id), None) => AudioSource::Sound {
+            sound_id,
+            speech: None,
+        },
+        (Some(sound_id), Some(text)) if !always_speak => AudioSource::Sound {
+            sound_id,
+            speech: Some(text),
+        },
+        (_, Some(text)) => speak(&settings, text).await,
+    };
+
+    if let AudioSource::Sound { sound_id, .. } = &source {
+        debug!("Picked sound {sound_id} for {audio:?}");
     }
 
-    let file = match tokio::fs::read(&path).await {
-        Ok(val) => val,
+    Ok(source)
+}
+
+#[cfg_attr(not(debug_assertions), server(input=Binary, output=Binary))]
+#[cfg_attr(debug_assertions, server)]
+pub async fn get_item_sound_data(sound_id: DatabaseId) -> Result<Vec<u8>, ServerFnError> {
+    use crate::backend::core::ServerState;
+    use axum::http::StatusCode;
+    use leptos_axum::ResponseOptions;
+    use tracing::error;
+
+    let response_opts: ResponseOptions = expect_context();
+
+    let state: ServerState = expect_context();
+
+    let data = async {
+        let mut conn = state.db.lock().await.get_conn().await?;
+        SoundDB::get_data(&mut *conn, sound_id).await
+    }
+    .await;
+
+    match data {
+        Ok(Some(value)) => Ok(value),
+        Ok(None) => {
+            response_opts.set_status(StatusCode::BAD_REQUEST);
+            Err(ServerFnError::new(
+                "Tried to fetch a sound that doesn't exist!",
+            ))
+        }
         Err(e) => {
-            error!("Failed to read file path '{}': {e}", path.to_str().unwrap());
+            error!("Failed to read sound {sound_id}: {e}");
             response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-            return Err(ServerFnError::new("Failed to get sound file"));
+            Err(ServerFnError::new("Failed to get sound file"))
         }
-    };
-
-    Ok(file)
+    }
 }
@@ -0,0 +1,132 @@
+use {crate::model::GlobalStatistics, leptos::prelude::*};
+
+#[cfg(not(debug_assertions))]
+use crate::backend::core::misc::custom_binary_encoding::Binary;
+
+#[cfg(feature = "ssr")]
+use crate::backend::database::{DBError, DatabaseResponse, DB};
+
+#[cfg(feature = "ssr")]
+const GLOBAL_STATISTICS_DAYS: i64 = 31;
+#[cfg(feature = "ssr")]
+const GLOBAL_STATISTICS_WEEKS: i64 = 52;
+
+#[cfg(feature = "ssr")]
+impl GlobalStatistics {
+    pub async fn get(db: &DB) -> DatabaseResponse<Self> {
+        use std::{cmp::Reverse, collections::HashMap};
+
+        use chrono::{Duration, Utc};
+
+        use crate::{
+            backend::{
+                core::{
+                    misc::{
+                        statistics::{
+                            day_label, last_periods, top_articles, week_label, STATISTICS_TOP_LIMIT,
+                        },
+                        EffectivePriceCache,
+                    },
+                    User,
+                },
+                database::TransactionDB,
+            },
+            model::{ConsumerStatistic, Money, UserId},
+        };
+
+        let mut conn = db.get_conn().await?;
+        let mut prices = EffectivePriceCache::default();
+        let now = Utc::now();
+
+        let purchases = TransactionDB::get_purchases(&mut *conn, None).await?;
+        let booked_articles = TransactionDB::get_booked_articles(&mut *conn, None).await?;
+
+        let mut revenue_per_day = HashMap::<String, Money>::new();
+        let mut revenue_per_week = HashMap::<String, Money>::new();
+        let mut per_user = HashMap::<i64, (i64, Money)>::new();
+
+        for purchase in purchases.iter() {
+            let price: Money = prices
+                .get(&mut *conn, purchase.article_id, purchase.timestamp)
+                .await?
+                .into();
+
+            let date = purchase.timestamp.date_naive();
+
+            let day = revenue_per_day.entry(day_label(date)).or_default();
+            *day = day.checked_add(price).map_err(DBError::new)?;
+
+            let week = revenue_per_week.entry(week_label(date)).or_default();
+            *week = week.checked_add(price).map_err(DBError::new)?;
+
+            let entry = per_user.entry(purchase.user_id).or_default();
+            entry.0 += 1;
+            entry.1 = entry.1.checked_add(price).map_err(DBError::new)?;
+        }
+
+        let mut top_consumers = Vec::<ConsumerStatistic>::new();
+
+        for (user_id, (amount, spent)) in per_user.into_iter() {
+            let user = match User::get(&mut *conn, UserId(user_id)).await? {
+                Some(user) => user,
+                None => continue,
+            };
+
+            if !user.show_in_stats {
+                continue;
+            }
+
+            top_consumers.push(ConsumerStatistic {
+                user_id: user.id,
+                nickname: user.nickname,
+                amount,
+                spent,
+            });
+        }
+
+        top_consumers.sort_by_key(|consumer| Reverse(consumer.spent));
+        top_consumers.truncate(STATISTICS_TOP_LIMIT);
+
+        Ok(GlobalStatistics {
+            top_articles: top_articles(&mut *conn, &mut prices, &booked_articles).await?,
+            top_consumers,
+            revenue_per_day: last_periods(
+                &revenue_per_day,
+                now,
+                GLOBAL_STATISTICS_DAYS,
+                Duration::days(1),
+                day_label,
+            ),
+            revenue_per_week: last_periods(
+                &revenue_per_week,
+                now,
+                GLOBAL_STATISTICS_WEEKS,
+                Duration::weeks(1),
+                week_label,
+            ),
+        })
+    }
+}
+
+#[cfg_attr(not(debug_assertions), server(input=Binary, output=Binary))]
+#[cfg_attr(debug_assertions, server)]
+pub async fn get_global_statistics() -> Result<GlobalStatistics, ServerFnError> {
+    use crate::backend::core::ServerState;
+    let state: ServerState = expect_context();
+    use axum::http::StatusCode;
+    use leptos_axum::ResponseOptions;
+    use tracing::error;
+
+    let response_opts: ResponseOptions = expect_context();
+
+    let db = state.db.lock().await;
+
+    match GlobalStatistics::get(&db).await {
+        Ok(value) => Ok(value),
+        Err(e) => {
+            response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+            error!("Failed to compute global statistics: {}", e);
+            Err(ServerFnError::new("Failed to compute global statistics!"))
+        }
+    }
+}
@@ -65,7 +65,7 @@ pub async fn get_group_members(gid: i64) -> Result<Vec<User>, ServerFnError> {
     for user_db in group.members.iter() {
         match User::get(&mut *conn, user_db.id.into()).await {
             Ok(user) => match user {
-                Some(user) => users.push(user),
+                Some(user) => users.push(user.redact()),
                 None => {
                     error!("Failed to get user from user id: {}", user_db.id);
                     response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
@@ -0,0 +1,16 @@
+use leptos::prelude::*;
+
+use crate::model::KioskSettings;
+
+#[cfg(not(debug_assertions))]
+use crate::backend::core::misc::custom_binary_encoding::Binary;
+
+#[cfg_attr(not(debug_assertions), server(input=Binary, output=Binary))]
+#[cfg_attr(debug_assertions, server)]
+pub async fn get_kiosk_settings() -> Result<KioskSettings, ServerFnError> {
+    use crate::backend::core::ServerState;
+
+    let state: ServerState = expect_context();
+
+    Ok(state.settings.load().kiosk.clone())
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "ssr")]
+
+use std::time::Instant;
+
+use axum::{
+    extract::Request,
+    http::{header, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Extension,
+};
+use tracing::error;
+
+use crate::backend::core::{misc::METRICS, ServerState};
+
+/// GET /metrics
+pub async fn get_metrics(Extension(state): Extension<ServerState>) -> Response {
+    if let Err(e) = METRICS.refresh(&state.db).await {
+        error!("Failed to refresh metrics: {}", e);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Failed to refresh metrics!",
+        )
+            .into_response();
+    }
+
+    match METRICS.encode() {
+        Ok(body) => (
+            [(header::CONTENT_TYPE, prometheus::TEXT_FORMAT.to_string())],
+            body,
+        )
+            .into_response(),
+        Err(e) => {
+            error!("Failed to encode metrics: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to encode metrics!",
+            )
+                .into_response()
+        }
+    }
+}
+
+/// Middleware that records the latency of every server function call
+pub async fn track_server_fn_metrics(request: Request, next: Next) -> Response {
+    // server functions are mounted as /api/<name><hash>
+    let function = match request.uri().path().strip_prefix("/api/") {
+        Some(value) => value
+            .trim_end_matches(|c: char| c.is_ascii_digit())
+            .to_string(),
+        None => return next.run(request).await,
+    };
+
+    let start = Instant::now();
+    let response = next.run(request).await;
+
+    METRICS
+        .server_fn_duration
+        .with_label_values(&[function.as_str(), response.status().as_str()])
+        .observe(start.elapsed().as_secs_f64());
+
+    response
+}
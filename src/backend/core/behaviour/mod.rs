@@ -4,15 +4,40 @@ pub mod article_get_articles_for_users;
 pub mod article_get_by_barcode;
 pub mod article_new;
 pub mod article_set_barcodes;
+pub mod article_set_components;
 pub mod article_set_name;
 pub mod article_set_price;
+pub mod debt_reminder_get_due;
+pub mod debt_reminder_routes;
+pub mod debt_reminder_send;
+pub mod event_routes;
+pub mod export_get_balances;
+pub mod export_get_transactions;
+pub mod export_routes;
 pub mod get_item_sound;
+pub mod global_statistics_get;
 pub mod group_get;
 pub mod group_get_group_id_for_multiple_users;
 pub mod group_get_groups;
 pub mod group_get_user_group_id;
+pub mod kiosk_settings_get;
+pub mod metrics_routes;
+pub mod pin_session_create;
+pub mod pin_session_get;
 pub mod send_money;
+pub mod server_event_new;
+pub mod settings_check_articles;
+pub mod sound_delete;
+pub mod sound_get_all;
+pub mod sound_set_assignments;
+pub mod sound_upload;
 pub mod split_cost;
+pub mod statement_get;
+pub mod statement_render;
+pub mod statement_routes;
+pub mod transaction_book_articles;
+pub mod transaction_check_consumption_rules;
+pub mod transaction_check_milestones;
 pub mod transaction_create;
 pub mod transaction_get;
 pub mod transaction_get_transaction_delta;
@@ -27,6 +52,14 @@ pub mod user_get_all;
 pub mod user_get_by_card_number;
 pub mod user_get_by_nick;
 pub mod user_get_transactions;
+pub mod user_redact;
 pub mod user_set_card_number;
+pub mod user_set_email;
+pub mod user_set_is_minor;
+pub mod user_set_limits;
 pub mod user_set_money;
 pub mod user_set_name;
+pub mod user_set_pin_hash;
+pub mod user_set_show_in_stats;
+pub mod user_set_sound_ids;
+pub mod user_statistics_get;
@@ -0,0 +1,102 @@
+use leptos::prelude::*;
+
+use crate::model::{PinSession, UserId};
+
+#[cfg(not(debug_assertions))]
+use crate::backend::core::misc::custom_binary_encoding::Binary;
+
+/// Unlocks an account with its PIN or card number
+#[cfg_attr(not(debug_assertions), server(input=Binary, output=Binary))]
+#[cfg_attr(debug_assertions, server)]
+pub async fn create_pin_session(
+    user_id: UserId,
+    secret: String,
+) -> Result<PinSession, ServerFnError> {
+    use crate::backend::{
+        core::{misc::verify_pin, ServerState, PIN_SESSION_COOKIE},
+        database::UserDB,
+    };
+    use axum::http::{header, HeaderValue, StatusCode};
+    use leptos_axum::ResponseOptions;
+    use std::time::Duration;
+    use tracing::{error, warn};
+
+    let state: ServerState = expect_context();
+    let response_opts: ResponseOptions = expect_context();
+
+    let (pin_hash, card_number) = {
+        let mut conn = match state.db.lock().await.get_conn().await {
+            Ok(value) => value,
+            Err(e) => {
+                error!("Failed to get database handle: {e}");
+                response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+                return Err(ServerFnError::new("Failed to get database handle!"));
+            }
+        };
+
+        let pin_hash = UserDB::get_pin_hash(&mut *conn, user_id.0).await;
+        let card_number = UserDB::get_card_number(&mut *conn, user_id.0).await;
+
+        match (pin_hash, card_number) {
+            (Ok(pin_hash), Ok(card_number)) => (pin_hash, card_number),
+            (Err(e), _) | (_, Err(e)) => {
+                error!("Failed to fetch the credentials of user {user_id}: {e}");
+                response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+                return Err(ServerFnError::new("Failed to fetch the user!"));
+            }
+        }
+    };
+
+    let pin_hash = match pin_hash {
+        Some(value) => value,
+        None => {
+            return Ok(PinSession {
+                pin_required: false,
+                unlocked_for_sec: 0,
+            })
+        }
+    };
+
+    if let Some(remaining) = state.pin_sessions.start_attempt(user_id) {
+        warn!("Too many attempts to unlock user {user_id}");
+        response_opts.set_status(StatusCode::TOO_MANY_REQUESTS);
+        return Err(ServerFnError::new(format!(
+            "Too many wrong PINs, try again in {} seconds!",
+            remaining.as_secs().max(1)
+        )));
+    }
+
+    let is_card = card_number.is_some_and(|card_number| card_number == secret);
+
+    if !is_card && !verify_pin(&secret, &pin_hash) {
+        warn!("Wrong PIN for user {user_id}");
+        // slows down guessing
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        response_opts.set_status(StatusCode::FORBIDDEN);
+        return Err(ServerFnError::new("Wrong PIN!"));
+    }
+
+    state.pin_sessions.reset_attempts(user_id);
+
+    let duration = Duration::from_secs(state.settings.load().accounts.pin_session_sec);
+    let token = state.pin_sessions.create(user_id, duration);
+
+    let cookie = format!(
+        "{PIN_SESSION_COOKIE}={token}; Path=/; HttpOnly; SameSite=Strict; Max-Age={}",
+        duration.as_secs()
+    );
+
+    match HeaderValue::from_str(&cookie) {
+        Ok(value) => response_opts.insert_header(header::SET_COOKIE, value),
+        Err(e) => {
+            error!("Failed to create the pin session cookie: {e}");
+            response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+            return Err(ServerFnError::new("Failed to create the pin session!"));
+        }
+    }
+
+    Ok(PinSession {
+        pin_required: true,
+        unlocked_for_sec: duration.as_secs(),
+    })
+}
@@ -0,0 +1,76 @@
+use leptos::prelude::*;
+
+use crate::model::{PinSession, UserId};
+
+#[cfg(feature = "ssr")]
+use {
+    crate::backend::{
+        core::{PinSessions, State},
+        database::{DatabaseResponse, DatabaseType, UserDB},
+    },
+    sqlx::Executor,
+};
+
+#[cfg(not(debug_assertions))]
+use crate::backend::core::misc::custom_binary_encoding::Binary;
+
+#[cfg(feature = "ssr")]
+impl State {
+    /// Whether the current request may act on behalf of the user.
+    /// Accounts without a PIN are always unlocked.
+    pub async fn get_pin_session(&self, user_id: UserId) -> DatabaseResponse<PinSession> {
+        let mut conn = self.db.lock().await.get_conn().await?;
+
+        self.pin_sessions.get_session(&mut *conn, user_id).await
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl PinSessions {
+    /// Like `State::get_pin_session`, but reads the PIN through `conn`, so it can be used
+    /// while the database is locked.
+    pub async fn get_session<T>(
+        &self,
+        conn: &mut T,
+        user_id: UserId,
+    ) -> DatabaseResponse<PinSession>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        if UserDB::get_pin_hash(&mut *conn, user_id.0).await?.is_none() {
+            return Ok(PinSession {
+                pin_required: false,
+                unlocked_for_sec: 0,
+            });
+        }
+
+        let remaining =
+            PinSessions::request_token().and_then(|token| self.remaining(&token, user_id));
+
+        Ok(PinSession {
+            pin_required: true,
+            unlocked_for_sec: remaining.map(|value| value.as_secs()).unwrap_or(0),
+        })
+    }
+}
+
+#[cfg_attr(not(debug_assertions), server(input=Binary, output=Binary))]
+#[cfg_attr(debug_assertions, server)]
+pub async fn get_pin_session(user_id: UserId) -> Result<PinSession, ServerFnError> {
+    use crate::backend::core::ServerState;
+    use axum::http::StatusCode;
+    use leptos_axum::ResponseOptions;
+    use tracing::error;
+
+    let state: ServerState = expect_context();
+    let response_opts: ResponseOptions = expect_context();
+
+    match state.get_pin_session(user_id).await {
+        Ok(value) => Ok(value),
+        Err(e) => {
+            error!("Failed to get the pin session: {e}");
+            response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+            Err(ServerFnError::new("Failed to get the pin session!"))
+        }
+    }
+}
@@ -8,12 +8,13 @@ pub async fn send_money(
     to_user: String,
     amount: String,
 ) -> Result<(), ServerFnError> {
-    use crate::backend::core::{ServerState, User};
+    use crate::backend::core::{misc::METRICS, ServerState, User};
     let state: ServerState = expect_context();
+    let settings = state.settings.load_full();
 
     use crate::{
         backend::{core::behaviour::user_get::get_user, database::GroupDB},
-        model::{GroupId, Money, Transaction, TransactionType},
+        model::{GroupId, Money, ServerEvent, Transaction, TransactionType},
     };
     use axum::http::StatusCode;
     use leptos_axum::redirect;
@@ -22,7 +23,7 @@ pub async fn send_money(
 
     let response_opts: ResponseOptions = expect_context();
 
-    let money: Money = match amount.clone().try_into() {
+    let money: Money = match (amount.as_str(), &settings.currency).try_into() {
         Ok(value) => value,
         Err(e) => {
             response_opts.set_status(StatusCode::BAD_REQUEST);
@@ -47,6 +48,19 @@ pub async fn send_money(
         }
     };
 
+    match state.get_pin_session(sender.id).await {
+        Ok(session) if session.is_unlocked() => {}
+        Ok(_) => {
+            response_opts.set_status(StatusCode::FORBIDDEN);
+            return Err(ServerFnError::new("The PIN is required to send money!"));
+        }
+        Err(e) => {
+            error!("Failed to get the pin session: {e}");
+            response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+            return Err(ServerFnError::new("Failed to get the pin session!"));
+        }
+    }
+
     let db = state.db.lock().await;
 
     let mut db_trns = match db.get_conn_transaction().await {
@@ -102,14 +116,14 @@ pub async fn send_money(
         }
     };
 
-    Transaction::create(
+    let (transaction_id, deltas) = Transaction::create(
         &mut *db_trns,
         GroupId(sender_group),
         GroupId(recipient_group),
         TransactionType::Sent(GroupId(recipient_group)),
         None,
         money,
-        &state.settings,
+        &settings,
     )
     .await?;
 
@@ -119,6 +133,17 @@ pub async fn send_money(
         return Err(ServerFnError::new("Failed to apply transaction!"));
     };
 
+    METRICS.transaction_booked(TransactionType::Sent(GroupId(recipient_group)));
+
+    state.events.publish_all(ServerEvent::transaction_created(
+        transaction_id,
+        TransactionType::Sent(GroupId(recipient_group)),
+        None,
+        money,
+        &deltas,
+        settings.accounts.low_balance_threshold,
+    ));
+
     redirect(&format!("/user/{}", sender.id));
 
     Ok(())
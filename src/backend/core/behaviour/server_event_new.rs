@@ -0,0 +1,89 @@
+#![cfg(feature = "ssr")]
+
+use std::collections::HashMap;
+
+use crate::{
+    backend::{
+        core::User,
+        database::{DBUSER_AUFLADUNG_ID, DBUSER_SNACKBAR_ID},
+    },
+    model::{
+        BalanceChange, DatabaseId, Money, ServerEvent, TransactionDelta, TransactionType, UserId,
+    },
+};
+
+fn is_system_user(id: UserId) -> bool {
+    id == DBUSER_AUFLADUNG_ID || id == DBUSER_SNACKBAR_ID
+}
+
+impl ServerEvent {
+    /// Returns the event for the new transaction, followed by a [`ServerEvent::LowBalance`]
+    /// for every user whose balance dropped below the threshold because of it.
+    pub fn transaction_created(
+        transaction_id: DatabaseId,
+        t_type: TransactionType,
+        description: Option<String>,
+        money: Money,
+        deltas: &HashMap<User, TransactionDelta>,
+        low_balance_threshold: i64,
+    ) -> Vec<Self> {
+        let mut users = deltas
+            .iter()
+            .filter(|(user, _)| !is_system_user(user.id))
+            .map(|(user, delta)| BalanceChange {
+                user_id: user.id,
+                nickname: user.nickname.clone(),
+                delta: delta.delta,
+                balance: delta.post_amount(),
+            })
+            .collect::<Vec<BalanceChange>>();
+
+        users.sort_by_key(|change| change.user_id.0);
+
+        let low_balances = users
+            .iter()
+            .filter(|change| {
+                change.balance < low_balance_threshold
+                    && change.balance - change.delta >= low_balance_threshold
+            })
+            .map(|change| ServerEvent::LowBalance {
+                user_id: change.user_id,
+                nickname: change.nickname.clone(),
+                balance: change.balance,
+            })
+            .collect::<Vec<Self>>();
+
+        let article_id = match t_type {
+            TransactionType::Bought(article_id) => Some(article_id),
+            _ => None,
+        };
+
+        let mut events = vec![ServerEvent::TransactionCreated {
+            transaction_id,
+            kind: t_type.into(),
+            article_id,
+            description,
+            money: money.value,
+            users,
+        }];
+
+        events.extend(low_balances);
+        events
+    }
+
+    pub fn transaction_undone(transaction_id: DatabaseId, users: &[User]) -> Self {
+        let mut users = users
+            .iter()
+            .map(|user| user.id)
+            .filter(|id| !is_system_user(*id))
+            .collect::<Vec<UserId>>();
+
+        users.sort_by_key(|id| id.0);
+        users.dedup();
+
+        ServerEvent::TransactionUndone {
+            transaction_id,
+            users,
+        }
+    }
+}
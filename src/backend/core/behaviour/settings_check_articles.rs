@@ -0,0 +1,35 @@
+#![cfg(feature = "ssr")]
+
+use crate::backend::{
+    core::Settings,
+    database::{ArticleDB, DatabaseResponse, DB},
+};
+
+impl Settings {
+    /// Returns every article id of the consumption rules that matches no article.
+    /// Articles can be deleted while the server runs, so it can't be part of [`Settings::validate`].
+    pub async fn check_articles(&self, db: &DB) -> DatabaseResponse<Vec<String>> {
+        let mut conn = db.get_conn().await?;
+
+        let article_ids = ArticleDB::get_all(&mut *conn, None)
+            .await?
+            .into_iter()
+            .map(|article| article.id)
+            .collect::<Vec<i64>>();
+
+        let mut problems = Vec::new();
+
+        for rule in self.consumption_rules.iter() {
+            for article_id in rule.articles.iter() {
+                if !article_ids.contains(article_id) {
+                    problems.push(format!(
+                        "the consumption rule '{}' names the article {article_id} that does not exist",
+                        rule.name
+                    ));
+                }
+            }
+        }
+
+        Ok(problems)
+    }
+}
@@ -0,0 +1,43 @@
+#[cfg(not(debug_assertions))]
+use crate::backend::core::misc::custom_binary_encoding::Binary;
+
+use leptos::prelude::*;
+
+use crate::model::DatabaseId;
+
+#[cfg(feature = "ssr")]
+use crate::backend::{
+    core::Sound,
+    database::{DBError, DatabaseResponse, SoundDB, DB},
+};
+
+#[cfg(feature = "ssr")]
+impl Sound {
+    pub async fn delete(db: &DB, sound_id: DatabaseId) -> DatabaseResponse<()> {
+        let mut transaction = db.get_conn_transaction().await?;
+
+        SoundDB::delete(&mut *transaction, sound_id).await?;
+
+        transaction.commit().await.map_err(DBError::new)
+    }
+}
+
+#[cfg_attr(not(debug_assertions), server(input=Binary, output=Binary))]
+#[cfg_attr(debug_assertions, server)]
+pub async fn delete_sound(sound_id: DatabaseId) -> Result<(), ServerFnError> {
+    use crate::backend::core::ServerState;
+    use axum::http::StatusCode;
+    use leptos_axum::ResponseOptions;
+    use tracing::error;
+
+    let state: ServerState = expect_context();
+    let response_opts: ResponseOptions = expect_context();
+
+    let db = state.db.lock().await;
+
+    Sound::delete(&db, sound_id).await.map_err(|e| {
+        error!("Failed to delete sound {}: {}", sound_id, e);
+        response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+        ServerFnError::new(format!("Failed to delete sound: {e}"))
+    })
+}
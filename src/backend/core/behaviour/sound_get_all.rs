@@ -0,0 +1,60 @@
+use crate::backend::core::Sound;
+
+#[cfg(not(debug_assertions))]
+use crate::backend::core::misc::custom_binary_encoding::Binary;
+
+use leptos::prelude::*;
+
+#[cfg(feature = "ssr")]
+use crate::{
+    backend::database::{DatabaseResponse, SoundDB, DB},
+    model::SoundEvent,
+};
+
+#[cfg(feature = "ssr")]
+impl Sound {
+    pub async fn get_all(db: &DB) -> DatabaseResponse<Vec<Self>> {
+        let mut conn = db.get_conn().await?;
+
+        let sounds = SoundDB::get_all(&mut *conn).await?;
+
+        let mut result = Vec::new();
+        for SoundDB { id, name } in sounds {
+            let events = SoundDB::get_events(&mut *conn, id)
+                .await?
+                .iter()
+                .filter_map(|event| SoundEvent::from_name(event))
+                .collect();
+            let article_ids = SoundDB::get_article_ids(&mut *conn, id).await?;
+
+            result.push(Sound {
+                id,
+                name,
+                events,
+                article_ids,
+            });
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg_attr(not(debug_assertions), server(input=Binary, output=Binary))]
+#[cfg_attr(debug_assertions, server)]
+pub async fn get_sounds() -> Result<Vec<Sound>, ServerFnError> {
+    use crate::backend::core::ServerState;
+    use axum::http::StatusCode;
+    use leptos_axum::ResponseOptions;
+    use tracing::error;
+
+    let state: ServerState = expect_context();
+    let response_opts: ResponseOptions = expect_context();
+
+    let db = state.db.lock().await;
+
+    Sound::get_all(&db).await.map_err(|e| {
+        error!("Could not fetch sounds: {}", e);
+        response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+        ServerFnError::new(format!("Could not fetch sounds: {e}"))
+    })
+}
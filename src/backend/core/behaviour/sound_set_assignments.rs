@@ -0,0 +1,65 @@
+#[cfg(not(debug_assertions))]
+use crate::backend::core::misc::custom_binary_encoding::Binary;
+
+use leptos::prelude::*;
+
+use crate::model::{DatabaseId, SoundEvent};
+
+#[cfg(feature = "ssr")]
+use crate::backend::{
+    core::Sound,
+    database::{DBError, DatabaseResponse, SoundDB, DB},
+};
+
+#[cfg(feature = "ssr")]
+impl Sound {
+    /// Replaces the events and articles the sound is played for
+    pub async fn set_assignments(
+        db: &DB,
+        sound_id: DatabaseId,
+        events: &[SoundEvent],
+        article_ids: &[DatabaseId],
+    ) -> DatabaseResponse<()> {
+        let mut transaction = db.get_conn_transaction().await?;
+
+        let events = events.iter().map(SoundEvent::name).collect::<Vec<_>>();
+
+        SoundDB::set_events(&mut *transaction, sound_id, &events).await?;
+        SoundDB::set_articles(&mut *transaction, sound_id, article_ids).await?;
+
+        transaction.commit().await.map_err(DBError::new)
+    }
+}
+
+#[cfg_attr(not(debug_assertions), server(input=Binary, output=Binary))]
+#[cfg_attr(debug_assertions, server)]
+pub async fn set_sound_assignments(
+    sound_id: DatabaseId,
+    events: Vec<SoundEvent>,
+    article_ids: Vec<DatabaseId>,
+) -> Result<(), ServerFnError> {
+    use crate::backend::core::ServerState;
+    use axum::http::StatusCode;
+    use leptos_axum::ResponseOptions;
+    use tracing::error;
+
+    let state: ServerState = expect_context();
+    let response_opts: ResponseOptions = expect_context();
+
+    let mut events = events;
+    events.sort_by_key(SoundEvent::name);
+    events.dedup();
+    let mut article_ids = article_ids;
+    article_ids.sort();
+    article_ids.dedup();
+
+    let db = state.db.lock().await;
+
+    Sound::set_assignments(&db, sound_id, &events, &article_ids)
+        .await
+        .map_err(|e| {
+            error!("Failed to assign sound {}: {}", sound_id, e);
+            response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+            ServerFnError::new(format!("Failed to assign sound: {e}"))
+        })
+}
@@ -0,0 +1,117 @@
+use leptos::{
+    prelude::*,
+    server_fn::codec::{MultipartData, MultipartFormData},
+};
+
+use crate::model::DatabaseId;
+
+#[cfg(feature = "ssr")]
+use crate::backend::{
+    core::Sound,
+    database::{DBError, DatabaseResponse, SoundDB, DB},
+};
+
+/// Uploaded files larger than this are rejected
+#[cfg(feature = "ssr")]
+const MAX_SOUND_BYTES: usize = 10 * 1024 * 1024;
+
+#[cfg(feature = "ssr")]
+impl Sound {
+    /// Stores all sounds at once, returns their ids
+    pub async fn create_all(
+        db: &DB,
+        sounds: &[(String, Vec<u8>)],
+    ) -> DatabaseResponse<Vec<DatabaseId>> {
+        let mut transaction = db.get_conn_transaction().await?;
+
+        let mut ids = Vec::new();
+        for (name, data) in sounds.iter() {
+            ids.push(SoundDB::create(&mut *transaction, name, data).await?);
+        }
+
+        transaction.commit().await.map_err(DBError::new)?;
+
+        Ok(ids)
+    }
+}
+
+/// Stores every audio file of the form as a new sound without assignments
+#[server(input = MultipartFormData)]
+pub async fn upload_sounds(data: MultipartData) -> Result<Vec<DatabaseId>, ServerFnError> {
+    use crate::backend::core::ServerState;
+    use axum::http::StatusCode;
+    use leptos_axum::ResponseOptions;
+    use tracing::{error, info};
+
+    let state: ServerState = expect_context();
+    let response_opts: ResponseOptions = expect_context();
+
+    let bad_request = |msg: String| {
+        response_opts.set_status(StatusCode::BAD_REQUEST);
+        Err(ServerFnError::new(msg))
+    };
+
+    let mut data = match data.into_inner() {
+        Some(value) => value,
+        None => return bad_request(String::from("Expected a multipart form")),
+    };
+
+    let mut sounds = Vec::new();
+
+    loop {
+        let mut field = match data.next_field().await {
+            Ok(Some(value)) => value,
+            Ok(None) => break,
+            Err(e) => return bad_request(format!("Failed to read the upload: {e}")),
+        };
+
+        // an empty file input still sends a field without a name
+        let name = match field.file_name() {
+            Some(value) if !value.is_empty() => value.to_string(),
+            _ => continue,
+        };
+
+        if field
+            .content_type()
+            .is_none_or(|mime| mime.type_().as_str() != "audio")
+        {
+            return bad_request(format!("'{name}' is not an audio file"));
+        }
+
+        let mut bytes = Vec::new();
+        loop {
+            match field.chunk().await {
+                Ok(Some(chunk)) => bytes.extend_from_slice(&chunk),
+                Ok(None) => break,
+                Err(e) => return bad_request(format!("Failed to read '{name}': {e}")),
+            }
+
+            if bytes.len() > MAX_SOUND_BYTES {
+                return bad_request(format!(
+                    "'{name}' is larger than {} MiB",
+                    MAX_SOUND_BYTES / 1024 / 1024
+                ));
+            }
+        }
+
+        sounds.push((name, bytes));
+    }
+
+    if sounds.is_empty() {
+        return bad_request(String::from("No files were uploaded"));
+    }
+
+    let db = state.db.lock().await;
+
+    match Sound::create_all(&db, &sounds).await {
+        Ok(ids) => {
+            info!("Uploaded {} sound(s)", ids.len());
+            Ok(ids)
+        }
+        Err(e) => {
+            error!("Failed to store sounds: {}", e);
+            response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+            Err(ServerFnError::new(format!("Failed to store sounds: {e}")))
+        }
+    }
+}
@@ -2,9 +2,69 @@ use leptos::prelude::*;
 
 use crate::model::SplitCostError;
 
+#[cfg(feature = "ssr")]
+use {
+    crate::{
+        backend::{
+            core::{misc::METRICS, Group, Settings, User},
+            database::DatabaseType,
+        },
+        model::{
+            CreateTransactionError, DatabaseId, GroupId, Money, Transaction, TransactionDelta,
+            TransactionType, UserId,
+        },
+    },
+    sqlx::Executor,
+    std::collections::HashMap,
+};
+
 #[cfg(not(debug_assertions))]
 use crate::backend::core::misc::custom_binary_encoding::Binary;
 
+#[cfg(feature = "ssr")]
+impl Transaction {
+    /// Splits `money` evenly between the primary and the secondary users. The primary user already
+    /// payed, so the secondary users send their shares to them.
+    /// Returns the transaction, the transferred money and the deltas of all involved users.
+    pub async fn split_cost<T>(
+        conn: &mut T,
+        primary_user: UserId,
+        secondary_users: &[UserId],
+        money: Money,
+        description: Option<String>,
+        settings: &Settings,
+    ) -> Result<(DatabaseId, Money, HashMap<User, TransactionDelta>), CreateTransactionError>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        let primary_group = Group::get_user_group_id(&mut *conn, primary_user).await?;
+
+        let secondary_group =
+            Group::get_group_id_for_multiple_users(&mut *conn, secondary_users).await?;
+
+        let shares = money.allocate(secondary_users.len() + 1)?;
+
+        // the primary user already payed his share, which is the smallest one
+        let money = match shares.last() {
+            Some(share) => money.checked_sub(*share)?,
+            None => money,
+        };
+
+        let (transaction_id, deltas) = Transaction::create(
+            &mut *conn,
+            secondary_group,
+            primary_group,
+            TransactionType::Sent(GroupId(0)),
+            description,
+            money,
+            settings,
+        )
+        .await?;
+
+        Ok((transaction_id, money, deltas))
+    }
+}
+
 #[cfg_attr(not(debug_assertions), server(input=Binary, output=Binary))]
 #[cfg_attr(debug_assertions, server)]
 pub async fn split_cost(
@@ -15,19 +75,19 @@ pub async fn split_cost(
 ) -> Result<(), SplitCostError> {
     use crate::backend::core::ServerState;
     let state: ServerState = expect_context();
-    use crate::backend::core::{Group, User};
-    use crate::model::{GroupId, Money, Transaction, TransactionType, UserId};
+    let settings = state.settings.load_full();
+    use crate::model::ServerEvent;
     use axum::http::StatusCode;
     use leptos_axum::{redirect, ResponseOptions};
     use tracing::error;
 
     let response_opts: ResponseOptions = expect_context();
 
-    let mut money: Money = match money.try_into() {
+    let money: Money = match (money.as_str(), &settings.currency).try_into() {
         Ok(value) => value,
         Err(e) => {
             response_opts.set_status(StatusCode::BAD_REQUEST);
-            return Err(SplitCostError::MoneyParseError(e.to_string()));
+            return Err(SplitCostError::MoneyParseError(e));
         }
     };
 
@@ -96,31 +156,29 @@ pub async fn split_cost(
             }
         };
 
+        // the secondary users pay, so their accounts have to be unlocked
+        if !state
+            .pin_sessions
+            .get_session(&mut *trans, user)
+            .await?
+            .is_unlocked()
+        {
+            response_opts.set_status(StatusCode::FORBIDDEN);
+            return Err(SplitCostError::CreateTransactionError(
+                CreateTransactionError::PinRequired(user),
+            ));
+        }
+
         secondary_users.push(user);
     }
 
-    let primary_group = Group::get_user_group_id(&mut *trans, primary_user.id).await?;
-
-    let secondary_group =
-        Group::get_group_id_for_multiple_users(&mut *trans, &secondary_users).await?;
-
-    let total_amount_of_users = (secondary_users.len() + 1) as i64;
-
-    let single_share = money.value / total_amount_of_users;
-
-    // the primary user already payed his share
-    money.value -= single_share;
-
-    let settings = &state.settings;
-
-    Transaction::create(
+    let (transaction_id, money, deltas) = Transaction::split_cost(
         &mut *trans,
-        secondary_group,
-        primary_group,
-        TransactionType::Sent(GroupId(0)),
-        description,
+        primary_user.id,
+        &secondary_users,
         money,
-        settings,
+        description.clone(),
+        &settings,
     )
     .await?;
 
@@ -132,6 +190,17 @@ pub async fn split_cost(
         ));
     }
 
+    METRICS.transaction_booked(TransactionType::Sent(GroupId(0)));
+
+    state.events.publish_all(ServerEvent::transaction_created(
+        transaction_id,
+        TransactionType::Sent(GroupId(0)),
+        description,
+        money,
+        &deltas,
+        settings.accounts.low_balance_threshold,
+    ));
+
     redirect("/");
 
     Ok(())
@@ -0,0 +1,121 @@
+#![cfg(feature = "ssr")]
+
+use crate::{
+    backend::{
+        core::{ExportBalance, ExportTransaction, Statement, StatementEntry, User},
+        database::{DBError, DatabaseResponse, DB},
+    },
+    model::{StatementMonth, UserId},
+};
+
+fn describe(transaction: &ExportTransaction) -> String {
+    let text = match transaction.kind {
+        "deposit" => String::from("Deposit"),
+        "withdraw" => String::from("Withdraw"),
+        "purchase" => transaction
+            .article
+            .clone()
+            .or(transaction.description.clone())
+            .unwrap_or(String::from("Purchase")),
+        _ => {
+            let text = match transaction.delta < 0 {
+                true => format!("Sent to {}", transaction.receivers.join(", ")),
+                false => format!("Received from {}", transaction.senders.join(", ")),
+            };
+
+            match &transaction.description {
+                Some(description) if !description.is_empty() => {
+                    format!("{text}: {description}")
+                }
+                _ => text,
+            }
+        }
+    };
+
+    // undos that were recorded are listed as entries of their own
+    match transaction.is_undone && transaction.undone_at.is_none() {
+        true => format!("{text} (undone)"),
+        false => text,
+    }
+}
+
+impl Statement {
+    /// Returns the statements of all users for the given month
+    pub async fn get_all(db: &DB, month: StatementMonth) -> DatabaseResponse<Vec<Self>> {
+        let mut statements = Vec::<Self>::new();
+
+        for user in User::get_all(db).await?.into_iter() {
+            if let Some(statement) = Statement::get(db, user.id, month).await? {
+                statements.push(statement);
+            }
+        }
+
+        Ok(statements)
+    }
+
+    /// Returns the statement of the user for the given month, only reads the user's transactions.
+    /// Undos are listed when they happened, so the entries add up to the closing balance.
+    pub async fn get(
+        db: &DB,
+        user_id: UserId,
+        month: StatementMonth,
+    ) -> DatabaseResponse<Option<Self>> {
+        let (from, until) = month
+            .range()
+            .ok_or_else(|| DBError::new(format!("Invalid month: {month}")))?;
+
+        let opening_balance = match ExportBalance::get_user_at(db, user_id, from).await? {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+
+        let closing_balance = match ExportBalance::get_user_at(db, user_id, until).await? {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+
+        let mut entries = ExportTransaction::get_user_in_range(db, user_id, from, until)
+            .await?
+            .iter()
+            .map(|transaction| StatementEntry {
+                transaction_id: transaction.transaction_id,
+                timestamp: transaction.timestamp,
+                description: describe(transaction),
+                delta: transaction.delta,
+                is_undone: transaction.is_undone && transaction.undone_at.is_none(),
+            })
+            .collect::<Vec<StatementEntry>>();
+
+        let undone = ExportTransaction::get_user_undone_in_range(db, user_id, from, until).await?;
+
+        for transaction in undone.iter() {
+            let Some(undone_at) = transaction.undone_at else {
+                continue;
+            };
+
+            let delta = transaction
+                .delta
+                .checked_neg()
+                .ok_or_else(|| DBError::new("The undone amount is out of range"))?;
+
+            entries.push(StatementEntry {
+                transaction_id: transaction.transaction_id,
+                timestamp: undone_at,
+                description: format!("Undo: {}", describe(transaction)),
+                delta,
+                is_undone: false,
+            });
+        }
+
+        entries.sort_by_key(|entry| entry.timestamp);
+
+        Ok(Some(Statement {
+            user_id: opening_balance.user_id,
+            nickname: opening_balance.nickname,
+            month,
+            opening_balance: opening_balance.balance,
+            closing_balance: closing_balance.balance,
+            entries,
+        }))
+    }
+}
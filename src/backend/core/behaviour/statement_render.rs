@@ -0,0 +1,134 @@
+#![cfg(feature = "ssr")]
+
+use crate::{
+    backend::core::{misc::PdfDocument, Statement},
+    model::Currency,
+};
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+impl Statement {
+    pub fn title(&self) -> String {
+        format!("Statement {} for {}", self.month, self.nickname)
+    }
+
+    pub fn file_name(&self) -> String {
+        format!("statement_{}_{}", self.month, self.user_id)
+    }
+
+    /// Renders the statement as a standalone html page, that can be printed from the browser
+    pub fn to_html(&self, currency: &Currency) -> String {
+        let rows = self
+            .entries
+            .iter()
+            .map(|entry| {
+                format!(
+                    "<tr{}><td>{}</td><td>{}</td><td class=\"amount\">{}</td></tr>\n",
+                    if entry.is_undone {
+                        " class=\"undone\""
+                    } else {
+                        ""
+                    },
+                    entry.timestamp.format("%Y-%m-%d %H:%M"),
+                    escape_html(&entry.description),
+                    currency.format_diff(entry.delta),
+                )
+            })
+            .collect::<String>();
+
+        format!(
+            r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+body {{ font-family: sans-serif; max-width: 50em; margin: 2em auto; color: #000; background: #fff; }}
+table {{ width: 100%; border-collapse: collapse; }}
+th, td {{ text-align: left; padding: 0.3em 0.5em; border-bottom: 1px solid #ccc; }}
+.amount {{ text-align: right; white-space: nowrap; }}
+.undone {{ color: #888; text-decoration: line-through; }}
+.summary td {{ font-weight: bold; border-bottom: none; }}
+@media print {{ body {{ margin: 0; max-width: none; }} }}
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+<table>
+<thead><tr><th>Date</th><th>Description</th><th class="amount">Amount</th></tr></thead>
+<tbody>
+<tr class="summary"><td></td><td>Opening balance</td><td class="amount">{opening}</td></tr>
+{rows}<tr class="summary"><td></td><td>Closing balance</td><td class="amount">{closing}</td></tr>
+</tbody>
+</table>
+</body>
+</html>
+"#,
+            title = escape_html(&self.title()),
+            opening = currency.format(self.opening_balance),
+            closing = currency.format(self.closing_balance),
+        )
+    }
+
+    pub fn to_pdf(&self, currency: &Currency) -> Vec<u8> {
+        const AMOUNT_COLUMN: f32 = 420.0;
+        const DESCRIPTION_COLUMN: f32 = 100.0;
+
+        let mut document = PdfDocument::new();
+
+        document.bold(self.title(), 18.0);
+        document.spacer(10.0);
+
+        document.columns(
+            vec![
+                (0.0, String::from("Date")),
+                (DESCRIPTION_COLUMN, String::from("Description")),
+                (AMOUNT_COLUMN, String::from("Amount")),
+            ],
+            10.0,
+            true,
+        );
+        document.columns(
+            vec![
+                (DESCRIPTION_COLUMN, String::from("Opening balance")),
+                (AMOUNT_COLUMN, currency.format(self.opening_balance)),
+            ],
+            10.0,
+            true,
+        );
+
+        for entry in self.entries.iter() {
+            let mut description = entry.description.clone();
+            if description.chars().count() > 60 {
+                description = description.chars().take(57).collect::<String>() + "...";
+            }
+
+            document.columns(
+                vec![
+                    (0.0, entry.timestamp.format("%Y-%m-%d %H:%M").to_string()),
+                    (DESCRIPTION_COLUMN, description),
+                    (AMOUNT_COLUMN, currency.format_diff(entry.delta)),
+                ],
+                10.0,
+                false,
+            );
+        }
+
+        document.columns(
+            vec![
+                (DESCRIPTION_COLUMN, String::from("Closing balance")),
+                (AMOUNT_COLUMN, currency.format(self.closing_balance)),
+            ],
+            10.0,
+            true,
+        );
+
+        document.render()
+    }
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "ssr")]
+
+use axum::{
+    extract::{Path, Query},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Extension,
+};
+use tracing::error;
+
+use crate::{
+    backend::core::{ServerState, Statement, StatementFormat, StatementParams},
+    model::{StatementMonth, UserId},
+};
+
+/// GET /statement/{user_id}/{YYYY-MM}?format=html|pdf
+pub async fn get_statement(
+    Extension(state): Extension<ServerState>,
+    Path((user_id, month)): Path<(i64, String)>,
+    Query(params): Query<StatementParams>,
+) -> Response {
+    let month = match month.parse::<StatementMonth>() {
+        Ok(value) => value,
+        Err(e) => return (StatusCode::BAD_REQUEST, e).into_response(),
+    };
+
+    let db = state.db.lock().await;
+
+    let statement = match Statement::get(&db, UserId(user_id), month).await {
+        Ok(value) => value,
+        Err(e) => {
+            error!("Failed to create statement: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to create statement!",
+            )
+                .into_response();
+        }
+    };
+
+    let statement = match statement {
+        Some(value) => value,
+        None => {
+            return (
+                StatusCode::NOT_FOUND,
+                format!("No user with the id {user_id} has been found!"),
+            )
+                .into_response()
+        }
+    };
+
+    let settings = state.settings.load();
+
+    let body = match params.format {
+        StatementFormat::Html => statement.to_html(&settings.currency).into_bytes(),
+        StatementFormat::Pdf => statement.to_pdf(&settings.currency),
+    };
+
+    (
+        [
+            (
+                header::CONTENT_TYPE,
+                params.format.content_type().to_string(),
+            ),
+            (
+                header::CONTENT_DISPOSITION,
+                format!(
+                    "inline; filename=\"{}.{}\"",
+                    statement.file_name(),
+                    params.format.extension()
+                ),
+            ),
+        ],
+        body,
+    )
+        .into_response()
+}
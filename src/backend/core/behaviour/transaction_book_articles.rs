@@ -0,0 +1,39 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::Executor;
+
+use crate::{
+    backend::database::{ArticleDB, DatabaseResponse, DatabaseType, TransactionDB},
+    model::{DatabaseId, Transaction},
+};
+
+impl Transaction {
+    /// Records which articles left the shelf for a purchase.
+    /// Bundles are booked against their components, normal articles against themselves.
+    pub async fn book_articles<T>(
+        conn: &mut T,
+        transaction_id: DatabaseId,
+        article_id: DatabaseId,
+    ) -> DatabaseResponse<()>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        let components = ArticleDB::get_components(&mut *conn, article_id).await?;
+
+        if components.is_empty() {
+            return TransactionDB::add_article(&mut *conn, transaction_id, article_id, 1).await;
+        }
+
+        for component in components.into_iter() {
+            TransactionDB::add_article(
+                &mut *conn,
+                transaction_id,
+                component.article_id,
+                component.quantity,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "ssr")]
+
+use chrono::Utc;
+use sqlx::Executor;
+
+use crate::{
+    backend::{
+        core::{ConsumptionRule, User},
+        database::{DatabaseType, TransactionDB},
+    },
+    model::{ConsumptionRuleViolation, CreateTransactionError, DatabaseId, Transaction},
+};
+
+impl Transaction {
+    /// Checks a purchase against the consumption rules of the config.
+    /// The purchase has to be booked already, so it is part of today's purchases.
+    pub async fn check_consumption_rules<T>(
+        conn: &mut T,
+        transaction_id: DatabaseId,
+        user: &User,
+        rules: &[ConsumptionRule],
+    ) -> Result<(), CreateTransactionError>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        if rules.is_empty() {
+            return Ok(());
+        }
+
+        let bought_articles = TransactionDB::get_sales_counts(&mut *conn, transaction_id)
+            .await?
+            .into_iter()
+            .map(|article| article.article_id)
+            .collect::<Vec<DatabaseId>>();
+
+        let rules = rules
+            .iter()
+            .filter(|rule| {
+                bought_articles
+                    .iter()
+                    .any(|article| rule.articles.contains(article))
+            })
+            .collect::<Vec<&ConsumptionRule>>();
+
+        if rules.is_empty() {
+            return Ok(());
+        }
+
+        for rule in rules.iter() {
+            if rule.adults_only && user.is_minor {
+                return Err(CreateTransactionError::ConsumptionRuleViolated(
+                    ConsumptionRuleViolation::AdultsOnly {
+                        rule: rule.name.clone(),
+                    },
+                ));
+            }
+        }
+
+        let start_of_day = match Utc::now().date_naive().and_hms_opt(0, 0, 0) {
+            Some(value) => value.and_utc(),
+            None => {
+                return Err(CreateTransactionError::new(
+                    "Failed to get the start of the day",
+                ))
+            }
+        };
+
+        let sales_today =
+            TransactionDB::get_user_sales_counts_since(&mut *conn, user.id, start_of_day).await?;
+
+        for rule in rules.into_iter() {
+            let max_per_day = match rule.max_per_day {
+                Some(value) => value,
+                None => continue,
+            };
+
+            let bought_today: i64 = sales_today
+                .iter()
+                .filter(|article| rule.articles.contains(&article.article_id))
+                .map(|article| article.sold)
+                .sum();
+
+            if bought_today > max_per_day {
+                return Err(CreateTransactionError::ConsumptionRuleViolated(
+                    ConsumptionRuleViolation::DailyLimitReached {
+                        rule: rule.name.clone(),
+                        max_per_day,
+                    },
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
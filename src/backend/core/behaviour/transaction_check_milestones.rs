@@ -0,0 +1,131 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use sqlx::Executor;
+
+use crate::{
+    backend::{
+        core::{misc::choose_random_item, MilestoneRule, MilestoneTrigger},
+        database::{DBError, DatabaseResponse, DatabaseType, SoundDB, TransactionDB},
+    },
+    model::{
+        DatabaseId, ReachedMilestone, SoundEvent, StatementMonth, Transaction, TransactionDelta,
+        TransactionType, UserId,
+    },
+};
+
+impl Transaction {
+    /// Returns the milestone rules of the config that the committed transaction fulfilled for the
+    /// user, together with the sound to play for them.
+    pub async fn check_milestones<T>(
+        conn: &mut T,
+        transaction_id: DatabaseId,
+        transaction_type: TransactionType,
+        user_id: UserId,
+        delta: &TransactionDelta,
+        rules: &[MilestoneRule],
+    ) -> DatabaseResponse<Vec<ReachedMilestone>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        let mut reached = Vec::new();
+
+        let is_purchase = matches!(transaction_type, TransactionType::Bought(_));
+        let counts_articles = rules
+            .iter()
+            .any(|rule| matches!(rule.trigger, MilestoneTrigger::ArticleCount { .. }));
+
+        let (sales, booked) = match is_purchase && counts_articles {
+            false => (Vec::new(), Vec::new()),
+            true => (
+                TransactionDB::get_user_sales_counts_since(
+                    &mut *conn,
+                    user_id,
+                    DateTime::<Utc>::UNIX_EPOCH,
+                )
+                .await?,
+                TransactionDB::get_booked_articles(&mut *conn, Some(user_id)).await?,
+            ),
+        };
+
+        for rule in rules.iter() {
+            let count = match &rule.trigger {
+                MilestoneTrigger::ArticleCount { article, every } => {
+                    let Some(sold) = sales.iter().find(|elem| elem.name == *article) else {
+                        continue;
+                    };
+
+                    let bought_now: i64 = booked
+                        .iter()
+                        .filter(|elem| {
+                            elem.transaction_id == transaction_id
+                                && elem.article_id == sold.article_id
+                        })
+                        .map(|elem| elem.amount)
+                        .sum();
+
+                    // a bundle may pass several multiples at once, the last one counts
+                    let every = (*every).max(1);
+                    if bought_now == 0 || sold.sold / every == (sold.sold - bought_now) / every {
+                        continue;
+                    }
+
+                    Some(sold.sold / every * every)
+                }
+                MilestoneTrigger::FirstDepositOfMonth => {
+                    if transaction_type != TransactionType::Deposit {
+                        continue;
+                    }
+
+                    let (start_of_month, _) = StatementMonth::of(Utc::now())
+                        .range()
+                        .ok_or_else(|| DBError::new("Failed to get the start of the month"))?;
+
+                    let deposits = TransactionDB::get_user_deposit_count_since(
+                        &mut *conn,
+                        user_id,
+                        start_of_month,
+                    )
+                    .await?;
+
+                    if deposits != 1 {
+                        continue;
+                    }
+
+                    None
+                }
+                MilestoneTrigger::BalanceAboveZero => {
+                    if delta.amount_pre >= 0 || delta.post_amount() < 0 {
+                        continue;
+                    }
+
+                    None
+                }
+            };
+
+            let message = match count {
+                Some(count) => rule.message.replace("{count}", &count.to_string()),
+                None => rule.message.clone(),
+            };
+
+            let named_sound = match &rule.sound {
+                Some(name) => SoundDB::get_id_by_name(&mut *conn, name).await?,
+                None => None,
+            };
+
+            let sound_id = match named_sound {
+                Some(value) => Some(value),
+                None => {
+                    let sounds =
+                        SoundDB::get_ids_for_event(&mut *conn, SoundEvent::Milestone.name())
+                            .await?;
+                    choose_random_item(&sounds).copied()
+                }
+            };
+
+            reached.push(ReachedMilestone { message, sound_id });
+        }
+
+        Ok(reached)
+    }
+}
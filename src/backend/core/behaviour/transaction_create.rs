@@ -3,7 +3,9 @@ use std::collections::HashMap;
 
 use leptos::prelude::*;
 
-use crate::model::{CreateTransactionError, Money, Transaction, TransactionType, UserId};
+use crate::model::{
+    CreateTransactionError, Money, ReachedMilestone, Transaction, TransactionType, UserId,
+};
 #[cfg(feature = "ssr")]
 use crate::{backend::core::User, model::TransactionDelta};
 
@@ -11,10 +13,10 @@ use crate::{backend::core::User, model::TransactionDelta};
 use {
     crate::{
         backend::{
-            core::{Article, Group, Settings},
+            core::{misc::METRICS, Article, Group, Settings, State},
             database::{DatabaseType, TransactionDB},
         },
-        model::{DatabaseId, GroupId},
+        model::{DatabaseId, GroupId, LimitViolation},
     },
     sqlx::Executor,
 };
@@ -51,6 +53,10 @@ impl Transaction {
         )
         .await?;
 
+        if let Some(article_id) = t_type_data {
+            Transaction::book_articles(&mut *conn, t_id, article_id).await?;
+        }
+
         let transaction_db = match TransactionDB::get(&mut *conn, t_id).await? {
             Some(val) => val,
             None => return Err(Error::new("Failed to find newly created transaction")),
@@ -69,8 +75,8 @@ impl Transaction {
         )
         .await?;
 
-        let mut users_too_low = Vec::<String>::new();
-        let mut users_too_high = Vec::<String>::new();
+        let mut users_too_low = Vec::<LimitViolation>::new();
+        let mut users_too_high = Vec::<LimitViolation>::new();
 
         for (key, value) in deltas.iter() {
             use crate::backend::database::{DBUSER_AUFLADUNG_ID, DBUSER_SNACKBAR_ID};
@@ -80,20 +86,40 @@ impl Transaction {
                 continue;
             }
 
-            if value.post_amount() > settings.accounts.upper_limit {
+            // personal limits take precedence over the global ones
+            let (upper_limit, upper_personal) = match key.upper_limit {
+                Some(limit) => (Some(limit.value), true),
+                None => (settings.accounts.upper_limit, false),
+            };
+            let (lower_limit, lower_personal) = match key.lower_limit {
+                Some(limit) => (limit.value, true),
+                None => (settings.accounts.lower_limit, false),
+            };
+
+            let post_amount = value.checked_post_amount()?.value;
+
+            if let Some(upper_limit) = upper_limit.filter(|limit| post_amount > *limit) {
                 if value.delta < 0 {
                     // allow users to loose money
                     continue;
                 }
 
-                users_too_high.push(key.nickname.clone());
-            } else if value.post_amount() < settings.accounts.lower_limit {
+                users_too_high.push(LimitViolation {
+                    nickname: key.nickname.clone(),
+                    limit: upper_limit.into(),
+                    personal: upper_personal,
+                });
+            } else if post_amount < lower_limit {
                 if value.delta > 0 {
                     // allow users to get money
                     continue;
                 }
 
-                users_too_low.push(key.nickname.clone());
+                users_too_low.push(LimitViolation {
+                    nickname: key.nickname.clone(),
+                    limit: lower_limit.into(),
+                    personal: lower_personal,
+                });
             }
         }
 
@@ -116,21 +142,186 @@ impl Transaction {
     }
 }
 
-/// Creates a Transaction. Returns the transaction and the money delta for the creating user
+#[cfg(feature = "ssr")]
+impl Transaction {
+    /// Creates a transaction for a user and publishes the resulting events.
+    /// Returns the transaction, the money delta for the user and the milestones it reached.
+    /// This is shared by [`create_transaction`] and other entry points like mqtt.
+    pub async fn create_for_user(
+        state: &State,
+        user_id: UserId,
+        money: Money,
+        transaction_type: TransactionType,
+    ) -> Result<(Transaction, Money, Vec<ReachedMilestone>), CreateTransactionError> {
+        type Error = CreateTransactionError;
+        use crate::{
+            backend::database::{DBGROUP_AUFLADUNG_ID, DBGROUP_SNACKBAR_ID},
+            model::ServerEvent,
+        };
+        use tracing::error;
+
+        if money.value < 0 {
+            return Err(Error::new("Money may not be negative"));
+        }
+
+        let settings = state.settings.load_full();
+
+        let db = state.db.lock().await;
+        let mut db_trans = match db.get_conn_transaction().await {
+            Ok(value) => value,
+            Err(e) => {
+                error!("Failed to get database handle: {}", e);
+                return Err(Error::new("Failed to get database handle!"));
+            }
+        };
+
+        let user = match User::get(&mut *db_trans, user_id).await {
+            Ok(value) => match value {
+                None => {
+                    return Err(Error::UserDoesNotExist(user_id));
+                }
+
+                Some(value) => value,
+            },
+            Err(e) => {
+                error!("Failed to lookup user: {e}");
+                return Err(Error::new("Failed to lookup user!"));
+            }
+        };
+
+        let user_group = match Group::get_user_group_id(&mut *db_trans, user_id).await {
+            Ok(value) => value,
+            Err(e) => {
+                error!("Failed to get user group: {}", e);
+                return Err(Error::new("Failed to get user group"));
+            }
+        };
+
+        let (sender_group_id, receiver_group_id) = match transaction_type {
+            TransactionType::Deposit => (DBGROUP_AUFLADUNG_ID, user_group),
+            TransactionType::Withdraw => (user_group, DBGROUP_AUFLADUNG_ID),
+            TransactionType::Bought(_) => (user_group, DBGROUP_SNACKBAR_ID),
+
+            _ => return Err(Error::new("Invalid state")),
+        };
+
+        let description = match transaction_type {
+            TransactionType::Deposit => None,
+            TransactionType::Withdraw => None,
+            TransactionType::Bought(article_id) => {
+                // this will result in a race-condition, because we have the db lock
+                // let article = get_article(article_id).await?;
+
+                match Article::get_single(&mut *db_trans, article_id).await? {
+                    None => return Err(CreateTransactionError::ArticleDoesNotExist(article_id)),
+                    Some(article) => Some(article.name),
+                }
+            }
+            TransactionType::Received(_) => None,
+            TransactionType::Sent(_) => None,
+            TransactionType::SentAndReceived(_) => None,
+        };
+
+        let (transaction_id, deltas) = Transaction::create(
+            &mut *db_trans,
+            sender_group_id,
+            receiver_group_id,
+            transaction_type,
+            description.clone(),
+            money,
+            &settings,
+        )
+        .await?;
+
+        if let TransactionType::Bought(_) = transaction_type {
+            Transaction::check_consumption_rules(
+                &mut *db_trans,
+                transaction_id,
+                &user,
+                &settings.consumption_rules,
+            )
+            .await?;
+        }
+
+        let transaction = match Transaction::get(&mut *db_trans, transaction_id, user_id).await {
+            Ok(val) => val,
+            Err(e) => {
+                error!("Failed to find transaction during DB-lookup: {}", e);
+                return Err(Error::new("Failed to find transaction!"));
+            }
+        };
+
+        let transaction = match transaction {
+            Some(val) => val,
+            None => {
+                error!("Failed to find transaction");
+                return Err(Error::new("Failed to find transaction!"));
+            }
+        };
+
+        match db_trans.commit().await {
+            Ok(_) => {}
+            Err(e) => {
+                error!("Failed to commit transaction: {}", e);
+                return Err(Error::new("Failed to commit transaction!"));
+            }
+        }
+
+        METRICS.transaction_booked(transaction_type);
+
+        state.events.publish_all(ServerEvent::transaction_created(
+            transaction_id,
+            transaction_type,
+            description,
+            money,
+            &deltas,
+            settings.accounts.low_balance_threshold,
+        ));
+
+        let user_delta = match deltas.get(&user) {
+            Some(value) => value,
+            None => {
+                error!("Failed to find user in deltas!");
+                return Err(Error::new("Failed to lookup deltas!"));
+            }
+        };
+
+        // the transaction is committed already, so a failed check only loses the milestones
+        let milestones = match db.get_conn().await {
+            Ok(mut conn) => {
+                Transaction::check_milestones(
+                    &mut *conn,
+                    transaction_id,
+                    transaction_type,
+                    user_id,
+                    user_delta,
+                    &settings.milestones,
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        };
+
+        let milestones = milestones.unwrap_or_else(|e| {
+            error!("Failed to check the milestones: {}", e);
+            Vec::new()
+        });
+
+        Ok((transaction, user_delta.delta.into(), milestones))
+    }
+}
+
+/// Creates a Transaction. Returns the transaction, the money delta for the creating user and the
+/// milestones it reached
 #[server]
 pub async fn create_transaction(
     user_id: UserId,
     money: Money,
     transaction_type: TransactionType,
-) -> Result<(Transaction, Money), CreateTransactionError> {
-    type Error = CreateTransactionError;
-    use crate::backend::{
-        core::ServerState,
-        database::{DBGROUP_AUFLADUNG_ID, DBGROUP_SNACKBAR_ID},
-    };
+) -> Result<(Transaction, Money, Vec<ReachedMilestone>), CreateTransactionError> {
+    use crate::backend::core::ServerState;
     use axum::http::StatusCode;
     use leptos_axum::ResponseOptions;
-    use tracing::error;
 
     let state: ServerState = expect_context();
 
@@ -138,114 +329,29 @@ pub async fn create_transaction(
 
     if money.value < 0 {
         response_opts.set_status(StatusCode::BAD_REQUEST);
-        return Err(Error::new("Money may not be negative"));
+        return Err(CreateTransactionError::new("Money may not be negative"));
     }
 
-    let db = state.db.lock().await;
-    let mut db_trans = match db.get_conn_transaction().await {
-        Ok(value) => value,
-        Err(e) => {
-            response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-            error!("Failed to get database handle: {}", e);
-            return Err(Error::new("Failed to get database handle!"));
-        }
-    };
+    if !state.get_pin_session(user_id).await?.is_unlocked() {
+        response_opts.set_status(StatusCode::FORBIDDEN);
+        return Err(CreateTransactionError::PinRequired(user_id));
+    }
 
-    let user = match User::get(&mut *db_trans, user_id).await {
-        Ok(value) => match value {
-            None => {
-                response_opts.set_status(StatusCode::BAD_REQUEST);
-                return Err(Error::UserDoesNotExist(user_id));
-            }
+    let result = Transaction::create_for_user(&state, user_id, money, transaction_type).await;
 
-            Some(value) => value,
-        },
-        Err(e) => {
-            response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-            error!("Failed to lookup user: {e}");
-            return Err(Error::new("Failed to lookup user!"));
-        }
-    };
-
-    let user_group = match Group::get_user_group_id(&mut *db_trans, user_id).await {
-        Ok(value) => value,
-        Err(e) => {
-            response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-            error!("Failed to get user group: {}", e);
-            return Err(Error::new("Failed to get user group"));
-        }
-    };
-
-    let (sender_group_id, receiver_group_id) = match transaction_type {
-        TransactionType::Deposit => (DBGROUP_AUFLADUNG_ID, user_group),
-        TransactionType::Withdraw => (user_group, DBGROUP_AUFLADUNG_ID),
-        TransactionType::Bought(_) => (user_group, DBGROUP_SNACKBAR_ID),
-
-        _ => return Err(Error::new("Invalid state")),
-    };
-
-    let description = match transaction_type {
-        TransactionType::Deposit => None,
-        TransactionType::Withdraw => None,
-        TransactionType::Bought(article_id) => {
-            // this will result in a race-condition, because we have the db lock
-            // let article = get_article(article_id).await?;
-
-            match Article::get(&db, article_id).await? {
-                None => return Err(CreateTransactionError::ArticleDoesNotExist(article_id)),
-                Some(article) => Some(article.name),
-            }
-        }
-        TransactionType::Received(_) => None,
-        TransactionType::Sent(_) => None,
-        TransactionType::SentAndReceived(_) => None,
-    };
-
-    let (transaction_id, deltas) = Transaction::create(
-        &mut *db_trans,
-        sender_group_id,
-        receiver_group_id,
-        transaction_type,
-        description,
-        money,
-        &state.settings,
-    )
-    .await?;
-
-    let transaction = match Transaction::get(&mut *db_trans, transaction_id, user_id).await {
-        Ok(val) => val,
-        Err(e) => {
-            response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-            error!("Failed to find transaction during DB-lookup: {}", e);
-            return Err(Error::new("Failed to find transaction!"));
+    match result {
+        Ok(_) => {}
+        Err(CreateTransactionError::UserDoesNotExist(_))
+        | Err(CreateTransactionError::MoneyError(_)) => {
+            response_opts.set_status(StatusCode::BAD_REQUEST);
         }
-    };
-
-    let transaction = match transaction {
-        Some(val) => val,
-        None => {
-            response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-            error!("Failed to find transaction");
-            return Err(Error::new("Failed to find transaction!"));
+        Err(CreateTransactionError::ConsumptionRuleViolated(_)) => {
+            response_opts.set_status(StatusCode::FORBIDDEN);
         }
-    };
-
-    match db_trans.commit().await {
-        Ok(_) => {}
-        Err(e) => {
+        Err(_) => {
             response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-            error!("Failed to commit transaction: {}", e);
-            return Err(Error::new("Failed to commit transaction!"));
         }
     }
 
-    let user_delta = match deltas.get(&user) {
-        Some(value) => value,
-        None => {
-            error!("Failed to find user in deltas!");
-            return Err(Error::new("Failed to lookup deltas!"));
-        }
-    };
-
-    Ok((transaction, user_delta.delta.into()))
+    result
 }
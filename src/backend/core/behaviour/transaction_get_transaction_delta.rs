@@ -7,9 +7,9 @@ use sqlx::Executor;
 use crate::{
     backend::{
         core::{Group, User},
-        database::{DBError, DatabaseType, TransactionDB},
+        database::{DatabaseType, TransactionDB},
     },
-    model::{Transaction, TransactionDelta, UserId},
+    model::{CreateTransactionError, Money, Transaction, TransactionDelta, UserId},
 };
 
 impl Transaction {
@@ -18,7 +18,7 @@ impl Transaction {
         sender_group: &Group,
         receiver_group: &Group,
         transaction_db: &TransactionDB,
-    ) -> Result<HashMap<User, TransactionDelta>, DBError>
+    ) -> Result<HashMap<User, TransactionDelta>, CreateTransactionError>
     where
         for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
     {
@@ -32,7 +32,7 @@ impl Transaction {
                 Some(val) => val,
                 None => {
                     error!("Failed to find a user that should exist! Id: {}", sender.id);
-                    return Err(DBError::new("Failed to find user"));
+                    return Err(CreateTransactionError::new("Failed to find user"));
                 }
             };
 
@@ -47,7 +47,7 @@ impl Transaction {
                         "Failed to find a user that should exist! Id: {}",
                         receiver.id
                     );
-                    return Err(DBError::new("Failed to find user"));
+                    return Err(CreateTransactionError::new("Failed to find user"));
                 }
             };
 
@@ -56,9 +56,6 @@ impl Transaction {
 
         let mut delta_map = HashMap::new();
 
-        let mut full_cost = transaction_db.money;
-        let cost_share = transaction_db.money / sender_group.members.len() as u64;
-
         for user in senders.iter().chain(receivers.iter()) {
             _ = delta_map.insert(
                 user.clone(),
@@ -69,67 +66,33 @@ impl Transaction {
             );
         }
 
-        for sender in senders.iter() {
+        let money = Money::try_from(transaction_db.money)?;
+
+        let sender_shares = money.allocate(senders.len())?;
+        let receiver_shares = money.allocate(receivers.len())?;
+
+        for (sender, share) in senders.iter().zip(sender_shares) {
             let user = match delta_map.get_mut(sender) {
                 Some(user) => user,
                 None => {
                     error!("Failed to find user in HashMap where it should exist!");
-                    return Err(DBError::new("Failed to find user"));
+                    return Err(CreateTransactionError::new("Failed to find user"));
                 }
             };
 
-            user.delta -= cost_share as i64;
-            full_cost -= cost_share;
+            user.delta = Money::from(user.delta).checked_sub(share)?.value;
         }
 
-        while full_cost > 0 {
-            for sender in senders.iter_mut() {
-                let user = match delta_map.get_mut(sender) {
-                    Some(user) => user,
-                    None => {
-                        error!("Failed to find user in HashMap where it should exist!");
-                        return Err(DBError::new("Failed to find user"));
-                    }
-                };
-                user.delta -= 1;
-                if full_cost == 0 {
-                    break;
-                }
-
-                full_cost -= 1;
-            }
-        }
-
-        let cost_share = transaction_db.money / receiver_group.members.len() as u64;
-
-        for receiver in receivers.iter_mut() {
+        for (receiver, share) in receivers.iter().zip(receiver_shares) {
             let user = match delta_map.get_mut(receiver) {
                 Some(user) => user,
                 None => {
                     error!("Failed to find user in HashMap where it should exist!");
-                    return Err(DBError::new("Failed to find user"));
+                    return Err(CreateTransactionError::new("Failed to find user"));
                 }
             };
-            user.delta += cost_share as i64;
-            full_cost += cost_share;
-        }
-
-        while full_cost < transaction_db.money {
-            for receiver in receivers.iter_mut() {
-                let user = match delta_map.get_mut(receiver) {
-                    Some(user) => user,
-                    None => {
-                        error!("Failed to find user in HashMap where it should exist!");
-                        return Err(DBError::new("Failed to find user"));
-                    }
-                };
-                user.delta += 1;
-                if full_cost == transaction_db.money {
-                    break;
-                }
 
-                full_cost += 1;
-            }
+            user.delta = Money::from(user.delta).checked_add(share)?.value;
         }
 
         Ok(delta_map)
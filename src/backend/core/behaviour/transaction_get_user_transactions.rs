@@ -8,7 +8,7 @@ use crate::backend::core::misc::custom_binary_encoding::Binary;
 
 #[cfg(feature = "ssr")]
 use crate::{
-    backend::database::{ArticleDB, DatabaseResponse, TransactionDB, DB},
+    backend::database::{ArticleDB, DBError, DatabaseResponse, TransactionDB, DB},
     model::TransactionType,
 };
 
@@ -80,7 +80,19 @@ impl Transaction {
                     use crate::backend::core::Group;
 
                     let sender_group = Group::get(&mut *conn, transaction.group_id).await?;
-                    transaction.money.value /= sender_group.members.len() as i64;
+
+                    // show the share of this user, the order matches the one used for booking
+                    let index = sender_group
+                        .members
+                        .iter()
+                        .position(|member| member.id == user_id.0)
+                        .unwrap_or_default();
+                    let shares = transaction
+                        .money
+                        .allocate(sender_group.members.len())
+                        .map_err(DBError::new)?;
+
+                    transaction.money = shares[index];
                 }
 
                 TransactionType::SentAndReceived(receiver_group) => {
@@ -99,7 +111,8 @@ impl Transaction {
                         &receiver_group,
                         &transaction_db,
                     )
-                    .await?;
+                    .await
+                    .map_err(DBError::new)?;
 
                     let user = User::get(&mut *conn, user_id).await?.unwrap();
 
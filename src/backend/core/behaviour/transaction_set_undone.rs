@@ -3,8 +3,11 @@ use {crate::model::UserId, leptos::prelude::*};
 #[cfg(feature = "ssr")]
 use {
     crate::{
-        backend::database::{DatabaseResponse, DatabaseType, TransactionDB},
-        model::Transaction,
+        backend::{
+            core::{Group, User},
+            database::{DBError, DatabaseResponse, DatabaseType, TransactionDB},
+        },
+        model::{GroupId, Money, Transaction},
     },
     sqlx::Executor,
 };
@@ -17,15 +20,52 @@ impl Transaction {
     {
         TransactionDB::set_undone(&mut *conn, self.id, new_value).await
     }
+
+    /// Books the deltas of the transaction in reverse and marks it as undone.
+    /// Returns the involved users.
+    pub async fn undo<T>(
+        conn: &mut T,
+        transaction_db: &TransactionDB,
+    ) -> DatabaseResponse<Vec<User>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        let (sender_group, receiver_group) = (
+            Group::get(&mut *conn, GroupId(transaction_db.sender)).await?,
+            Group::get(&mut *conn, GroupId(transaction_db.receiver)).await?,
+        );
+
+        // the same deltas that were booked when the transaction was created
+        let deltas = Transaction::get_transaction_delta(
+            &mut *conn,
+            &sender_group,
+            &receiver_group,
+            transaction_db,
+        )
+        .await
+        .map_err(DBError::new)?;
+
+        let mut users = Vec::<User>::new();
+
+        for (mut user, delta) in deltas.into_iter() {
+            let delta = Money::from(delta.delta)
+                .checked_neg()
+                .map_err(DBError::new)?;
+
+            user.add_money(&mut *conn, delta).await?;
+            users.push(user);
+        }
+
+        TransactionDB::set_undone(&mut *conn, transaction_db.id, true).await?;
+
+        Ok(users)
+    }
 }
 #[server]
 pub async fn undo_transaction(user_id: UserId, transaction_id: i64) -> Result<(), ServerFnError> {
     use crate::backend::core::ServerState;
-    use crate::{
-        backend::core::{behaviour::user_get::get_user, Group, User},
-        model::{GroupId, Money},
-    };
-    use tracing::{debug, error, trace, warn};
+    use crate::{backend::core::behaviour::user_get::get_user, model::ServerEvent};
+    use tracing::{debug, error, warn};
     let state: ServerState = expect_context();
     use axum::http::StatusCode;
     use leptos_axum::ResponseOptions;
@@ -39,8 +79,8 @@ pub async fn undo_transaction(user_id: UserId, transaction_id: i64) -> Result<()
 
     let user = get_user(user_id).await?;
 
-    let user = match user {
-        Some(user) => user,
+    match user {
+        Some(_) => {}
         None => {
             warn!("A user with id '{}' does not exist!", user_id);
             response_opts.set_status(StatusCode::BAD_REQUEST);
@@ -85,192 +125,32 @@ pub async fn undo_transaction(user_id: UserId, transaction_id: i64) -> Result<()
         return Err(ServerFnError::new("The transaction is already undone!"));
     }
 
-    let (receiver_group, sender_group) = (
-        match Group::get(&mut *db_trns, GroupId(transaction_db.receiver)).await {
-            Ok(val) => val,
-            Err(e) => {
-                warn!(
-                    "Failed to find receiver group with id '{}': {}",
-                    transaction_db.receiver, e
-                );
-                response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-                return Err(ServerFnError::new("Failed to find group!"));
-            }
-        },
-        match Group::get(&mut *db_trns, GroupId(transaction_db.sender)).await {
-            Ok(val) => val,
-            Err(e) => {
-                warn!(
-                    "Failed to find sender group with id '{}': {}",
-                    transaction_db.sender, e
-                );
-                response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-                return Err(ServerFnError::new("Failed to find group!"));
-            }
-        },
-    );
-
-    let money_shares_sender = transaction_db.money / sender_group.members.len() as u64;
-
-    let mut full_money = transaction_db.money;
-    trace!("full_money: {}", full_money);
-
-    let mut users_sender = Vec::<User>::new();
-    let mut users_receiver = Vec::<User>::new();
-
-    for user_db in sender_group.members.iter() {
-        let user = match User::get(&mut *db_trns, UserId(user_db.id)).await {
-            Ok(val) => val,
-            Err(e) => {
-                error!("Failed to lookup user: '{}'", e);
-                response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-                return Err(ServerFnError::new("Failed to find user"));
-            }
-        };
-
-        let user = match user {
-            Some(val) => val,
-            None => {
-                error!("Failed to find user with id '{}'", user_db.id);
-                response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-                return Err(ServerFnError::new("Failed to find user"));
-            }
-        };
-
-        users_sender.push(user);
-    }
-
-    for user_db in receiver_group.members.iter() {
-        let user = match User::get(&mut *db_trns, UserId(user_db.id)).await {
-            Ok(val) => val,
-            Err(e) => {
-                error!("Failed to lookup user: '{}'", e);
-                response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-                return Err(ServerFnError::new("Failed to find user"));
-            }
-        };
-
-        let user = match user {
-            Some(val) => val,
-            None => {
-                error!("Failed to find user with id '{}'", user_db.id);
-                response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-                return Err(ServerFnError::new("Failed to find user"));
-            }
-        };
-
-        users_receiver.push(user);
-    }
-
-    for user_sender in users_sender.iter_mut() {
-        match user_sender
-            .add_money(
-                &mut *db_trns,
-                Money {
-                    value: money_shares_sender as i64,
-                },
-            )
-            .await
-        {
-            Ok(val) => val,
-            Err(e) => {
-                error!(
-                    "Failed to add money to user '{}': {}",
-                    user_sender.nickname, e
-                );
-                response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-                return Err(ServerFnError::new("Failed to subtract money"));
-            }
+    let users = match Transaction::undo(&mut *db_trns, &transaction_db).await {
+        Ok(val) => val,
+        Err(e) => {
+            error!("Failed to undo transaction '{}': {}", transaction_db.id, e);
+            response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+            return Err(ServerFnError::new("Failed to undo transaction!"));
         }
+    };
 
-        full_money -= money_shares_sender;
-        trace!("full_money: {}", full_money);
-    }
-
-    while full_money > 0 {
-        for user_sender in users_sender.iter_mut() {
-            match user_sender
-                .add_money(&mut *db_trns, Money { value: 1 })
-                .await
-            {
-                Ok(val) => val,
-                Err(e) => {
-                    error!(
-                        "Failed to add money to user (fine-grained) '{}': {}",
-                        user.nickname, e
-                    );
-                    response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-                    return Err(ServerFnError::new("Failed to subtract money"));
-                }
-            };
-
-            trace!("full_money: {}", full_money);
-            if full_money == 0 {
-                break;
+    // undoing moves the money of every involved user, so their accounts have to be unlocked
+    for user in users.iter() {
+        match state.pin_sessions.get_session(&mut *db_trns, user.id).await {
+            Ok(session) if session.is_unlocked() => {}
+            Ok(_) => {
+                response_opts.set_status(StatusCode::FORBIDDEN);
+                return Err(ServerFnError::new(format!(
+                    "The PIN of {} is required to undo the transaction!",
+                    user.nickname
+                )));
             }
-
-            full_money -= 1;
-        }
-    }
-
-    let restore_money_share = transaction_db.money / users_receiver.len() as u64;
-
-    for user_receiver in users_receiver.iter_mut() {
-        match user_receiver
-            .add_money(
-                &mut *db_trns,
-                Money {
-                    value: -(restore_money_share as i64),
-                },
-            )
-            .await
-        {
-            Ok(val) => val,
             Err(e) => {
-                error!(
-                    "Failed to remove money from user '{}': {}",
-                    user_receiver.nickname, e
-                );
+                error!("Failed to get the pin session: {}", e);
                 response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-                return Err(ServerFnError::new("Failed to subtract money"));
+                return Err(ServerFnError::new("Failed to get the pin session!"));
             }
         }
-
-        full_money += restore_money_share;
-    }
-
-    while full_money < transaction_db.money {
-        for user_receiver in users_receiver.iter_mut() {
-            match user_receiver
-                .add_money(&mut *db_trns, Money { value: -1 })
-                .await
-            {
-                Ok(val) => val,
-                Err(e) => {
-                    error!(
-                        "Failed to remove money from user (fine-grained) '{}': {}",
-                        user.nickname, e
-                    );
-                    response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-                    return Err(ServerFnError::new("Failed to subtract money"));
-                }
-            };
-
-            if full_money == transaction_db.money {
-                break;
-            }
-
-            full_money += 1;
-        }
-    }
-
-    match TransactionDB::set_undone(&mut *db_trns, transaction_db.id, true).await {
-        Ok(_) => {}
-        Err(e) => {
-            error!("Failed to set transaction to undone: {}", e);
-            response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-            return Err(ServerFnError::new("Failed to update transaction!"));
-        }
     }
 
     match db_trns.commit().await {
@@ -282,5 +162,9 @@ pub async fn undo_transaction(user_id: UserId, transaction_id: i64) -> Result<()
         }
     }
 
+    state
+        .events
+        .publish(ServerEvent::transaction_undone(transaction_db.id, &users));
+
     Ok(())
 }
@@ -1,4 +1,4 @@
-use crate::backend::core::BarcodeDiff;
+use crate::backend::core::{ArticleComponent, BarcodeDiff};
 
 #[cfg(not(debug_assertions))]
 use crate::backend::core::misc::custom_binary_encoding::Binary;
@@ -12,10 +12,14 @@ pub async fn update_article(
     name: String,
     cost: String,
     barcodes: Option<Vec<BarcodeDiff>>,
+    components: Option<Vec<ArticleComponent>>,
 ) -> Result<(), ServerFnError> {
     use crate::{
-        backend::core::{behaviour::article_get::get_article, ServerState},
-        model::Money,
+        backend::{
+            core::{behaviour::article_get::get_article, Article, ServerState},
+            database::ArticleDB,
+        },
+        model::{Money, ServerEvent},
     };
     use axum::http::StatusCode;
     use leptos_axum::redirect;
@@ -27,7 +31,7 @@ pub async fn update_article(
 
     let mut article = get_article(id).await?;
 
-    let cost: Money = match cost.clone().try_into() {
+    let cost: Money = match (cost.as_str(), &state.settings.load().currency).try_into() {
         Ok(value) => value,
         Err(e) => {
             response_opts.set_status(StatusCode::BAD_REQUEST);
@@ -95,6 +99,81 @@ pub async fn update_article(
         }
     }
 
+    match components {
+        None => {}
+        Some(components) => {
+            let bundles_containing =
+                match ArticleDB::get_bundles_containing(&mut *db_transaction, article.id).await {
+                    Ok(value) => value,
+                    Err(e) => {
+                        response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+                        error!("Failed to fetch bundles containing article: {}", e);
+                        return Err(ServerFnError::new("Failed to fetch bundles!"));
+                    }
+                };
+
+            if !components.is_empty() && !bundles_containing.is_empty() {
+                response_opts.set_status(StatusCode::BAD_REQUEST);
+                return Err(ServerFnError::new(
+                    "An article that is part of a bundle cannot be a bundle itself!",
+                ));
+            }
+
+            for component in components.iter() {
+                if component.quantity <= 0 {
+                    response_opts.set_status(StatusCode::BAD_REQUEST);
+                    return Err(ServerFnError::new("Component quantity has to be positive!"));
+                }
+
+                if component.article_id == article.id {
+                    response_opts.set_status(StatusCode::BAD_REQUEST);
+                    return Err(ServerFnError::new("A bundle cannot contain itself!"));
+                }
+
+                let component_article =
+                    match Article::get_single(&mut *db_transaction, component.article_id).await {
+                        Ok(value) => value,
+                        Err(e) => {
+                            response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+                            error!("Failed to fetch component article: {}", e);
+                            return Err(ServerFnError::new("Failed to fetch component article!"));
+                        }
+                    };
+
+                match component_article {
+                    None => {
+                        response_opts.set_status(StatusCode::BAD_REQUEST);
+                        return Err(ServerFnError::new(format!(
+                            "Unknown Article id '{}'",
+                            component.article_id
+                        )));
+                    }
+                    Some(value) if value.is_bundle() => {
+                        response_opts.set_status(StatusCode::BAD_REQUEST);
+                        return Err(ServerFnError::new(format!(
+                            "'{}' is a bundle and cannot be part of another bundle!",
+                            value.name
+                        )));
+                    }
+                    Some(_) => {}
+                }
+            }
+
+            let result = article
+                .set_components(&mut *db_transaction, components)
+                .await;
+
+            if let Err(e) = result {
+                response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+                error!("Failed to set components: {}", e);
+                return Err(ServerFnError::new(format!(
+                    "Failed to set components: {}",
+                    e
+                )));
+            }
+        }
+    }
+
     match db_transaction.commit().await {
         Ok(_) => {}
         Err(e) => {
@@ -104,6 +183,10 @@ pub async fn update_article(
         }
     }
 
+    state
+        .events
+        .publish(ServerEvent::ArticleUpdated { article_id: id });
+
     redirect("/articles");
 
     Ok(())
@@ -2,16 +2,27 @@ use leptos::prelude::*;
 
 use crate::model::UserId;
 
+/// the minimum length of a PIN
+pub const MIN_PIN_LENGTH: usize = 4;
+
 #[server]
+#[allow(clippy::too_many_arguments)]
 pub async fn update_user(
     id: UserId,
     nickname: String,
     card_number: String,
+    email: String,
+    show_in_stats: Option<String>,
+    pin: String,
+    remove_pin: Option<String>,
+    purchase_sound: Option<String>,
+    deposit_sound: Option<String>,
 ) -> Result<(), ServerFnError> {
     use crate::backend::core::behaviour::user_get::get_user;
-    use crate::backend::core::ServerState;
+    use crate::backend::core::{misc::hash_pin, ServerState};
     let state: ServerState = expect_context();
     use crate::backend::core::User;
+    use crate::model::{DatabaseId, ServerEvent};
     use axum::http::StatusCode;
     use leptos_axum::redirect;
     use leptos_axum::ResponseOptions;
@@ -37,6 +48,21 @@ pub async fn update_user(
         }
     };
 
+    match state.get_pin_session(id).await {
+        Ok(session) if session.is_unlocked() => {}
+        Ok(_) => {
+            response_opts.set_status(StatusCode::FORBIDDEN);
+            return Err(ServerFnError::new(
+                "The PIN is required to change the settings!",
+            ));
+        }
+        Err(e) => {
+            error!("Failed to get the pin session: {e}");
+            response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+            return Err(ServerFnError::new("Failed to get the pin session!"));
+        }
+    }
+
     match User::get_by_card_number(&*state.db.lock().await, card_number.clone()).await {
         Ok(value) => match value {
             None => {}
@@ -63,6 +89,61 @@ pub async fn update_user(
         _ => Some(card_number),
     };
 
+    let email = match email.trim() {
+        "" => None,
+        email => match email.parse::<lettre::Address>() {
+            Ok(_) => Some(email.to_string()),
+            Err(e) => {
+                warn!("Invalid email address '{}': {}", email, e);
+                response_opts.set_status(StatusCode::BAD_REQUEST);
+                return Err(ServerFnError::new("The email address is invalid!"));
+            }
+        },
+    };
+
+    // an empty PIN keeps the current one
+    let pin_hash = match (remove_pin.is_some(), pin.trim()) {
+        (true, _) => Some(None),
+        (false, "") => None,
+        (false, pin) if pin.chars().count() < MIN_PIN_LENGTH => {
+            response_opts.set_status(StatusCode::BAD_REQUEST);
+            return Err(ServerFnError::new(format!(
+                "The PIN needs at least {MIN_PIN_LENGTH} characters!"
+            )));
+        }
+        (false, pin) => match hash_pin(pin) {
+            Ok(value) => Some(Some(value)),
+            Err(e) => {
+                error!("Failed to hash the PIN: {e}");
+                response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+                return Err(ServerFnError::new("Failed to set the PIN!"));
+            }
+        },
+    };
+
+    // a missing field keeps the current sound, an empty one falls back to the default sounds
+    let parse_sound = |sound: Option<String>,
+                       current: Option<DatabaseId>|
+     -> Result<Option<DatabaseId>, ServerFnError> {
+        match sound.as_deref().map(str::trim) {
+            None => Ok(current),
+            Some("") => Ok(None),
+            Some(sound) => match sound.parse::<DatabaseId>() {
+                Ok(value) => Ok(Some(value)),
+                Err(e) => {
+                    warn!("Invalid sound id '{}': {}", sound, e);
+                    response_opts.set_status(StatusCode::BAD_REQUEST);
+                    Err(ServerFnError::new(format!(
+                        "The sound '{sound}' is invalid!"
+                    )))
+                }
+            },
+        }
+    };
+
+    let purchase_sound_id = parse_sound(purchase_sound, user.purchase_sound_id)?;
+    let deposit_sound_id = parse_sound(deposit_sound, user.deposit_sound_id)?;
+
     let db = &*state.db.lock().await;
 
     let mut db_trans = match db.get_conn_transaction().await {
@@ -97,6 +178,56 @@ pub async fn update_user(
         }
     }
 
+    if user.email != email {
+        match user.set_email(&mut *db_trans, email).await {
+            Ok(_) => {}
+            Err(e) => {
+                response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+                error!("Failed to set a new email address: {}", e);
+                return Err(ServerFnError::new("Failed to set a new email address!"));
+            }
+        }
+    }
+
+    // checkboxes are only submitted if they are checked
+    let show_in_stats = show_in_stats.is_some();
+
+    if user.show_in_stats != show_in_stats {
+        match user.set_show_in_stats(&mut *db_trans, show_in_stats).await {
+            Ok(_) => {}
+            Err(e) => {
+                response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+                error!("Failed to set statistics visibility: {}", e);
+                return Err(ServerFnError::new("Failed to set statistics visibility!"));
+            }
+        }
+    }
+
+    if let Some(pin_hash) = pin_hash {
+        match user.set_pin_hash(&mut *db_trans, pin_hash).await {
+            Ok(_) => {}
+            Err(e) => {
+                response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+                error!("Failed to set the PIN: {}", e);
+                return Err(ServerFnError::new("Failed to set the PIN!"));
+            }
+        }
+    }
+
+    if user.purchase_sound_id != purchase_sound_id || user.deposit_sound_id != deposit_sound_id {
+        match user
+            .set_sound_ids(&mut *db_trans, purchase_sound_id, deposit_sound_id)
+            .await
+        {
+            Ok(_) => {}
+            Err(e) => {
+                response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+                error!("Failed to set the sounds: {}", e);
+                return Err(ServerFnError::new("Failed to set the sounds!"));
+            }
+        }
+    }
+
     match db_trans.commit().await {
         Ok(_) => {}
         Err(e) => {
@@ -108,6 +239,10 @@ pub async fn update_user(
         }
     }
 
+    state
+        .events
+        .publish(ServerEvent::UserUpdated { user_id: id });
+
     redirect(&format!("/user/{}", id));
 
     Ok(())
@@ -3,7 +3,7 @@
 use crate::{backend::core::User, model::Money};
 
 use {
-    crate::backend::database::{DatabaseResponse, DatabaseType},
+    crate::backend::database::{DBError, DatabaseResponse, DatabaseType},
     sqlx::Executor,
 };
 
@@ -12,8 +12,8 @@ impl User {
     where
         for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
     {
-        let new_money = self.money.value + money.value;
+        let new_money = self.money.checked_add(money).map_err(DBError::new)?;
 
-        self.set_money(conn, new_money).await
+        self.set_money(conn, new_money.value).await
     }
 }
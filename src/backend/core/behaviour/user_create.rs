@@ -42,6 +42,7 @@ pub async fn create_user(username: String) -> Result<(), ServerFnError> {
     use crate::backend::core::ServerState;
     let state: ServerState = expect_context();
 
+    use crate::model::ServerEvent;
     use axum::http::StatusCode;
     use leptos_axum::redirect;
     use leptos_axum::ResponseOptions;
@@ -56,7 +57,7 @@ pub async fn create_user(username: String) -> Result<(), ServerFnError> {
     }
     let username = username.trim().to_string();
 
-    let user_id = match User::create(&*state.db.lock().await, username, None).await {
+    let user_id = match User::create(&*state.db.lock().await, username.clone(), None).await {
         Ok(value) => value,
         Err(e) => {
             response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
@@ -65,6 +66,11 @@ pub async fn create_user(username: String) -> Result<(), ServerFnError> {
         }
     };
 
+    state.events.publish(ServerEvent::UserCreated {
+        user_id,
+        nickname: username,
+    });
+
     redirect(&format!("/user/{}", user_id));
 
     Ok(())
@@ -42,7 +42,7 @@ pub async fn get_all_users() -> Result<Vec<User>, ServerFnError> {
     let response_opts: ResponseOptions = expect_context();
 
     let users = match User::get_all(&*state.db.lock().await).await {
-        Ok(users) => users,
+        Ok(users) => users.into_iter().map(User::redact).collect(),
         Err(err) => {
             let err = err.to_string();
             error!("Could not fetch users: {}", err);
@@ -44,7 +44,7 @@ pub async fn get_user_by_barcode(barcode_string: String) -> Result<Option<User>,
     }
 
     let user = match User::get_by_card_number(&*state.db.lock().await, barcode_string).await {
-        Ok(user) => user,
+        Ok(user) => user.map(User::redact),
         Err(err) => {
             let err = err.to_string();
             error!("Could not fetch user: {}", err);
@@ -22,15 +22,33 @@ impl User {
                     id,
                     nickname,
                     money,
+                    show_in_stats,
+                    lower_limit,
+                    upper_limit,
+                    is_minor,
                     ..
                 } = value;
                 let card_number = UserDB::get_card_number(&mut *conn, id).await?;
+                let email = UserDB::get_email(&mut *conn, id)
+                    .await?
+                    .map(|email| email.email);
+                let has_pin = UserDB::get_pin_hash(&mut *conn, id).await?.is_some();
+                let (purchase_sound_id, deposit_sound_id) =
+                    UserDB::get_sound_ids(&mut *conn, id).await?;
 
                 Ok(Some(User {
                     id: UserId(id),
                     nickname,
                     card_number,
+                    email,
                     money: money.into(),
+                    show_in_stats,
+                    lower_limit: lower_limit.map(Into::into),
+                    upper_limit: upper_limit.map(Into::into),
+                    is_minor,
+                    has_pin,
+                    purchase_sound_id,
+                    deposit_sound_id,
                 }))
             }
         }
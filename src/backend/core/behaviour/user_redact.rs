@@ -0,0 +1,13 @@
+#![cfg(feature = "ssr")]
+
+use crate::backend::core::User;
+
+impl User {
+    /// Removes what only the unlocked user may see before the user is sent to a client.
+    /// The card number unlocks the account, see `create_pin_session`, the email address is private.
+    pub fn redact(mut self) -> Self {
+        self.card_number = None;
+        self.email = None;
+        self
+    }
+}
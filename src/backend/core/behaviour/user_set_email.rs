@@ -0,0 +1,35 @@
+#![cfg(feature = "ssr")]
+
+use rand::{distr::Alphanumeric, Rng};
+use sqlx::Executor;
+
+use crate::backend::{
+    core::User,
+    database::{DatabaseResponse, DatabaseType, UserDB},
+};
+
+/// length of the token in the opt-out link of the debt reminders
+const OPT_OUT_TOKEN_LENGTH: usize = 32;
+
+impl User {
+    pub async fn set_email<T>(
+        &mut self,
+        conn: &mut T,
+        new_value: Option<String>,
+    ) -> DatabaseResponse<()>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        let opt_out_token = rand::rng()
+            .sample_iter(&Alphanumeric)
+            .take(OPT_OUT_TOKEN_LENGTH)
+            .map(char::from)
+            .collect::<String>();
+
+        UserDB::set_email(&mut *conn, self.id, new_value.clone(), opt_out_token).await?;
+
+        self.email = new_value;
+
+        Ok(())
+    }
+}
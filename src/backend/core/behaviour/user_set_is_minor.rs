@@ -0,0 +1,20 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::Executor;
+
+use crate::backend::{
+    core::User,
+    database::{DatabaseResponse, DatabaseType, UserDB},
+};
+impl User {
+    pub async fn set_is_minor<T>(&mut self, conn: &mut T, new_value: bool) -> DatabaseResponse<()>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        UserDB::set_is_minor(&mut *conn, self.id, new_value).await?;
+
+        self.is_minor = new_value;
+
+        Ok(())
+    }
+}
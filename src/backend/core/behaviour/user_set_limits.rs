@@ -0,0 +1,35 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::Executor;
+
+use crate::{
+    backend::{
+        core::User,
+        database::{DatabaseResponse, DatabaseType, UserDB},
+    },
+    model::Money,
+};
+impl User {
+    pub async fn set_limits<T>(
+        &mut self,
+        conn: &mut T,
+        lower_limit: Option<Money>,
+        upper_limit: Option<Money>,
+    ) -> DatabaseResponse<()>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        UserDB::set_limits(
+            &mut *conn,
+            self.id,
+            lower_limit.map(|limit| limit.value),
+            upper_limit.map(|limit| limit.value),
+        )
+        .await?;
+
+        self.lower_limit = lower_limit;
+        self.upper_limit = upper_limit;
+
+        Ok(())
+    }
+}
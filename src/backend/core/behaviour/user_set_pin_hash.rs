@@ -0,0 +1,26 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::Executor;
+
+use crate::backend::{
+    core::User,
+    database::{DatabaseResponse, DatabaseType, UserDB},
+};
+impl User {
+    pub async fn set_pin_hash<T>(
+        &mut self,
+        conn: &mut T,
+        new_value: Option<String>,
+    ) -> DatabaseResponse<()>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        let has_pin = new_value.is_some();
+
+        UserDB::set_pin_hash(&mut *conn, self.id, new_value).await?;
+
+        self.has_pin = has_pin;
+
+        Ok(())
+    }
+}
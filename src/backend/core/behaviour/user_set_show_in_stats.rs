@@ -0,0 +1,24 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::Executor;
+
+use crate::backend::{
+    core::User,
+    database::{DatabaseResponse, DatabaseType, UserDB},
+};
+impl User {
+    pub async fn set_show_in_stats<T>(
+        &mut self,
+        conn: &mut T,
+        new_value: bool,
+    ) -> DatabaseResponse<()>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        UserDB::set_show_in_stats(&mut *conn, self.id, new_value).await?;
+
+        self.show_in_stats = new_value;
+
+        Ok(())
+    }
+}
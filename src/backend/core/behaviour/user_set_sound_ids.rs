@@ -0,0 +1,29 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::Executor;
+
+use crate::{
+    backend::{
+        core::User,
+        database::{DatabaseResponse, DatabaseType, UserDB},
+    },
+    model::DatabaseId,
+};
+impl User {
+    pub async fn set_sound_ids<T>(
+        &mut self,
+        conn: &mut T,
+        purchase_sound_id: Option<DatabaseId>,
+        deposit_sound_id: Option<DatabaseId>,
+    ) -> DatabaseResponse<()>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        UserDB::set_sound_ids(&mut *conn, self.id, purchase_sound_id, deposit_sound_id).await?;
+
+        self.purchase_sound_id = purchase_sound_id;
+        self.deposit_sound_id = deposit_sound_id;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use {
+    crate::model::{UserId, UserStatistics},
+    leptos::prelude::*,
+};
+
+#[cfg(not(debug_assertions))]
+use crate::backend::core::misc::custom_binary_encoding::Binary;
+
+#[cfg(feature = "ssr")]
+use crate::backend::database::{DBError, DatabaseResponse, DB};
+
+#[cfg(feature = "ssr")]
+const USER_STATISTICS_WEEKS: i64 = 26;
+
+#[cfg(feature = "ssr")]
+impl UserStatistics {
+    pub async fn get(db: &DB, user_id: UserId) -> DatabaseResponse<Self> {
+        use std::collections::{BTreeMap, HashMap};
+
+        use chrono::{Duration, Utc};
+
+        use crate::{
+            backend::{
+                core::misc::{
+                    statistics::{last_periods, month_label, top_articles, week_label},
+                    EffectivePriceCache,
+                },
+                database::TransactionDB,
+            },
+            model::{Money, StatisticPoint},
+        };
+
+        let mut conn = db.get_conn().await?;
+        let mut prices = EffectivePriceCache::default();
+        let now = Utc::now();
+
+        let purchases = TransactionDB::get_purchases(&mut *conn, Some(user_id)).await?;
+        let booked_articles = TransactionDB::get_booked_articles(&mut *conn, Some(user_id)).await?;
+
+        let mut total_spent = Money::default();
+        let mut spent_per_month = BTreeMap::<String, Money>::new();
+
+        for purchase in purchases.iter() {
+            let price: Money = prices
+                .get(&mut *conn, purchase.article_id, purchase.timestamp)
+                .await?
+                .into();
+
+            total_spent = total_spent.checked_add(price).map_err(DBError::new)?;
+
+            let month = spent_per_month
+                .entry(month_label(purchase.timestamp.date_naive()))
+                .or_default();
+            *month = month.checked_add(price).map_err(DBError::new)?;
+        }
+
+        let weeks = match purchases.first() {
+            None => 1,
+            Some(first) => ((now - first.timestamp).num_days() / 7 + 1).max(1),
+        };
+
+        let mut consumption_per_week = HashMap::<String, i64>::new();
+        for article in booked_articles.iter() {
+            *consumption_per_week
+                .entry(week_label(article.timestamp.date_naive()))
+                .or_default() += article.amount;
+        }
+
+        Ok(UserStatistics {
+            total_spent,
+            average_per_week: Money {
+                value: total_spent.value / weeks,
+            },
+            spent_per_month: spent_per_month
+                .into_iter()
+                .map(|(label, value)| StatisticPoint { label, value })
+                .collect(),
+            consumption_per_week: last_periods(
+                &consumption_per_week,
+                now,
+                USER_STATISTICS_WEEKS,
+                Duration::weeks(1),
+                week_label,
+            ),
+            top_articles: top_articles(&mut *conn, &mut prices, &booked_articles).await?,
+        })
+    }
+}
+
+#[cfg_attr(not(debug_assertions), server(input=Binary, output=Binary))]
+#[cfg_attr(debug_assertions, server)]
+pub async fn get_user_statistics(user_id: UserId) -> Result<UserStatistics, ServerFnError> {
+    use crate::backend::core::ServerState;
+    let state: ServerState = expect_context();
+    use axum::http::StatusCode;
+    use leptos_axum::ResponseOptions;
+    use tracing::error;
+
+    let response_opts: ResponseOptions = expect_context();
+
+    let db = state.db.lock().await;
+
+    match UserStatistics::get(&db, user_id).await {
+        Ok(value) => Ok(value),
+        Err(e) => {
+            response_opts.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+            error!("Failed to compute user statistics: {}", e);
+            Err(ServerFnError::new("Failed to compute user statistics!"))
+        }
+    }
+}
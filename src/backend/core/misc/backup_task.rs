@@ -0,0 +1,230 @@
+#![cfg(feature = "ssr")]
+
+use std::{
+    cmp::Reverse,
+    fs::File,
+    io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use tokio::sync::Mutex;
+use tracing::{error, info, warn};
+
+use crate::backend::{
+    core::{BackupSettings, ServerState},
+    database::{DB, MIGRATOR},
+};
+
+const FILE_PREFIX: &str = "strichliste-";
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%d_%H-%M-%S";
+
+/// Writes a verified backup into the backup directory and removes the backups that are no
+/// longer kept. Returns the path of the new backup.
+pub async fn create_backup(db: &Mutex<DB>, settings: &BackupSettings) -> Result<PathBuf, String> {
+    let directory = &settings.directory;
+
+    std::fs::create_dir_all(directory)
+        .map_err(|e| format!("Failed to create directory '{}': {e}", directory.display()))?;
+
+    let path = directory.join(format!(
+        "{FILE_PREFIX}{}.sqlite",
+        Utc::now().format(TIMESTAMP_FORMAT)
+    ));
+
+    db.lock()
+        .await
+        .backup_into(&path)
+        .await
+        .map_err(|e| format!("Failed to write backup '{}': {e}", path.display()))?;
+
+    if let Err(e) = DB::verify_file(&path).await {
+        _ = std::fs::remove_file(&path);
+        return Err(format!("The backup '{}' is broken: {e}", path.display()));
+    }
+
+    let settings = settings.clone();
+
+    tokio::task::spawn_blocking(move || {
+        let path = match settings.gzip {
+            false => path,
+            true => {
+                let target = with_suffix(&path, ".gz");
+
+                gzip(&path, &target)
+                    .map_err(|e| format!("Failed to compress '{}': {e}", path.display()))?;
+                _ = std::fs::remove_file(&path);
+
+                target
+            }
+        };
+
+        prune_backups(&settings)?;
+
+        Ok(path)
+    })
+    .await
+    .map_err(|e| format!("Failed to finish backup: {e}"))?
+}
+
+/// Returns the backups that are neither among the `keep_last` newest ones nor the newest one of
+/// the last `keep_daily` days that have a backup. The newest backup is always kept.
+pub fn expired_backups(
+    mut backups: Vec<(DateTime<Utc>, PathBuf)>,
+    keep_last: usize,
+    keep_daily: usize,
+) -> Vec<PathBuf> {
+    backups.sort_by_key(|(timestamp, _)| Reverse(*timestamp));
+
+    let mut days = Vec::new();
+
+    backups
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, (time, path))| {
+            let day = time.date_naive();
+            let newest_of_day = !days.contains(&day);
+
+            if newest_of_day {
+                days.push(day);
+            }
+
+            let keep = index < keep_last.max(1) || (newest_of_day && days.len() <= keep_daily);
+
+            (!keep).then_some(path)
+        })
+        .collect()
+}
+
+fn list_backups(directory: &Path) -> Result<Vec<(DateTime<Utc>, PathBuf)>, String> {
+    let entries = std::fs::read_dir(directory)
+        .map_err(|e| format!("Failed to read directory '{}': {e}", directory.display()))?;
+
+    let backups = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            let timestamp = name.strip_prefix(FILE_PREFIX)?;
+            let timestamp = timestamp
+                .strip_suffix(".sqlite.gz")
+                .or_else(|| timestamp.strip_suffix(".sqlite"))?;
+
+            let time = NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT).ok()?;
+
+            Some((time.and_utc(), entry.path()))
+        })
+        .collect();
+
+    Ok(backups)
+}
+
+fn prune_backups(settings: &BackupSettings) -> Result<(), String> {
+    let backups = list_backups(&settings.directory)?;
+
+    for path in expired_backups(backups, settings.keep_last, settings.keep_daily) {
+        match std::fs::remove_file(&path) {
+            Ok(_) => info!("Removed old backup '{}'", path.display()),
+            Err(e) => warn!("Failed to remove old backup '{}': {}", path.display(), e),
+        }
+    }
+
+    Ok(())
+}
+
+/// Periodically writes backups. Returns immediately if the backups are not configured.
+pub async fn run_backups(state: ServerState) {
+    // read once, changing the backups needs a restart
+    let loaded = state.settings.load_full();
+    let settings = match &loaded.backups {
+        Some(value) => value,
+        None => return,
+    };
+
+    let mut interval =
+        tokio::time::interval(Duration::from_secs(settings.interval_minutes.max(1) * 60));
+
+    loop {
+        interval.tick().await;
+
+        match create_backup(&state.db, settings).await {
+            Ok(path) => info!("Wrote backup '{}'", path.display()),
+            Err(e) => error!("{}", e),
+        }
+    }
+}
+
+/// Replaces the database at `db_path` with a (gzipped) backup after verifying it.
+/// The server must not be running. The replaced database is kept as
+/// `<db_path>.before-restore-<time>`, its path is returned if there was one.
+pub async fn restore_backup(db_path: &Path, backup: &Path) -> Result<Option<PathBuf>, String> {
+    let staging = with_suffix(db_path, ".restore");
+
+    let copied = match backup
+        .extension()
+        .is_some_and(|extension| extension == "gz")
+    {
+        true => gunzip(backup, &staging),
+        false => std::fs::copy(backup, &staging).map(|_| ()),
+    };
+
+    copied.map_err(|e| format!("Failed to read '{}': {e}", backup.display()))?;
+
+    let version = match DB::verify_file(&staging).await {
+        Ok(value) => value,
+        Err(e) => {
+            _ = std::fs::remove_file(&staging);
+            return Err(format!("Refusing to restore '{}': {e}", backup.display()));
+        }
+    };
+
+    if MIGRATOR.iter().any(|migration| migration.version > version) {
+        info!("The backup is from an older version, it will be migrated on the next start");
+    }
+
+    let kept = match db_path.exists() {
+        false => None,
+        true => {
+            let kept = with_suffix(
+                db_path,
+                &format!(".before-restore-{}", Utc::now().format(TIMESTAMP_FORMAT)),
+            );
+
+            // the server is stopped, so moving the wal along keeps the old database complete
+            for suffix in ["", "-wal", "-shm"] {
+                let from = with_suffix(db_path, suffix);
+
+                if from.exists() {
+                    std::fs::rename(&from, with_suffix(&kept, suffix))
+                        .map_err(|e| format!("Failed to move '{}': {e}", from.display()))?;
+                }
+            }
+
+            Some(kept)
+        }
+    };
+
+    std::fs::rename(&staging, db_path)
+        .map_err(|e| format!("Failed to move '{}': {e}", staging.display()))?;
+
+    Ok(kept)
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+fn gzip(from: &Path, to: &Path) -> io::Result<()> {
+    let mut encoder = GzEncoder::new(File::create(to)?, Compression::default());
+    io::copy(&mut File::open(from)?, &mut encoder)?;
+    encoder.finish()?.sync_all()
+}
+
+fn gunzip(from: &Path, to: &Path) -> io::Result<()> {
+    let mut decoder = GzDecoder::new(File::open(from)?);
+    io::copy(&mut decoder, &mut File::create(to)?)?;
+    Ok(())
+}
@@ -1,6 +1,6 @@
 #![cfg(feature = "ssr")]
 use rand::seq::IndexedRandom;
 
-pub fn choose_random_item(vec: &[String]) -> Option<&String> {
+pub fn choose_random_item<T>(vec: &[T]) -> Option<&T> {
     vec.choose(&mut rand::rng())
 }
@@ -0,0 +1,48 @@
+#![cfg(feature = "ssr")]
+
+use serde::Serialize;
+
+use crate::backend::core::ExportFormat;
+
+/// Something that can be written as a line of a csv file
+pub trait CsvRecord {
+    fn header() -> Vec<&'static str>;
+    fn fields(&self) -> Vec<String>;
+}
+
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn to_csv<T: CsvRecord>(records: &[T]) -> String {
+    let mut result = T::header().join(",");
+    result.push('\n');
+
+    for record in records.iter() {
+        let line = record
+            .fields()
+            .iter()
+            .map(|field| escape_csv_field(field))
+            .collect::<Vec<String>>()
+            .join(",");
+
+        result.push_str(&line);
+        result.push('\n');
+    }
+
+    result
+}
+
+pub fn encode_export<T: CsvRecord + Serialize>(
+    format: ExportFormat,
+    records: &[T],
+) -> Result<String, serde_json::Error> {
+    match format {
+        ExportFormat::Csv => Ok(to_csv(records)),
+        ExportFormat::Json => serde_json::to_string_pretty(records),
+    }
+}
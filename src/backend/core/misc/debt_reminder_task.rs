@@ -0,0 +1,90 @@
+#![cfg(feature = "ssr")]
+
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::sync::Mutex;
+use tracing::{error, info, warn};
+
+use crate::{
+    backend::{
+        core::{misc::Mailer, DebtReminder, ReminderSettings, ServerState},
+        database::DB,
+    },
+    model::Currency,
+};
+
+/// Sends all due reminders and returns how many have been sent.
+/// The database is not locked while talking to the smtp server.
+pub async fn send_debt_reminders(
+    db: &Mutex<DB>,
+    settings: &ReminderSettings,
+    currency: &Currency,
+    mailer: &Mailer,
+) -> Result<usize, String> {
+    let now = Utc::now();
+
+    let due = {
+        let db = db.lock().await;
+        DebtReminder::get_due(&db, settings, now)
+            .await
+            .map_err(|e| format!("Failed to find due reminders: {e}"))?
+    };
+
+    let mut sent = 0;
+
+    for reminder in due.iter() {
+        if let Err(e) = reminder.send(mailer, settings, currency).await {
+            warn!("Failed to send reminder to '{}': {}", reminder.nickname, e);
+            continue;
+        }
+
+        let db = db.lock().await;
+        let mut conn = db
+            .get_conn()
+            .await
+            .map_err(|e| format!("Failed to get database handle: {e}"))?;
+
+        reminder
+            .mark_sent(&mut *conn, now)
+            .await
+            .map_err(|e| format!("Failed to record sent reminder: {e}"))?;
+
+        sent += 1;
+    }
+
+    Ok(sent)
+}
+
+/// Periodically sends the debt reminders. Returns immediately if smtp or the reminders are not configured.
+pub async fn run_debt_reminders(state: ServerState) {
+    // read once, changing the reminders needs a restart
+    let settings = state.settings.load_full();
+
+    let (smtp, reminders) = match (&settings.smtp, &settings.reminders) {
+        (Some(smtp), Some(reminders)) => (smtp, reminders),
+        _ => return,
+    };
+
+    let mailer = match Mailer::new(smtp) {
+        Ok(value) => value,
+        Err(e) => {
+            error!("Debt reminders are disabled: {}", e);
+            return;
+        }
+    };
+
+    let mut interval = tokio::time::interval(Duration::from_secs(
+        reminders.check_interval_minutes.max(1) * 60,
+    ));
+
+    loop {
+        interval.tick().await;
+
+        match send_debt_reminders(&state.db, reminders, &settings.currency, &mailer).await {
+            Ok(0) => {}
+            Ok(sent) => info!("Sent {} debt reminders", sent),
+            Err(e) => error!("{}", e),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "ssr")]
+
+use std::collections::{hash_map::Entry, HashMap};
+
+use chrono::{DateTime, Utc};
+use sqlx::Executor;
+
+use crate::{
+    backend::database::{ArticleCostMapDB, ArticleDB, DatabaseResponse, DatabaseType},
+    model::DatabaseId,
+};
+
+/// Resolves the price an article had at a given point in time.
+/// Loads the price history of every article only once, which matters when going over all transactions.
+#[derive(Default)]
+pub struct EffectivePriceCache {
+    histories: HashMap<DatabaseId, Vec<ArticleCostMapDB>>,
+}
+
+impl EffectivePriceCache {
+    pub async fn get<T>(
+        &mut self,
+        conn: &mut T,
+        article_id: DatabaseId,
+        timestamp: DateTime<Utc>,
+    ) -> DatabaseResponse<i64>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        if let Entry::Vacant(entry) = self.histories.entry(article_id) {
+            _ = entry.insert(ArticleDB::get_cost_history(&mut *conn, article_id).await?);
+        }
+
+        let history = &self.histories[&article_id];
+
+        // same semantics as ArticleDB::get_effective_cost, but falls back to the first known price
+        let cost = history
+            .iter()
+            .rev()
+            .find(|elem| elem.effective_since < timestamp)
+            .or(history.first())
+            .map(|elem| elem.cost)
+            .unwrap_or(0);
+
+        Ok(cost)
+    }
+}
@@ -0,0 +1,164 @@
+#![cfg(feature = "ssr")]
+
+use std::sync::LazyLock;
+
+use chrono::{Duration, Utc};
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, Opts, Registry,
+    TextEncoder,
+};
+use tokio::sync::Mutex;
+
+use crate::{
+    backend::database::{TransactionDB, UserDB, DB},
+    model::{TransactionKind, TransactionType},
+};
+
+/// users count as active if they took part in a transaction within this many days
+pub const ACTIVE_USER_DAYS: i64 = 30;
+
+/// The prometheus metrics, exposed under `/metrics`
+pub struct Metrics {
+    registry: Registry,
+    pub server_fn_duration: HistogramVec,
+    pub db_query_duration: HistogramVec,
+    pub transactions: IntCounterVec,
+    pub outstanding_debt: IntGauge,
+    pub total_credit: IntGauge,
+    pub articles_sold: IntGaugeVec,
+    pub active_users: IntGauge,
+}
+
+pub static METRICS: LazyLock<Metrics> = LazyLock::new(Metrics::new);
+
+impl Metrics {
+    fn new() -> Self {
+        let registry = Registry::new_custom(Some("strichliste".to_string()), None)
+            .expect("the prefix is valid");
+
+        let server_fn_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "server_fn_duration_seconds",
+                "The time it took to answer a server function",
+            ),
+            &["function", "status"],
+        )
+        .expect("the metric is valid");
+
+        let db_query_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "db_query_duration_seconds",
+                "The time it took to execute a database query",
+            )
+            .buckets(vec![
+                0.0001, 0.00025, 0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 1.0,
+            ]),
+            &["statement"],
+        )
+        .expect("the metric is valid");
+
+        let transactions = IntCounterVec::new(
+            Opts::new("transactions_total", "The booked transactions by type"),
+            &["type"],
+        )
+        .expect("the metric is valid");
+
+        let outstanding_debt =
+            IntGauge::new("outstanding_debt_cents", "The sum of all negative balances")
+                .expect("the metric is valid");
+
+        let total_credit = IntGauge::new("total_credit_cents", "The sum of all positive balances")
+            .expect("the metric is valid");
+
+        let articles_sold = IntGaugeVec::new(
+            Opts::new("articles_sold", "How often an article has been sold"),
+            &["article"],
+        )
+        .expect("the metric is valid");
+
+        let active_users = IntGauge::new(
+            "active_users",
+            format!("The users with a transaction in the last {ACTIVE_USER_DAYS} days"),
+        )
+        .expect("the metric is valid");
+
+        for collector in [
+            Box::new(server_fn_duration.clone()) as Box<dyn prometheus::core::Collector>,
+            Box::new(db_query_duration.clone()),
+            Box::new(transactions.clone()),
+            Box::new(outstanding_debt.clone()),
+            Box::new(total_credit.clone()),
+            Box::new(articles_sold.clone()),
+            Box::new(active_users.clone()),
+        ] {
+            registry
+                .register(collector)
+                .expect("the metric names are unique");
+        }
+
+        Self {
+            registry,
+            server_fn_duration,
+            db_query_duration,
+            transactions,
+            outstanding_debt,
+            total_credit,
+            articles_sold,
+            active_users,
+        }
+    }
+
+    /// Counts a booked transaction, call it only once the transaction is committed
+    pub fn transaction_booked(&self, t_type: TransactionType) {
+        self.transactions
+            .with_label_values(&[TransactionKind::from(t_type).name()])
+            .inc();
+    }
+
+    /// Updates the metrics that are read from the database
+    pub async fn refresh(&self, db: &Mutex<DB>) -> Result<(), String> {
+        let db = db.lock().await;
+        let mut conn = db
+            .get_conn()
+            .await
+            .map_err(|e| format!("Failed to get database handle: {e}"))?;
+
+        let totals = UserDB::get_balance_totals(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to fetch balance totals: {e}"))?;
+
+        let sales = TransactionDB::get_all_sales_counts(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to fetch sales counts: {e}"))?;
+
+        let active_users =
+            UserDB::get_active_count(&mut *conn, Utc::now() - Duration::days(ACTIVE_USER_DAYS))
+                .await
+                .map_err(|e| format!("Failed to fetch active users: {e}"))?;
+
+        self.outstanding_debt.set(totals.debt);
+        self.total_credit.set(totals.credit);
+        self.active_users.set(active_users);
+
+        // articles may have been renamed since the last scrape
+        self.articles_sold.reset();
+        for article in sales.iter() {
+            self.articles_sold
+                .with_label_values(&[article.name.as_str()])
+                .set(article.sold);
+        }
+
+        Ok(())
+    }
+
+    /// Returns all metrics in the prometheus text format
+    pub fn encode(&self) -> Result<String, String> {
+        let mut buffer = Vec::new();
+
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .map_err(|e| e.to_string())?;
+
+        String::from_utf8(buffer).map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "ssr")]
+
+use tracing::{
+    field::{Field, Visit},
+    Event, Subscriber,
+};
+use tracing_subscriber::{layer::Context, Layer};
+
+use crate::backend::core::misc::METRICS;
+
+/// Records the query timings that sqlx logs under the `sqlx::query` target.
+/// Needs a filter that lets the `sqlx::query` events through at debug level.
+pub struct MetricsLayer;
+
+#[derive(Default)]
+struct QueryVisitor {
+    statement: Option<String>,
+    elapsed_secs: Option<f64>,
+}
+
+impl Visit for QueryVisitor {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        if field.name() == "elapsed_secs" {
+            self.elapsed_secs = Some(value);
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "summary" {
+            // only the kind of statement, the full summary would create a label per query
+            self.statement = value
+                .split_whitespace()
+                .next()
+                .map(|elem| elem.to_lowercase());
+        }
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+}
+
+impl<S: Subscriber> Layer<S> for MetricsLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if event.metadata().target() != "sqlx::query" {
+            return;
+        }
+
+        let mut visitor = QueryVisitor::default();
+        event.record(&mut visitor);
+
+        if let (Some(statement), Some(elapsed_secs)) = (visitor.statement, visitor.elapsed_secs) {
+            METRICS
+                .db_query_duration
+                .with_label_values(&[statement.as_str()])
+                .observe(elapsed_secs);
+        }
+    }
+}
@@ -1,5 +1,51 @@
+pub mod backup_task;
 pub mod choose_random_item;
+pub mod csv;
 pub mod custom_binary_encoding;
+pub mod debt_reminder_task;
+pub mod effective_price_cache;
+pub mod mailer;
+pub mod metrics;
+pub mod metrics_layer;
+pub mod mqtt_task;
+pub mod pdf;
+pub mod pin_hash;
+pub mod seed;
+pub mod settings_reload_task;
+pub mod sound_seed;
+pub mod speech;
+pub mod statistics;
+pub mod webhook_task;
 
+#[cfg(feature = "ssr")]
+pub use backup_task::*;
 #[cfg(feature = "ssr")]
 pub use choose_random_item::*;
+#[cfg(feature = "ssr")]
+pub use csv::*;
+#[cfg(feature = "ssr")]
+pub use debt_reminder_task::*;
+#[cfg(feature = "ssr")]
+pub use effective_price_cache::*;
+#[cfg(feature = "ssr")]
+pub use mailer::*;
+#[cfg(feature = "ssr")]
+pub use metrics::*;
+#[cfg(feature = "ssr")]
+pub use metrics_layer::*;
+#[cfg(feature = "ssr")]
+pub use mqtt_task::*;
+#[cfg(feature = "ssr")]
+pub use pdf::*;
+#[cfg(feature = "ssr")]
+pub use pin_hash::*;
+#[cfg(feature = "ssr")]
+pub use seed::*;
+#[cfg(feature = "ssr")]
+pub use settings_reload_task::*;
+#[cfg(feature = "ssr")]
+pub use sound_seed::*;
+#[cfg(feature = "ssr")]
+pub use speech::*;
+#[cfg(feature = "ssr")]
+pub use webhook_task::*;
@@ -0,0 +1,237 @@
+#![cfg(feature = "ssr")]
+
+use std::time::Duration;
+
+use rumqttc::{AsyncClient, Event, MqttOptions, Packet, QoS};
+use serde::Serialize;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{debug, error, info, warn};
+
+use crate::{
+    backend::{
+        core::{
+            Article, MqttPurchaseCommand, MqttPurchaseResult, MqttSettings, ServerState, State,
+            User,
+        },
+        database::TransactionDB,
+    },
+    model::{DatabaseId, ServerEvent, Transaction, TransactionKind, TransactionType},
+};
+
+/// how long to wait before reconnecting to the broker
+const MQTT_RECONNECT_DELAY_SEC: u64 = 5;
+
+const MQTT_KEEP_ALIVE_SEC: u64 = 30;
+
+const MQTT_CHANNEL_CAPACITY: usize = 64;
+
+async fn publish_json<T: Serialize>(client: &AsyncClient, topic: &str, retain: bool, value: &T) {
+    let payload = match serde_json::to_vec(value) {
+        Ok(value) => value,
+        Err(e) => {
+            error!("Failed to serialize mqtt payload: {}", e);
+            return;
+        }
+    };
+
+    if let Err(e) = client
+        .publish(topic, QoS::AtLeastOnce, retain, payload)
+        .await
+    {
+        warn!("Failed to publish to '{}': {}", topic, e);
+    }
+}
+
+/// Publishes the current sales of every article that is part of the transaction
+async fn publish_sales_counts(
+    state: &State,
+    client: &AsyncClient,
+    settings: &MqttSettings,
+    transaction_id: DatabaseId,
+) {
+    let sales = {
+        let db = state.db.lock().await;
+        let mut conn = match db.get_conn().await {
+            Ok(value) => value,
+            Err(e) => {
+                error!("Failed to get database handle: {}", e);
+                return;
+            }
+        };
+
+        match TransactionDB::get_sales_counts(&mut *conn, transaction_id).await {
+            Ok(value) => value,
+            Err(e) => {
+                error!("Failed to fetch sales counts: {}", e);
+                return;
+            }
+        }
+    };
+
+    for article in sales.iter() {
+        let topic = format!("{}/{}", settings.topics.sales, article.article_id);
+        publish_json(client, &topic, true, article).await;
+    }
+}
+
+/// Forwards the purchase, deposit and undo events from the event bus to the broker
+async fn publish_events(state: ServerState, client: AsyncClient) {
+    let loaded = state.settings.load_full();
+    let settings = match &loaded.mqtt {
+        Some(value) => value,
+        None => return,
+    };
+
+    let mut receiver = state.events.subscribe();
+
+    loop {
+        let event = match receiver.recv().await {
+            Ok(value) => value,
+            Err(RecvError::Lagged(skipped)) => {
+                warn!("Mqtt lagged behind, {} events are not published", skipped);
+                continue;
+            }
+            Err(RecvError::Closed) => return,
+        };
+
+        match &event {
+            ServerEvent::TransactionCreated {
+                transaction_id,
+                kind: TransactionKind::Purchase,
+                ..
+            } => {
+                publish_json(&client, &settings.topics.purchase, false, &event).await;
+                publish_sales_counts(&state, &client, settings, *transaction_id).await;
+            }
+            ServerEvent::TransactionCreated {
+                kind: TransactionKind::Deposit,
+                ..
+            } => {
+                publish_json(&client, &settings.topics.deposit, false, &event).await;
+            }
+            ServerEvent::TransactionUndone { transaction_id, .. } => {
+                publish_json(&client, &settings.topics.undo, false, &event).await;
+                publish_sales_counts(&state, &client, settings, *transaction_id).await;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Buys the article for the owner of the card, the same way the kiosk does
+async fn buy_by_card(state: &State, command: &MqttPurchaseCommand) -> Result<(), String> {
+    let user = User::get_by_card_number(&*state.db.lock().await, command.card_number.clone())
+        .await
+        .map_err(|e| format!("Failed to lookup card number: {e}"))?
+        .ok_or_else(|| "Unknown card number".to_string())?;
+
+    let article = Article::get(&*state.db.lock().await, command.article_id)
+        .await
+        .map_err(|e| format!("Failed to lookup article: {e}"))?
+        .ok_or_else(|| format!("The article with id {} does not exist!", command.article_id))?;
+
+    Transaction::create_for_user(
+        state,
+        user.id,
+        article.cost,
+        TransactionType::Bought(article.id),
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+async fn handle_command(
+    state: ServerState,
+    client: AsyncClient,
+    result_topic: String,
+    payload: Vec<u8>,
+) {
+    let command: MqttPurchaseCommand = match serde_json::from_slice(&payload) {
+        Ok(value) => value,
+        Err(e) => {
+            warn!("Ignoring invalid mqtt command: {}", e);
+            return;
+        }
+    };
+
+    debug!(
+        "Mqtt purchase of article {} for card '{}'",
+        command.article_id, command.card_number
+    );
+
+    let result = buy_by_card(&state, &command).await;
+
+    if let Err(e) = &result {
+        warn!("Mqtt purchase failed: {}", e);
+    }
+
+    let result = MqttPurchaseResult {
+        card_number: command.card_number,
+        article_id: command.article_id,
+        success: result.is_ok(),
+        error: result.err(),
+    };
+
+    publish_json(&client, &result_topic, false, &result).await;
+}
+
+/// Connects to the mqtt broker, publishes the events and executes the purchase commands.
+/// Returns immediately if mqtt is not configured.
+pub async fn run_mqtt(state: ServerState) {
+    // read once, changing the broker needs a restart
+    let loaded = state.settings.load_full();
+    let settings = match &loaded.mqtt {
+        Some(value) => value,
+        None => return,
+    };
+
+    let mut options = MqttOptions::new(&settings.client_id, &settings.host, settings.port);
+    options.set_keep_alive(Duration::from_secs(MQTT_KEEP_ALIVE_SEC));
+
+    if let Some(username) = &settings.username {
+        options.set_credentials(username, settings.password.clone().unwrap_or_default());
+    }
+
+    let (client, mut eventloop) = AsyncClient::new(options, MQTT_CHANNEL_CAPACITY);
+
+    tokio::spawn(publish_events(state.clone(), client.clone()));
+
+    loop {
+        match eventloop.poll().await {
+            Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                info!(
+                    "Connected to mqtt broker at {}:{}",
+                    settings.host, settings.port
+                );
+
+                // subscriptions don't survive a reconnect
+                if let Some(topic) = &settings.topics.command {
+                    if let Err(e) = client.try_subscribe(topic, QoS::AtLeastOnce) {
+                        error!("Failed to subscribe to '{}': {}", topic, e);
+                    }
+                }
+            }
+            Ok(Event::Incoming(Packet::Publish(publish))) => {
+                let topic = match &settings.topics.command {
+                    Some(topic) if *topic == publish.topic => topic,
+                    _ => continue,
+                };
+
+                // the eventloop has to keep running while the purchase is processed
+                tokio::spawn(handle_command(
+                    state.clone(),
+                    client.clone(),
+                    format!("{topic}/result"),
+                    publish.payload.to_vec(),
+                ));
+            }
+            Ok(_) => {}
+            Err(e) => {
+                warn!("Mqtt connection error: {}", e);
+                tokio::time::sleep(Duration::from_secs(MQTT_RECONNECT_DELAY_SEC)).await;
+            }
+        }
+    }
+}
@@ -0,0 +1,202 @@
+#![cfg(feature = "ssr")]
+
+//! A minimal PDF writer for simple text documents, so we don't need an external renderer.
+//! Only supports the builtin Helvetica font with WinAnsiEncoding.
+
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 50.0;
+
+pub struct PdfLine {
+    pub text: String,
+    pub size: f32,
+    pub bold: bool,
+    /// offset from the left margin
+    pub indent: f32,
+}
+
+#[derive(Default)]
+pub struct PdfDocument {
+    pages: Vec<Vec<(f32, PdfLine)>>,
+    cursor: f32,
+}
+
+fn encode_text(text: &str) -> Vec<u8> {
+    let mut result = Vec::new();
+
+    for char in text.chars() {
+        let byte = match char {
+            '€' => 0x80,
+            '(' | ')' | '\\' => {
+                result.push(b'\\');
+                char as u8
+            }
+            char if (char as u32) < 0x20 => b' ',
+            char if (char as u32) <= 0xFF => char as u32 as u8,
+            _ => b'?',
+        };
+
+        result.push(byte);
+    }
+
+    result
+}
+
+impl PdfDocument {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn ensure_space(&mut self, height: f32) {
+        if self.pages.is_empty() || self.cursor - height < MARGIN {
+            self.pages.push(Vec::new());
+            self.cursor = PAGE_HEIGHT - MARGIN;
+        }
+    }
+
+    pub fn line(&mut self, line: PdfLine) {
+        let height = line.size * 1.4;
+        self.ensure_space(height);
+        self.cursor -= height;
+
+        let y = self.cursor;
+        self.pages
+            .last_mut()
+            .expect("ensure_space creates a page")
+            .push((y, line));
+    }
+
+    pub fn text(&mut self, text: impl ToString, size: f32) {
+        self.line(PdfLine {
+            text: text.to_string(),
+            size,
+            bold: false,
+            indent: 0.0,
+        });
+    }
+
+    pub fn bold(&mut self, text: impl ToString, size: f32) {
+        self.line(PdfLine {
+            text: text.to_string(),
+            size,
+            bold: true,
+            indent: 0.0,
+        });
+    }
+
+    /// Writes multiple columns on the same line. Each column is given with its offset from the left margin.
+    pub fn columns(&mut self, columns: Vec<(f32, String)>, size: f32, bold: bool) {
+        let height = size * 1.4;
+        self.ensure_space(height);
+        self.cursor -= height;
+
+        let y = self.cursor;
+        let page = self.pages.last_mut().expect("ensure_space creates a page");
+        for (indent, text) in columns.into_iter() {
+            page.push((
+                y,
+                PdfLine {
+                    text,
+                    size,
+                    bold,
+                    indent,
+                },
+            ));
+        }
+    }
+
+    pub fn spacer(&mut self, height: f32) {
+        self.ensure_space(height);
+        self.cursor -= height;
+    }
+
+    pub fn render(mut self) -> Vec<u8> {
+        if self.pages.is_empty() {
+            self.pages.push(Vec::new());
+        }
+
+        // object ids: 1 catalog, 2 pages, 3 regular font, 4 bold font, then (page, content) pairs
+        let page_count = self.pages.len();
+        let page_id = |index: usize| 5 + index * 2;
+
+        let mut objects = Vec::<Vec<u8>>::new();
+
+        objects.push(b"<< /Type /Catalog /Pages 2 0 R >>".to_vec());
+
+        let kids = (0..page_count)
+            .map(|index| format!("{} 0 R", page_id(index)))
+            .collect::<Vec<String>>()
+            .join(" ");
+        objects.push(format!("<< /Type /Pages /Kids [{kids}] /Count {page_count} >>").into_bytes());
+
+        objects.push(
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+                .to_vec(),
+        );
+        objects.push(
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+                .to_vec(),
+        );
+
+        for (index, page) in self.pages.iter().enumerate() {
+            let mut content = Vec::<u8>::new();
+
+            for (y, line) in page.iter() {
+                let font = if line.bold { "F2" } else { "F1" };
+                content.extend_from_slice(
+                    format!(
+                        "BT /{} {} Tf {} {} Td (",
+                        font,
+                        line.size,
+                        MARGIN + line.indent,
+                        y
+                    )
+                    .as_bytes(),
+                );
+                content.extend(encode_text(&line.text));
+                content.extend_from_slice(b") Tj ET\n");
+            }
+
+            objects.push(
+                format!(
+                    "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+                    page_id(index) + 1
+                )
+                .into_bytes(),
+            );
+
+            let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+            stream.extend(content);
+            stream.extend_from_slice(b"endstream");
+            objects.push(stream);
+        }
+
+        let mut result = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+        let mut offsets = Vec::<usize>::new();
+
+        for (index, object) in objects.iter().enumerate() {
+            offsets.push(result.len());
+            result.extend_from_slice(format!("{} 0 obj\n", index + 1).as_bytes());
+            result.extend_from_slice(object);
+            result.extend_from_slice(b"\nendobj\n");
+        }
+
+        let xref_offset = result.len();
+        result.extend_from_slice(format!("xref\n0 {}\n", objects.len() + 1).as_bytes());
+        result.extend_from_slice(b"0000000000 65535 f \n");
+        for offset in offsets.iter() {
+            result.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+        }
+
+        result.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+                objects.len() + 1,
+                xref_offset
+            )
+            .as_bytes(),
+        );
+
+        result
+    }
+}
@@ -0,0 +1,479 @@
+#![cfg(feature = "ssr")]
+
+use std::fmt::Display;
+
+use chrono::{DateTime, Duration, Utc};
+use rand::{rngs::StdRng, seq::IndexedRandom, Rng, SeedableRng};
+use sqlx::Sqlite;
+
+use crate::{
+    backend::{
+        core::{Article, BarcodeDiff, Group, Settings, User},
+        database::{ArticleDB, TransactionDB, DB, DBGROUP_AUFLADUNG_ID, DBGROUP_SNACKBAR_ID},
+    },
+    model::{
+        CreateTransactionError, DatabaseId, GroupId, Money, Transaction, TransactionType, UserId,
+    },
+};
+
+/// The seeded catalogue with the current prices in cents
+const ARTICLES: &[(&str, i64)] = &[
+    ("Club-Mate", 150),
+    ("Club-Mate Granat", 160),
+    ("Spezi", 130),
+    ("Cola", 130),
+    ("Apfelschorle", 120),
+    ("Wasser", 80),
+    ("Eistee", 130),
+    ("Energy Drink", 200),
+    ("Pils", 150),
+    ("Radler", 150),
+    ("Kaffee", 50),
+    ("Tee", 30),
+    ("Schokoriegel", 100),
+    ("Chips", 150),
+    ("Gummibärchen", 120),
+    ("Brezel", 90),
+    ("Tiefkühlpizza", 350),
+];
+
+const NICKNAMES: &[&str] = &[
+    "Alex", "Anna", "Ben", "Carla", "Chris", "David", "Elena", "Felix", "Finn", "Hannah", "Ida",
+    "Jakob", "Jana", "Jonas", "Julia", "Kai", "Klara", "Lea", "Leon", "Lina", "Luca", "Lukas",
+    "Marie", "Max", "Mia", "Moritz", "Nele", "Niklas", "Noah", "Paul", "Pia", "Sam", "Sarah",
+    "Simon", "Sophie", "Tim", "Tom", "Vincent", "Yara", "Zoe",
+];
+
+const SPLIT_DESCRIPTIONS: &[&str] = &[
+    "Pizza",
+    "Döner",
+    "Sushi",
+    "Groceries",
+    "Cinema",
+    "Soldering supplies",
+    "Filament",
+];
+
+pub struct SeedOptions {
+    pub users: usize,
+    pub months: u32,
+    /// the same seed always creates the same data, relative to the current time
+    pub seed: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct SeedSummary {
+    pub users: usize,
+    pub articles: usize,
+    pub transactions: usize,
+    /// transactions that were dropped because they violated the account limits
+    pub refused: usize,
+}
+
+impl Display for SeedSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Created {} users, {} articles and {} transactions ({} refused by the account limits)",
+            self.users, self.articles, self.transactions, self.refused
+        )
+    }
+}
+
+struct SeedArticle {
+    id: DatabaseId,
+    /// (effective since, cost), oldest first. The first entry is older than all events.
+    prices: Vec<(DateTime<Utc>, i64)>,
+}
+
+impl SeedArticle {
+    fn price_at(&self, time: DateTime<Utc>) -> i64 {
+        self.prices
+            .iter()
+            .rev()
+            .find(|(since, _)| *since <= time)
+            .unwrap_or(&self.prices[0])
+            .1
+    }
+}
+
+enum Event {
+    Purchase {
+        user: usize,
+        article: usize,
+    },
+    Deposit {
+        user: usize,
+        money: i64,
+    },
+    Transfer {
+        from: usize,
+        to: usize,
+        money: i64,
+    },
+    Split {
+        payer: usize,
+        others: Vec<usize>,
+        money: i64,
+        description: &'static str,
+    },
+}
+
+/// Fills an empty database with users, articles and months of transactions.
+/// Everything is created through the same behaviours as the server functions, afterwards the
+/// transactions are backdated.
+pub async fn seed_demo_data(
+    db: &DB,
+    settings: &Settings,
+    options: SeedOptions,
+) -> Result<SeedSummary, String> {
+    if options.users < 2 {
+        return Err(String::from("At least two users are needed"));
+    }
+
+    if options.months == 0 {
+        return Err(String::from("At least one month is needed"));
+    }
+
+    let existing_users = User::get_all(db)
+        .await
+        .map_err(|e| format!("Failed to fetch users: {e}"))?;
+
+    let existing_articles = Article::get_all(db, Some(1))
+        .await
+        .map_err(|e| format!("Failed to fetch articles: {e}"))?;
+
+    if !existing_users.is_empty() || !existing_articles.is_empty() {
+        return Err(String::from(
+            "The database already contains users or articles, seeding needs an empty database",
+        ));
+    }
+
+    let mut rng = StdRng::seed_from_u64(options.seed);
+    let mut summary = SeedSummary::default();
+
+    let now = Utc::now();
+    let start = now - Duration::days(30 * options.months as i64);
+    let span = (now - start).num_seconds();
+
+    let mut articles = Vec::<SeedArticle>::new();
+
+    for (name, cost) in ARTICLES.iter() {
+        let mut article = Article::new(db, name.to_string(), Money::from(*cost))
+            .await
+            .map_err(|e| format!("Failed to create article '{name}': {e}"))?;
+
+        let mut changes = (0..rng.random_range(0..=2))
+            .map(|_| start + Duration::seconds(rng.random_range(0..span)))
+            .collect::<Vec<_>>();
+        changes.sort();
+
+        // walk backwards from the current price, every older price is a bit cheaper
+        let mut cost = *cost;
+        let mut prices = Vec::new();
+        for since in changes.into_iter().rev() {
+            prices.push((since, cost));
+            cost = (cost - 10 * rng.random_range(1..=3)).max(10);
+        }
+        prices.push((start - Duration::days(1), cost));
+        prices.reverse();
+
+        let mut conn = db
+            .get_conn()
+            .await
+            .map_err(|e| format!("Failed to get connection: {e}"))?;
+
+        for (since, cost) in prices.iter() {
+            ArticleDB::set_price_since(&mut *conn, article.id, *cost, *since)
+                .await
+                .map_err(|e| format!("Failed to set price of '{name}': {e}"))?;
+        }
+
+        let barcode = format!("40{:011}", rng.random_range(0..100_000_000_000_u64));
+        article
+            .set_barcodes(&mut *conn, vec![BarcodeDiff::Added(barcode)])
+            .await
+            .map_err(|e| format!("Failed to set barcode of '{name}': {e}"))?;
+
+        articles.push(SeedArticle {
+            id: article.id,
+            prices,
+        });
+        summary.articles += 1;
+    }
+
+    let mut users = Vec::<(UserId, GroupId)>::new();
+
+    for index in 0..options.users {
+        let name = NICKNAMES[index % NICKNAMES.len()];
+        let nickname = match index / NICKNAMES.len() {
+            0 => name.to_string(),
+            round => format!("{name} {}", round + 1),
+        };
+
+        let card_number = rng
+            .random_bool(0.5)
+            .then(|| format!("{:010}", 1_000_000_000 + index));
+
+        let user_id = User::create(db, nickname.clone(), card_number)
+            .await
+            .map_err(|e| format!("Failed to create user '{nickname}': {e}"))?;
+
+        let mut conn = db
+            .get_conn()
+            .await
+            .map_err(|e| format!("Failed to get connection: {e}"))?;
+
+        let group = Group::get_user_group_id(&mut *conn, user_id)
+            .await
+            .map_err(|e| format!("Failed to find group of '{nickname}': {e}"))?;
+
+        users.push((user_id, group));
+        summary.users += 1;
+    }
+
+    let mut events = Vec::<(DateTime<Utc>, Event)>::new();
+    let at = |rng: &mut StdRng| start + Duration::seconds(rng.random_range(0..span));
+
+    for user in 0..users.len() {
+        // everyone starts with some money on the first day
+        events.push((
+            start + Duration::seconds(rng.random_range(0..86_400)),
+            Event::Deposit {
+                user,
+                money: 500 * rng.random_range(2..=10),
+            },
+        ));
+
+        // some users drink a lot more than others
+        let thirst = rng.random_range(2..=30);
+
+        for _ in 0..options.months {
+            for _ in 0..rng.random_range(thirst / 2..=thirst) {
+                let article = rng.random_range(0..articles.len());
+                events.push((at(&mut rng), Event::Purchase { user, article }));
+            }
+
+            for _ in 0..rng.random_range(0..=2) {
+                let money = 500 * rng.random_range(2..=10);
+                events.push((at(&mut rng), Event::Deposit { user, money }));
+            }
+
+            if rng.random_bool(0.3) {
+                let to = (user + rng.random_range(1..users.len())) % users.len();
+                let money = 50 * rng.random_range(2..=40);
+                events.push((
+                    at(&mut rng),
+                    Event::Transfer {
+                        from: user,
+                        to,
+                        money,
+                    },
+                ));
+            }
+
+            if rng.random_bool(0.15) {
+                let amount = rng.random_range(1..=3.min(users.len() - 1));
+                let others = rand::seq::index::sample(&mut rng, users.len() - 1, amount)
+                    .into_iter()
+                    .map(|other| (user + 1 + other) % users.len())
+                    .collect();
+                let money = 100 * rng.random_range(10..=60);
+                let description = SPLIT_DESCRIPTIONS.choose(&mut rng).unwrap();
+                events.push((
+                    at(&mut rng),
+                    Event::Split {
+                        payer: user,
+                        others,
+                        money,
+                        description,
+                    },
+                ));
+            }
+        }
+    }
+
+    events.sort_by_key(|(time, _)| *time);
+
+    for (time, event) in events.into_iter() {
+        let created = match event {
+            Event::Purchase { user, article } => {
+                let (_, group) = users[user];
+                let article = &articles[article];
+                let t_type = TransactionType::Bought(article.id);
+                let cost = article.price_at(time);
+
+                let created = create_at(
+                    db,
+                    settings,
+                    time,
+                    group,
+                    DBGROUP_SNACKBAR_ID,
+                    t_type,
+                    None,
+                    cost,
+                )
+                .await?;
+
+                match created {
+                    Some(created) => created,
+                    // like at the real kiosk, broke users top up their account and try again
+                    None => {
+                        deposit(db, settings, time, group, 2000, &mut summary).await?;
+
+                        create_at(
+                            db,
+                            settings,
+                            time,
+                            group,
+                            DBGROUP_SNACKBAR_ID,
+                            t_type,
+                            None,
+                            cost,
+                        )
+                        .await?
+                        .unwrap_or(false)
+                    }
+                }
+            }
+            Event::Deposit { user, money } => {
+                deposit(db, settings, time, users[user].1, money, &mut summary).await?;
+                continue;
+            }
+            Event::Transfer { from, to, money } => create_at(
+                db,
+                settings,
+                time,
+                users[from].1,
+                users[to].1,
+                TransactionType::Sent(users[to].1),
+                None,
+                money,
+            )
+            .await?
+            .unwrap_or(false),
+            Event::Split {
+                payer,
+                others,
+                money,
+                description,
+            } => {
+                let others = others
+                    .into_iter()
+                    .map(|other| users[other].0)
+                    .collect::<Vec<_>>();
+
+                let mut trans = begin(db).await?;
+                let result = Transaction::split_cost(
+                    &mut *trans,
+                    users[payer].0,
+                    &others,
+                    Money::from(money),
+                    Some(description.to_string()),
+                    settings,
+                )
+                .await
+                .map(|(transaction_id, _, _)| transaction_id);
+
+                finish(trans, time, result).await?.unwrap_or(false)
+            }
+        };
+
+        match created {
+            true => summary.transactions += 1,
+            false => summary.refused += 1,
+        }
+    }
+
+    Ok(summary)
+}
+
+async fn begin(db: &DB) -> Result<sqlx::Transaction<'_, Sqlite>, String> {
+    db.get_conn_transaction()
+        .await
+        .map_err(|e| format!("Failed to start transaction: {e}"))
+}
+
+async fn deposit(
+    db: &DB,
+    settings: &Settings,
+    time: DateTime<Utc>,
+    group: GroupId,
+    money: i64,
+    summary: &mut SeedSummary,
+) -> Result<(), String> {
+    let created = create_at(
+        db,
+        settings,
+        time,
+        DBGROUP_AUFLADUNG_ID,
+        group,
+        TransactionType::Deposit,
+        None,
+        money,
+    )
+    .await?;
+
+    match created {
+        Some(true) => summary.transactions += 1,
+        _ => summary.refused += 1,
+    }
+
+    Ok(())
+}
+
+/// Creates the transaction like the server functions do and backdates it to `time`.
+/// Returns `None` if the sender had too little money and `Some(false)` if the receiver would
+/// have too much.
+#[allow(clippy::too_many_arguments)]
+async fn create_at(
+    db: &DB,
+    settings: &Settings,
+    time: DateTime<Utc>,
+    sender: GroupId,
+    receiver: GroupId,
+    t_type: TransactionType,
+    description: Option<String>,
+    money: i64,
+) -> Result<Option<bool>, String> {
+    let mut trans = begin(db).await?;
+
+    let result = Transaction::create(
+        &mut *trans,
+        sender,
+        receiver,
+        t_type,
+        description,
+        Money::from(money),
+        settings,
+    )
+    .await
+    .map(|(transaction_id, _)| transaction_id);
+
+    finish(trans, time, result).await
+}
+
+async fn finish(
+    mut trans: sqlx::Transaction<'_, Sqlite>,
+    time: DateTime<Utc>,
+    result: Result<DatabaseId, CreateTransactionError>,
+) -> Result<Option<bool>, String> {
+    // dropping the transaction rolls back everything that was already written
+    let transaction_id = match result {
+        Ok(transaction_id) => transaction_id,
+        Err(CreateTransactionError::TooLittleMoneyError(_)) => return Ok(None),
+        Err(CreateTransactionError::TooMuchMoneyError(_)) => return Ok(Some(false)),
+        Err(e) => return Err(format!("Failed to create transaction: {e}")),
+    };
+
+    TransactionDB::set_timestamp(&mut *trans, transaction_id, time)
+        .await
+        .map_err(|e| format!("Failed to set timestamp: {e}"))?;
+
+    trans
+        .commit()
+        .await
+        .map_err(|e| format!("Failed to commit transaction: {e}"))?;
+
+    Ok(Some(true))
+}
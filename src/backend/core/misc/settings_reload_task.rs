@@ -0,0 +1,85 @@
+#![cfg(feature = "ssr")]
+
+use std::{path::PathBuf, sync::Arc, time::Duration};
+
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use tokio::sync::mpsc;
+use tracing::{error, info, warn};
+
+use crate::backend::core::{ServerState, Settings};
+
+/// editors emit several events per save, they are awaited before the file is read
+const SETTINGS_RELOAD_DELAY_MS: u64 = 500;
+
+/// Watches the config file and swaps the settings in the state after every valid change.
+/// Requests that already loaded the settings finish with the previous ones.
+pub async fn run_settings_reload(state: ServerState, config_path: PathBuf) {
+    // the directory is watched, as editors and deployments often replace the file
+    let directory = match config_path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    let file_name = config_path.file_name().map(ToOwned::to_owned);
+
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+
+    let mut watcher = match notify::recommended_watcher(move |event: notify::Result<Event>| {
+        _ = sender.send(event);
+    }) {
+        Ok(value) => value,
+        Err(e) => {
+            error!("Config reloading is disabled: {}", e);
+            return;
+        }
+    };
+
+    if let Err(e) = watcher.watch(&directory, RecursiveMode::NonRecursive) {
+        error!("Config reloading is disabled: {}", e);
+        return;
+    }
+
+    while let Some(event) = receiver.recv().await {
+        let changed = match event {
+            // reading the file emits access events, including our own reads
+            Ok(event) => {
+                matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+                    && event
+                        .paths
+                        .iter()
+                        .any(|path| path.file_name() == file_name.as_deref())
+            }
+            Err(e) => {
+                warn!("Failed to watch the config: {}", e);
+                false
+            }
+        };
+
+        if !changed {
+            continue;
+        }
+
+        tokio::time::sleep(Duration::from_millis(SETTINGS_RELOAD_DELAY_MS)).await;
+        while receiver.try_recv().is_ok() {}
+
+        match Settings::load(&config_path) {
+            Ok(settings) => {
+                match settings.check_articles(&*state.db.lock().await).await {
+                    Ok(problems) => {
+                        for problem in problems.iter() {
+                            warn!("Invalid config: {problem}");
+                        }
+                    }
+                    Err(e) => error!("Failed to check the articles of the config: {e}"),
+                }
+
+                state.settings.store(Arc::new(settings));
+                info!("Reloaded the config '{}'", config_path.display());
+            }
+            Err(problems) => error!(
+                "Keeping the previous config, '{}' is invalid: {}",
+                config_path.display(),
+                problems.join(", ")
+            ),
+        }
+    }
+}
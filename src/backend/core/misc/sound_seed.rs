@@ -0,0 +1,130 @@
+#![cfg(feature = "ssr")]
+
+use std::{collections::HashMap, path::Path};
+
+use tracing::warn;
+
+use crate::{
+    backend::{
+        core::SoundSettings,
+        database::{ArticleDB, SoundDB, DB},
+    },
+    model::{DatabaseId, SoundEvent},
+};
+
+struct SeedFile<'a> {
+    path: &'a str,
+    events: Vec<&'static str>,
+    article_ids: Vec<DatabaseId>,
+}
+
+fn seed_file<'a, 'b>(files: &'b mut Vec<SeedFile<'a>>, path: &'a str) -> &'b mut SeedFile<'a> {
+    let index = match files.iter().position(|elem| elem.path == path) {
+        Some(value) => value,
+        None => {
+            files.push(SeedFile {
+                path,
+                events: Vec::new(),
+                article_ids: Vec::new(),
+            });
+            files.len() - 1
+        }
+    };
+
+    &mut files[index]
+}
+
+/// Imports the sounds of the config into the sound store, but only while the store is still
+/// empty. Afterwards the sounds are managed in the ui. Returns the number of imported sounds.
+pub async fn seed_sounds(db: &DB, sounds: &SoundSettings) -> Result<usize, String> {
+    let mut transaction = db
+        .get_conn_transaction()
+        .await
+        .map_err(|e| format!("Failed to get transaction: {e}"))?;
+
+    let existing = SoundDB::get_all(&mut *transaction)
+        .await
+        .map_err(|e| format!("Failed to fetch sounds: {e}"))?;
+
+    if !existing.is_empty() {
+        return Ok(0);
+    }
+
+    let articles = ArticleDB::get_all(&mut *transaction, None)
+        .await
+        .map_err(|e| format!("Failed to fetch articles: {e}"))?;
+
+    // the same file may be listed for several events and articles, it is stored once
+    let mut files = Vec::new();
+
+    for (event, list) in [
+        (SoundEvent::Generic, &sounds.generic),
+        (SoundEvent::Failed, &sounds.failed),
+        (SoundEvent::Refused, &sounds.refused),
+        (SoundEvent::Deposit, &sounds.deposit),
+        (SoundEvent::Withdraw, &sounds.withdraw),
+    ] {
+        for file in list.iter() {
+            let entry = seed_file(&mut files, file);
+
+            if !entry.events.contains(&event.name()) {
+                entry.events.push(event.name());
+            }
+        }
+    }
+
+    let article_ids = articles
+        .into_iter()
+        .map(|article| (article.name, article.id))
+        .collect::<HashMap<_, _>>();
+
+    for (name, list) in sounds.articles.iter() {
+        let Some(article_id) = article_ids.get(name) else {
+            warn!("Not importing the sounds of '{name}', there is no such article");
+            continue;
+        };
+
+        for file in list.iter() {
+            let entry = seed_file(&mut files, file);
+
+            if !entry.article_ids.contains(article_id) {
+                entry.article_ids.push(*article_id);
+            }
+        }
+    }
+
+    for SeedFile {
+        path: file,
+        events,
+        article_ids,
+    } in files.iter()
+    {
+        let data = tokio::fs::read(file)
+            .await
+            .map_err(|e| format!("Failed to read sound file '{file}': {e}"))?;
+
+        let name = Path::new(file)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| file.to_string());
+
+        let id = SoundDB::create(&mut *transaction, &name, &data)
+            .await
+            .map_err(|e| format!("Failed to store sound '{file}': {e}"))?;
+
+        SoundDB::set_events(&mut *transaction, id, events)
+            .await
+            .map_err(|e| format!("Failed to assign sound '{file}': {e}"))?;
+
+        SoundDB::set_articles(&mut *transaction, id, article_ids)
+            .await
+            .map_err(|e| format!("Failed to assign sound '{file}': {e}"))?;
+    }
+
+    transaction
+        .commit()
+        .await
+        .map_err(|e| format!("Failed to commit sounds: {e}"))?;
+
+    Ok(files.len())
+}
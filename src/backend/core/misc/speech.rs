@@ -0,0 +1,104 @@
+#![cfg(feature = "ssr")]
+
+use std::{process::Stdio, time::Duration};
+
+use tokio::{io::AsyncWriteExt, process::Command};
+
+use crate::{
+    backend::core::SpeechSettings,
+    model::{Currency, Money},
+};
+
+const SYNTHESIS_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The values for the placeholders of a speech template, missing ones are left empty
+#[derive(Debug, Clone, Default)]
+pub struct SpeechValues {
+    pub user: Option<String>,
+    pub article: Option<String>,
+    pub amount: Option<Money>,
+    pub balance: Option<Money>,
+}
+
+impl SpeechValues {
+    /// Replaces `{user}`, `{article}`, `{amount}` and `{balance}` in the template
+    pub fn render(&self, template: &str, currency: &Currency) -> String {
+        let money = |value: Option<Money>| {
+            value
+                .map(|value| spoken_money(value, currency))
+                .unwrap_or_default()
+        };
+
+        template
+            .replace("{user}", self.user.as_deref().unwrap_or_default())
+            .replace("{article}", self.article.as_deref().unwrap_or_default())
+            .replace("{amount}", &money(self.amount))
+            .replace("{balance}", &money(self.balance))
+    }
+}
+
+/// Formats an amount to be spoken, e.g. `minus 3€` instead of `-3.00€`
+pub fn spoken_money(money: Money, currency: &Currency) -> String {
+    let value = money.value.saturating_abs();
+    let minor_units = currency.minor_units();
+
+    let amount = match value % minor_units {
+        0 => Currency {
+            minor_digits: 0,
+            ..currency.clone()
+        }
+        .format(value / minor_units),
+        _ => currency.format(value),
+    };
+
+    match money.value < 0 {
+        true => format!("minus {amount}"),
+        false => amount,
+    }
+}
+
+/// Renders the text with the tts engine of the server. The text is written to its stdin, so
+/// nicknames can't be mistaken for arguments. Returns the wav file it writes to stdout.
+pub async fn synthesize_speech(settings: &SpeechSettings, text: &str) -> Result<Vec<u8>, String> {
+    let command = &settings.command;
+
+    let mut child = Command::new(command)
+        .args(&settings.args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| format!("Failed to run '{command}': {e}"))?;
+
+    let mut stdin = child
+        .stdin
+        .take()
+        .ok_or_else(|| format!("Failed to open the stdin of '{command}'"))?;
+
+    stdin
+        .write_all(text.as_bytes())
+        .await
+        .map_err(|e| format!("Failed to write to '{command}': {e}"))?;
+    drop(stdin);
+
+    let output = tokio::time::timeout(SYNTHESIS_TIMEOUT, child.wait_with_output())
+        .await
+        .map_err(|_| format!("'{command}' took longer than {SYNTHESIS_TIMEOUT:?}"))?
+        .map_err(|e| format!("Failed to run '{command}': {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        return Err(match stderr.trim() {
+            "" => format!("'{command}' failed with {}", output.status),
+            stderr => format!("'{command}' failed with {}: {stderr}", output.status),
+        });
+    }
+
+    if output.stdout.is_empty() {
+        return Err(format!("'{command}' did not write any audio"));
+    }
+
+    Ok(output.stdout)
+}
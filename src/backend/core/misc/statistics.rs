@@ -0,0 +1,95 @@
+#![cfg(feature = "ssr")]
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use sqlx::Executor;
+
+use crate::{
+    backend::{
+        core::misc::EffectivePriceCache,
+        database::{ArticleDB, ArticleSaleDB, DBError, DatabaseResponse, DatabaseType},
+    },
+    model::{ArticleStatistic, Money, StatisticPoint},
+};
+
+pub const STATISTICS_TOP_LIMIT: usize = 10;
+
+pub fn day_label(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+pub fn week_label(date: NaiveDate) -> String {
+    let week = date.iso_week();
+    format!("{}-W{:02}", week.year(), week.week())
+}
+
+pub fn month_label(date: NaiveDate) -> String {
+    date.format("%Y-%m").to_string()
+}
+
+/// Builds a series over the last `amount` periods (including the current one), filling gaps with the default value
+pub fn last_periods<T: Clone + Default>(
+    values: &HashMap<String, T>,
+    now: DateTime<Utc>,
+    amount: i64,
+    period: Duration,
+    label: fn(NaiveDate) -> String,
+) -> Vec<StatisticPoint<T>> {
+    (0..amount)
+        .rev()
+        .map(|offset| {
+            let label = label((now - period * offset as i32).date_naive());
+            StatisticPoint {
+                value: values.get(&label).cloned().unwrap_or_default(),
+                label,
+            }
+        })
+        .collect()
+}
+
+/// Sums up the given sales per article, ordered by the amount sold
+pub async fn top_articles<T>(
+    conn: &mut T,
+    prices: &mut EffectivePriceCache,
+    sales: &[ArticleSaleDB],
+) -> DatabaseResponse<Vec<ArticleStatistic>>
+where
+    for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+{
+    let mut per_article = BTreeMap::<i64, (i64, Money)>::new();
+
+    for sale in sales.iter() {
+        let price = prices
+            .get(&mut *conn, sale.article_id, sale.timestamp)
+            .await?;
+
+        let entry = per_article.entry(sale.article_id).or_default();
+        entry.0 += sale.amount;
+        entry.1 = Money::from(price)
+            .checked_mul(sale.amount)
+            .and_then(|value| entry.1.checked_add(value))
+            .map_err(DBError::new)?;
+    }
+
+    let mut result = Vec::<ArticleStatistic>::new();
+
+    for (article_id, (amount, value)) in per_article.into_iter() {
+        let name = match ArticleDB::get_single(&mut *conn, article_id).await? {
+            Some(article) => article.name,
+            None => continue, // Article got nuked?
+        };
+
+        result.push(ArticleStatistic {
+            article_id,
+            name,
+            amount,
+            value,
+        });
+    }
+
+    result.sort_by(|a, b| b.amount.cmp(&a.amount).then(b.value.cmp(&a.value)));
+    result.truncate(STATISTICS_TOP_LIMIT);
+
+    Ok(result)
+}
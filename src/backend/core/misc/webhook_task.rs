@@ -0,0 +1,253 @@
+#![cfg(feature = "ssr")]
+
+use std::{sync::Arc, time::Duration};
+
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use tokio::sync::{broadcast::error::RecvError, Notify};
+use tracing::{debug, error, info, warn};
+
+use crate::backend::{
+    core::{ServerState, WebhookEvent, WebhookPayload, WebhookSettings},
+    database::{
+        WebhookDeliveryDB, WEBHOOK_STATUS_DELIVERED, WEBHOOK_STATUS_FAILED, WEBHOOK_STATUS_PENDING,
+    },
+};
+
+/// after how many failed attempts a delivery is given up
+pub const WEBHOOK_MAX_ATTEMPTS: i64 = 8;
+
+/// the delay before the first retry, doubled on every further attempt
+pub const WEBHOOK_BACKOFF_BASE_SEC: i64 = 10;
+
+pub const WEBHOOK_TIMEOUT_SEC: u64 = 10;
+
+/// how often the queue is checked for due retries
+const WEBHOOK_POLL_INTERVAL_SEC: u64 = 5;
+
+const WEBHOOK_BATCH_SIZE: i64 = 50;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Signs the payload with the secret of the webhook. The result is hex encoded.
+pub fn sign_webhook_payload(secret: &str, payload: &str) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("hmac accepts keys of any length");
+    mac.update(payload.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// The delay before the next attempt after `attempts` failed ones
+pub fn webhook_backoff(attempts: i64) -> chrono::Duration {
+    chrono::Duration::seconds(WEBHOOK_BACKOFF_BASE_SEC << (attempts - 1).clamp(0, 10))
+}
+
+/// Listens on the event bus and queues a delivery for every webhook that subscribed to the event
+async fn enqueue_webhooks(state: ServerState, notify: Arc<Notify>) {
+    let mut receiver = state.events.subscribe();
+
+    loop {
+        let event = match receiver.recv().await {
+            Ok(value) => value,
+            Err(RecvError::Lagged(skipped)) => {
+                warn!(
+                    "Webhooks lagged behind, {} events are not delivered",
+                    skipped
+                );
+                continue;
+            }
+            Err(RecvError::Closed) => return,
+        };
+
+        let kind = match WebhookEvent::from_server_event(&event) {
+            Some(value) => value,
+            None => continue,
+        };
+
+        let settings = state.settings.load_full();
+        let targets: Vec<&WebhookSettings> = settings
+            .webhooks
+            .iter()
+            .filter(|target| target.events.contains(&kind))
+            .collect();
+
+        if targets.is_empty() {
+            continue;
+        }
+
+        let payload = WebhookPayload {
+            event: kind,
+            timestamp: Utc::now(),
+            data: event,
+        };
+
+        let payload = match serde_json::to_string(&payload) {
+            Ok(value) => value,
+            Err(e) => {
+                error!("Failed to serialize webhook payload: {}", e);
+                continue;
+            }
+        };
+
+        let db = state.db.lock().await;
+        let mut conn = match db.get_conn().await {
+            Ok(value) => value,
+            Err(e) => {
+                error!("Failed to get database handle: {}", e);
+                continue;
+            }
+        };
+
+        for target in targets {
+            if let Err(e) =
+                WebhookDeliveryDB::create(&mut *conn, &target.url, kind.name(), &payload).await
+            {
+                error!("Failed to queue webhook for '{}': {}", target.url, e);
+            }
+        }
+
+        notify.notify_one();
+    }
+}
+
+/// Posts a delivery. Returns the http status on success, otherwise the status (if any) and the reason.
+async fn post_webhook(
+    client: &reqwest::Client,
+    target: &WebhookSettings,
+    delivery: &WebhookDeliveryDB,
+) -> Result<u16, (Option<u16>, String)> {
+    let signature = sign_webhook_payload(&target.secret, &delivery.payload);
+
+    let response = client
+        .post(&target.url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header("X-Strichliste-Event", &delivery.event)
+        .header("X-Strichliste-Delivery", delivery.id.to_string())
+        .header("X-Strichliste-Signature", format!("sha256={signature}"))
+        .body(delivery.payload.clone())
+        .send()
+        .await
+        .map_err(|e| (None, e.to_string()))?;
+
+    let status = response.status();
+
+    match status.is_success() {
+        true => Ok(status.as_u16()),
+        false => Err((
+            Some(status.as_u16()),
+            format!("Unexpected response status {status}"),
+        )),
+    }
+}
+
+/// Attempts all due deliveries and returns how many have been delivered.
+/// The database is not locked while talking to the webhooks.
+pub async fn deliver_webhooks(
+    state: &ServerState,
+    client: &reqwest::Client,
+) -> Result<usize, String> {
+    let due = {
+        let db = state.db.lock().await;
+        let mut conn = db
+            .get_conn()
+            .await
+            .map_err(|e| format!("Failed to get database handle: {e}"))?;
+
+        WebhookDeliveryDB::get_due(&mut *conn, Utc::now(), WEBHOOK_BATCH_SIZE)
+            .await
+            .map_err(|e| format!("Failed to find due webhooks: {e}"))?
+    };
+
+    let settings = state.settings.load_full();
+    let mut delivered = 0;
+
+    for mut delivery in due.into_iter() {
+        let target = settings
+            .webhooks
+            .iter()
+            .find(|target| target.url == delivery.url);
+
+        let result = match target {
+            Some(target) => post_webhook(client, target, &delivery).await,
+            None => Err((None, "The webhook is no longer configured".to_string())),
+        };
+
+        delivery.attempts += 1;
+
+        match result {
+            Ok(status) => {
+                debug!("Delivered webhook {} to '{}'", delivery.id, delivery.url);
+                delivery.status = WEBHOOK_STATUS_DELIVERED.to_string();
+                delivery.response_status = Some(status.into());
+                delivery.last_error = None;
+                delivered += 1;
+            }
+            Err((status, e)) => {
+                delivery.response_status = status.map(Into::into);
+                delivery.last_error = Some(e);
+
+                if target.is_none() || delivery.attempts >= WEBHOOK_MAX_ATTEMPTS {
+                    warn!(
+                        "Giving up on webhook {} to '{}' after {} attempts: {}",
+                        delivery.id,
+                        delivery.url,
+                        delivery.attempts,
+                        delivery.last_error.as_deref().unwrap_or_default()
+                    );
+                    delivery.status = WEBHOOK_STATUS_FAILED.to_string();
+                } else {
+                    delivery.status = WEBHOOK_STATUS_PENDING.to_string();
+                    delivery.next_attempt = Utc::now() + webhook_backoff(delivery.attempts);
+                }
+            }
+        }
+
+        let db = state.db.lock().await;
+        let mut conn = db
+            .get_conn()
+            .await
+            .map_err(|e| format!("Failed to get database handle: {e}"))?;
+
+        delivery
+            .set_result(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to record webhook delivery: {e}"))?;
+    }
+
+    Ok(delivered)
+}
+
+/// Queues and delivers the webhooks. Runs even if no webhooks are configured,
+/// as they are read from the settings on every event and can be added by a reload.
+pub async fn run_webhooks(state: ServerState) {
+    let client = match reqwest::Client::builder()
+        .timeout(Duration::from_secs(WEBHOOK_TIMEOUT_SEC))
+        .build()
+    {
+        Ok(value) => value,
+        Err(e) => {
+            error!("Webhooks are disabled: {}", e);
+            return;
+        }
+    };
+
+    let notify = Arc::new(Notify::new());
+
+    tokio::spawn(enqueue_webhooks(state.clone(), notify.clone()));
+
+    loop {
+        match deliver_webhooks(&state, &client).await {
+            Ok(0) => {}
+            Ok(delivered) => info!("Delivered {} webhooks", delivered),
+            Err(e) => error!("{}", e),
+        }
+
+        // wake up early if a new event has been queued
+        let _ = tokio::time::timeout(
+            Duration::from_secs(WEBHOOK_POLL_INTERVAL_SEC),
+            notify.notified(),
+        )
+        .await;
+    }
+}
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{backend::core::Barcode, model::Money};
+use crate::{
+    backend::core::{ArticleComponent, Barcode},
+    model::Money,
+};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Article {
@@ -9,4 +12,12 @@ pub struct Article {
     pub cost: Money,
     pub barcodes: Vec<Barcode>,
     pub is_disabled: bool,
+    /// Empty if the article is not a bundle
+    pub components: Vec<ArticleComponent>,
+}
+
+impl Article {
+    pub fn is_bundle(&self) -> bool {
+        !self.components.is_empty()
+    }
 }
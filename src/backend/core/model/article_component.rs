@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+/// An article contained in a bundle, e.g. 20 x "Mate" in a "crate of 20"
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ArticleComponent {
+    pub article_id: i64,
+    pub quantity: i64,
+}
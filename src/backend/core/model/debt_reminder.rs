@@ -0,0 +1,16 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+
+use crate::model::{Money, UserId};
+
+/// A reminder that is due to be sent to a user
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DebtReminder {
+    pub user_id: UserId,
+    pub nickname: String,
+    pub email: String,
+    pub money: Money,
+    pub below_threshold_since: DateTime<Utc>,
+    pub opt_out_token: String,
+}
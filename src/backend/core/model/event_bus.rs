@@ -0,0 +1,45 @@
+#![cfg(feature = "ssr")]
+
+use tokio::sync::broadcast::{self, Receiver, Sender};
+use tracing::trace;
+
+use crate::model::ServerEvent;
+
+/// how many events a slow subscriber may fall behind before it misses events
+const EVENT_BUS_CAPACITY: usize = 256;
+
+#[derive(Clone, Debug)]
+pub struct EventBus {
+    sender: Sender<ServerEvent>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(EVENT_BUS_CAPACITY);
+
+        EventBus { sender }
+    }
+
+    pub fn publish(&self, event: ServerEvent) {
+        trace!("Publishing event: {:?}", event);
+
+        // fails only if nobody is subscribed, which is fine
+        _ = self.sender.send(event);
+    }
+
+    pub fn publish_all(&self, events: impl IntoIterator<Item = ServerEvent>) {
+        for event in events.into_iter() {
+            self.publish(event);
+        }
+    }
+
+    pub fn subscribe(&self) -> Receiver<ServerEvent> {
+        self.sender.subscribe()
+    }
+}
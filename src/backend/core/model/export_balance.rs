@@ -0,0 +1,30 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::backend::core::misc::CsvRecord;
+
+/// The balance of a user at a point in time in cents
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct ExportBalance {
+    pub user_id: i64,
+    pub nickname: String,
+    pub balance: i64,
+    pub at: DateTime<Utc>,
+}
+
+impl CsvRecord for ExportBalance {
+    fn header() -> Vec<&'static str> {
+        vec!["user_id", "nickname", "balance", "at"]
+    }
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.user_id.to_string(),
+            self.nickname.clone(),
+            self.balance.to_string(),
+            self.at.to_rfc3339(),
+        ]
+    }
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use serde::Deserialize;
+
+use crate::backend::core::ExportFormat;
+
+/// Query parameters of the export endpoints. Both dates are inclusive.
+#[derive(Deserialize, Clone, Copy, Debug, Default)]
+pub struct ExportParams {
+    pub from: Option<NaiveDate>,
+    pub until: Option<NaiveDate>,
+    #[serde(default)]
+    pub format: ExportFormat,
+}
+
+impl ExportParams {
+    /// Returns the range as `[from, until)` in utc
+    pub fn range(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        let from = self
+            .from
+            .map(|date| date.and_hms_opt(0, 0, 0).unwrap().and_utc())
+            .unwrap_or(DateTime::UNIX_EPOCH);
+
+        let until = self
+            .until
+            .and_then(|date| date.checked_add_days(Days::new(1)))
+            .map(|date| date.and_hms_opt(0, 0, 0).unwrap().and_utc())
+            .unwrap_or(Utc::now());
+
+        (from, until)
+    }
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::{backend::core::misc::CsvRecord, model::DatabaseId};
+
+/// One row per transaction and involved user. All amounts are in cents.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct ExportTransaction {
+    pub transaction_id: DatabaseId,
+    pub timestamp: DateTime<Utc>,
+    /// one of deposit, withdraw, purchase or transfer
+    pub kind: &'static str,
+    pub user_id: i64,
+    pub nickname: String,
+    pub senders: Vec<String>,
+    pub receivers: Vec<String>,
+    pub article: Option<String>,
+    /// the price of the article at the time of the purchase
+    pub effective_price: Option<i64>,
+    /// the full amount of the transaction
+    pub amount: i64,
+    /// the share of the amount for this user
+    pub delta: i64,
+    pub is_undone: bool,
+    /// when the transaction was undone, not known for undos before it was recorded
+    pub undone_at: Option<DateTime<Utc>>,
+    pub description: Option<String>,
+}
+
+impl CsvRecord for ExportTransaction {
+    fn header() -> Vec<&'static str> {
+        vec![
+            "transaction_id",
+            "timestamp",
+            "kind",
+            "user_id",
+            "nickname",
+            "senders",
+            "receivers",
+            "article",
+            "effective_price",
+            "amount",
+            "delta",
+            "is_undone",
+            "undone_at",
+            "description",
+        ]
+    }
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.transaction_id.to_string(),
+            self.timestamp.to_rfc3339(),
+            self.kind.to_string(),
+            self.user_id.to_string(),
+            self.nickname.clone(),
+            self.senders.join(", "),
+            self.receivers.join(", "),
+            self.article.clone().unwrap_or_default(),
+            self.effective_price
+                .map(|value| value.to_string())
+                .unwrap_or_default(),
+            self.amount.to_string(),
+            self.delta.to_string(),
+            self.is_undone.to_string(),
+            self.undone_at
+                .map(|value| value.to_rfc3339())
+                .unwrap_or_default(),
+            self.description.clone().unwrap_or_default(),
+        ]
+    }
+}
@@ -1,16 +1,62 @@
 pub mod article;
+pub mod article_component;
 pub mod barcode;
+pub mod debt_reminder;
+pub mod event_bus;
+pub mod export_balance;
+pub mod export_format;
+pub mod export_params;
+pub mod export_transaction;
 pub mod group;
+pub mod mqtt_purchase_command;
+pub mod mqtt_purchase_result;
+pub mod pin_sessions;
 pub mod settings;
+pub mod sound;
 pub mod state;
+pub mod statement;
+pub mod statement_format;
+pub mod statement_params;
 pub mod user;
+pub mod webhook_event;
+pub mod webhook_payload;
 
 pub use article::*;
+pub use article_component::*;
 pub use barcode::*;
 #[cfg(feature = "ssr")]
+pub use debt_reminder::*;
+#[cfg(feature = "ssr")]
+pub use event_bus::*;
+#[cfg(feature = "ssr")]
+pub use export_balance::*;
+#[cfg(feature = "ssr")]
+pub use export_format::*;
+#[cfg(feature = "ssr")]
+pub use export_params::*;
+#[cfg(feature = "ssr")]
+pub use export_transaction::*;
+#[cfg(feature = "ssr")]
 pub use group::*;
 #[cfg(feature = "ssr")]
+pub use mqtt_purchase_command::*;
+#[cfg(feature = "ssr")]
+pub use mqtt_purchase_result::*;
+#[cfg(feature = "ssr")]
+pub use pin_sessions::*;
+#[cfg(feature = "ssr")]
 pub use settings::*;
+pub use sound::*;
 #[cfg(feature = "ssr")]
 pub use state::*;
+#[cfg(feature = "ssr")]
+pub use statement::*;
+#[cfg(feature = "ssr")]
+pub use statement_format::*;
+#[cfg(feature = "ssr")]
+pub use statement_params::*;
 pub use user::*;
+#[cfg(feature = "ssr")]
+pub use webhook_event::*;
+#[cfg(feature = "ssr")]
+pub use webhook_payload::*;
@@ -0,0 +1,12 @@
+#![cfg(feature = "ssr")]
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::DatabaseId;
+
+/// A purchase requested over mqtt, e.g. by a button box
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MqttPurchaseCommand {
+    pub card_number: String,
+    pub article_id: DatabaseId,
+}
@@ -0,0 +1,14 @@
+#![cfg(feature = "ssr")]
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::DatabaseId;
+
+/// The outcome of a [`crate::backend::core::MqttPurchaseCommand`]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MqttPurchaseResult {
+    pub card_number: String,
+    pub article_id: DatabaseId,
+    pub success: bool,
+    pub error: Option<String>,
+}
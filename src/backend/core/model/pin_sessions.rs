@@ -0,0 +1,104 @@
+#![cfg(feature = "ssr")]
+
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use rand::{distr::Alphanumeric, Rng};
+
+use crate::model::UserId;
+
+/// name of the cookie that carries the token of a pin session
+pub const PIN_SESSION_COOKIE: &str = "strichliste_pin_session";
+
+/// length of the token in the pin session cookie
+const PIN_SESSION_TOKEN_LENGTH: usize = 32;
+
+/// how many attempts to unlock an account are allowed within `PIN_LOCKOUT`
+pub const MAX_PIN_ATTEMPTS: u32 = 5;
+
+/// how long an account stays locked once `MAX_PIN_ATTEMPTS` have been used up
+pub const PIN_LOCKOUT: Duration = Duration::from_secs(300);
+
+/// Accounts that were unlocked with their PIN or card, only kept in memory.
+/// A session belongs to a single user and expires after `accounts.pin_session_sec`.
+#[derive(Debug, Default)]
+pub struct PinSessions {
+    sessions: Mutex<HashMap<String, (UserId, Instant)>>,
+    /// the attempts to unlock a user and when the first of them was made
+    attempts: Mutex<HashMap<UserId, (u32, Instant)>>,
+}
+
+impl PinSessions {
+    /// Unlocks the user and returns the token for the cookie
+    pub fn create(&self, user_id: UserId, duration: Duration) -> String {
+        let token = rand::rng()
+            .sample_iter(&Alphanumeric)
+            .take(PIN_SESSION_TOKEN_LENGTH)
+            .map(char::from)
+            .collect::<String>();
+
+        let mut sessions = self.sessions.lock().unwrap();
+        let now = Instant::now();
+
+        sessions.retain(|_, (_, expires)| *expires > now);
+        sessions.insert(token.clone(), (user_id, now + duration));
+
+        token
+    }
+
+    /// How long the session of the token is still valid for the user, `None` if it isn't
+    pub fn remaining(&self, token: &str, user_id: UserId) -> Option<Duration> {
+        let sessions = self.sessions.lock().unwrap();
+
+        match sessions.get(token) {
+            Some((session_user, expires)) if *session_user == user_id => {
+                expires.checked_duration_since(Instant::now())
+            }
+            _ => None,
+        }
+    }
+
+    /// Counts an attempt to unlock the user before the PIN is checked, so parallel requests
+    /// can't get around the limit. Returns how long the user is still locked out, if they are.
+    pub fn start_attempt(&self, user_id: UserId) -> Option<Duration> {
+        let mut attempts = self.attempts.lock().unwrap();
+        let now = Instant::now();
+
+        attempts.retain(|_, (_, since)| now.duration_since(*since) < PIN_LOCKOUT);
+
+        let (count, since) = attempts.entry(user_id).or_insert((0, now));
+
+        if *count >= MAX_PIN_ATTEMPTS {
+            return Some(PIN_LOCKOUT.saturating_sub(now.duration_since(*since)));
+        }
+
+        *count += 1;
+        None
+    }
+
+    /// Forgets the attempts of the user after they unlocked their account
+    pub fn reset_attempts(&self, user_id: UserId) {
+        self.attempts.lock().unwrap().remove(&user_id);
+    }
+
+    /// The token of the pin session cookie of the current request
+    pub fn request_token() -> Option<String> {
+        use axum::http::{header, request::Parts};
+        use leptos::prelude::use_context;
+
+        let parts = use_context::<Parts>()?;
+
+        parts
+            .headers
+            .get_all(header::COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(';'))
+            .filter_map(|cookie| cookie.trim().split_once('='))
+            .find(|(name, _)| *name == PIN_SESSION_COOKIE)
+            .map(|(_, value)| value.to_string())
+    }
+}
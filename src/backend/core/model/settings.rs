@@ -2,12 +2,44 @@
 
 use config::ConfigError;
 use serde::Deserialize;
-use std::{collections::HashMap, path::PathBuf};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    backend::core::WebhookEvent,
+    model::{Currency, DatabaseId, KioskSettings, Locale},
+};
 
 #[derive(Deserialize, Debug)]
 pub struct Settings {
     pub sounds: SoundSettings,
     pub accounts: AccountsSettings,
+    #[serde(default)]
+    pub smtp: Option<SmtpSettings>,
+    #[serde(default)]
+    pub reminders: Option<ReminderSettings>,
+    #[serde(default)]
+    pub webhooks: Vec<WebhookSettings>,
+    #[serde(default)]
+    pub mqtt: Option<MqttSettings>,
+    #[serde(default)]
+    pub backups: Option<BackupSettings>,
+    /// forces the language of the ui, otherwise it is picked from the browser
+    #[serde(default)]
+    pub locale: Option<Locale>,
+    #[serde(default)]
+    pub currency: Currency,
+    #[serde(default)]
+    pub kiosk: KioskSettings,
+    #[serde(default)]
+    pub consumption_rules: Vec<ConsumptionRule>,
+    #[serde(default)]
+    pub milestones: Vec<MilestoneRule>,
+    /// announces transactions with text-to-speech, disabled if not set
+    #[serde(default)]
+    pub speech: Option<SpeechSettings>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -15,6 +47,9 @@ pub struct SoundSettings {
     pub articles: HashMap<String, Vec<String>>,
     pub generic: Vec<String>,
     pub failed: Vec<String>,
+    /// played when a consumption rule refuses a purchase, falls back to `failed`
+    #[serde(default)]
+    pub refused: Vec<String>,
     pub withdraw: Vec<String>,
     pub deposit: Vec<String>,
 }
@@ -29,6 +64,7 @@ impl SoundSettings {
 
         all_sounds.append(&mut self.generic.clone());
         all_sounds.append(&mut self.failed.clone());
+        all_sounds.append(&mut self.refused.clone());
         all_sounds.append(&mut self.withdraw.clone());
         all_sounds.append(&mut self.deposit.clone());
 
@@ -38,20 +74,354 @@ impl SoundSettings {
 
 #[derive(Deserialize, Debug)]
 pub struct AccountsSettings {
-    pub upper_limit: i64,
+    /// no balance may grow above this amount of cents, unlimited if it is left out
+    #[serde(default)]
+    pub upper_limit: Option<i64>,
     pub lower_limit: i64,
+    /// a low balance event is published when a balance drops below this amount of cents
+    #[serde(default)]
+    pub low_balance_threshold: i64,
+    /// how long an account stays unlocked after entering its PIN
+    #[serde(default = "default_pin_session_sec")]
+    pub pin_session_sec: u64,
+}
+
+fn default_pin_session_sec() -> u64 {
+    120
+}
+
+/// Restricts the purchases of a group of articles, e.g. all beers.
+/// Bundles are counted as their components.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ConsumptionRule {
+    /// shown to the user if the rule refuses a purchase
+    pub name: String,
+    /// the ids of the articles the rule applies to, so renaming an article keeps the rule
+    pub articles: Vec<DatabaseId>,
+    /// how many of the articles a user may buy per day (UTC)
+    #[serde(default)]
+    pub max_per_day: Option<i64>,
+    /// refuses the articles for users that are flagged as under 18
+    #[serde(default)]
+    pub adults_only: bool,
+}
+
+/// Celebrates a transaction with a toast and a special sound, checked after it was committed
+#[derive(Deserialize, Debug, Clone)]
+pub struct MilestoneRule {
+    /// shown in a toast, `{count}` is replaced with the number of purchases of `article_count`
+    pub message: String,
+    pub trigger: MilestoneTrigger,
+    /// the name of a sound on the sounds page, defaults to the milestone sounds
+    #[serde(default)]
+    pub sound: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MilestoneTrigger {
+    /// every `every`th purchase of the article by the same user, e.g. the 100th Mate
+    ArticleCount { article: String, every: i64 },
+    /// the first deposit of a user in a calendar month (UTC)
+    FirstDepositOfMonth,
+    /// the balance of the user was negative before the transaction and is not anymore
+    BalanceAboveZero,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SpeechEngine {
+    /// the speech synthesis of the browser
+    #[default]
+    Browser,
+    /// a local tts engine on the server, e.g. espeak-ng
+    Server,
+}
+
+fn default_speech_command() -> String {
+    "espeak-ng".to_string()
+}
+
+fn default_speech_args() -> Vec<String> {
+    vec!["--stdout".to_string()]
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SpeechSettings {
+    #[serde(default)]
+    pub engine: SpeechEngine,
+    /// speaks instead of the sounds, otherwise only if no sound fits or it can't be fetched
+    #[serde(default)]
+    pub always: bool,
+    /// the tts engine of the server, it gets the text on stdin and writes a wav file to stdout
+    #[serde(default = "default_speech_command")]
+    pub command: String,
+    #[serde(default = "default_speech_args")]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub templates: SpeechTemplates,
+}
+
+/// What is said per playback, `{user}`, `{article}`, `{amount}` and `{balance}` are replaced.
+/// Empty templates are not spoken.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct SpeechTemplates {
+    pub bought: String,
+    pub deposit: String,
+    pub withdraw: String,
+    pub sent: String,
+    pub undo: String,
+    pub failed: String,
+    pub refused: String,
+}
+
+impl Default for SpeechTemplates {
+    fn default() -> Self {
+        Self {
+            bought: "{user} bought {article}, balance {balance}".to_string(),
+            deposit: "{user} deposited {amount}, balance {balance}".to_string(),
+            withdraw: "{user} withdrew {amount}, balance {balance}".to_string(),
+            sent: "{user} sent {amount}, balance {balance}".to_string(),
+            undo: String::new(),
+            failed: String::new(),
+            refused: String::new(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpEncryption {
+    /// plain text, only useful for a local smtp server
+    None,
+    #[default]
+    Starttls,
+    Tls,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SmtpSettings {
+    pub host: String,
+    /// defaults to the standard port of the encryption
+    pub port: Option<u16>,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    #[serde(default)]
+    pub encryption: SmtpEncryption,
+    /// the sender address, e.g. `Strichliste <strichliste@example.org>`
+    pub from: String,
+}
+
+fn default_repeat_days() -> i64 {
+    7
+}
+
+fn default_max_per_run() -> usize {
+    20
+}
+
+fn default_check_interval_minutes() -> u64 {
+    60
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReminderSettings {
+    /// reminders are sent if the balance is below this amount of cents
+    pub threshold: i64,
+    /// how many days the balance has to stay below the threshold
+    pub days: i64,
+    /// the minimum amount of days between two reminders to the same user
+    #[serde(default = "default_repeat_days")]
+    pub repeat_days: i64,
+    /// the maximum amount of reminders sent per check
+    #[serde(default = "default_max_per_run")]
+    pub max_per_run: usize,
+    #[serde(default = "default_check_interval_minutes")]
+    pub check_interval_minutes: u64,
+    /// the public url of the instance, used for the opt-out link
+    pub base_url: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct WebhookSettings {
+    pub url: String,
+    /// used to sign the payload with hmac-sha256
+    pub secret: String,
+    /// the events that are sent to this webhook
+    pub events: Vec<WebhookEvent>,
+}
+
+fn default_mqtt_port() -> u16 {
+    1883
+}
+
+fn default_mqtt_client_id() -> String {
+    "strichliste-rs".to_string()
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct MqttSettings {
+    pub host: String,
+    #[serde(default = "default_mqtt_port")]
+    pub port: u16,
+    #[serde(default = "default_mqtt_client_id")]
+    pub client_id: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    #[serde(default)]
+    pub topics: MqttTopics,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct MqttTopics {
+    pub purchase: String,
+    pub deposit: String,
+    pub undo: String,
+    /// the sales of every article are published (retained) under `<sales>/<article id>`
+    pub sales: String,
+    /// purchases requested on this topic are executed, the outcome is published under `<command>/result`
+    pub command: Option<String>,
+}
+
+impl Default for MqttTopics {
+    fn default() -> Self {
+        Self {
+            purchase: "strichliste/purchase".to_string(),
+            deposit: "strichliste/deposit".to_string(),
+            undo: "strichliste/undo".to_string(),
+            sales: "strichliste/sales".to_string(),
+            command: None,
+        }
+    }
+}
+
+fn default_backup_interval_minutes() -> u64 {
+    360
+}
+
+fn default_keep_last() -> usize {
+    7
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct BackupSettings {
+    /// created if missing, should be on a different device than the database
+    pub directory: PathBuf,
+    #[serde(default = "default_backup_interval_minutes")]
+    pub interval_minutes: u64,
+    /// the amount of newest backups that are always kept, at least one
+    #[serde(default = "default_keep_last")]
+    pub keep_last: usize,
+    /// additionally keeps the newest backup of each of the last `keep_daily` days that have one
+    #[serde(default)]
+    pub keep_daily: usize,
+    #[serde(default)]
+    pub gzip: bool,
 }
 
 impl Settings {
-    pub fn new(config_path: PathBuf) -> Result<Self, ConfigError> {
-        use config::Config;
+    pub fn new(config_path: &Path) -> Result<Self, ConfigError> {
+        use config::{Config, File, FileFormat};
+
+        // the format is guessed from the extension, files without one are read as yaml
+        let file = match config_path.extension() {
+            Some(_) => File::from(config_path),
+            None => File::from(config_path).format(FileFormat::Yaml),
+        };
 
         let builder = Config::builder()
-            .add_source(config::File::with_name(
-                config_path.to_str().unwrap().rsplit_once(".").unwrap().0,
-            ))
+            .add_source(file)
             .add_source(config::Environment::with_prefix("STRICHLISTE"));
 
-        builder.build()?.try_deserialize()
+        let settings: Self = builder.build()?.try_deserialize()?;
+
+        Ok(settings)
+    }
+
+    /// Reads and validates the config file, returns all problems if it can't be used
+    pub fn load(config_path: &Path) -> Result<Self, Vec<String>> {
+        let settings = Self::new(config_path).map_err(|e| vec![e.to_string()])?;
+
+        let problems = settings.validate();
+
+        match problems.is_empty() {
+            true => Ok(settings),
+            false => Err(problems),
+        }
+    }
+
+    /// Returns every invalid value and missing file, the settings are only usable if it is empty
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        if self.accounts.lower_limit > 0 {
+            problems.push(String::from("accounts.lower_limit may not be positive"));
+        }
+
+        match self.accounts.upper_limit {
+            Some(limit) if limit < 0 => {
+                problems.push(String::from("accounts.upper_limit may not be negative"));
+            }
+            // 0 used to disable the limit, now it would refuse every deposit
+            Some(0) => problems.push(String::from(
+                "accounts.upper_limit of 0 refuses every deposit, leave it out for no upper limit",
+            )),
+            _ => {}
+        }
+
+        if self.currency.minor_digits > 4 {
+            problems.push(String::from(
+                "currency.minor_digits may not be larger than 4",
+            ));
+        }
+
+        if self.reminders.is_some() && self.smtp.is_none() {
+            problems.push(String::from("reminders need smtp to be configured"));
+        }
+
+        for rule in self.milestones.iter() {
+            if let MilestoneTrigger::ArticleCount { every, .. } = rule.trigger {
+                if every <= 0 {
+                    problems.push(format!(
+                        "the milestone '{}' needs a positive article_count.every",
+                        rule.message
+                    ));
+                }
+            }
+        }
+
+        if let Some(speech) = &self.speech {
+            if speech.engine == SpeechEngine::Server && speech.command.trim().is_empty() {
+                problems.push(String::from("speech.command may not be empty"));
+            }
+        }
+
+        let sounds = &self.sounds;
+        for (name, files) in [
+            ("generic", &sounds.generic),
+            ("failed", &sounds.failed),
+            ("withdraw", &sounds.withdraw),
+            ("deposit", &sounds.deposit),
+        ] {
+            if files.is_empty() {
+                problems.push(format!("sounds.{name} needs at least one file"));
+            }
+        }
+
+        let mut missing = sounds
+            .get_all_sounds()
+            .into_iter()
+            .filter(|file| !Path::new(file).is_file())
+            .collect::<Vec<_>>();
+        missing.sort();
+        missing.dedup();
+
+        for file in missing {
+            problems.push(format!("the sound file '{file}' does not exist"));
+        }
+
+        problems
     }
 }
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::{DatabaseId, SoundEvent};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Sound {
+    pub id: DatabaseId,
+    pub name: String,
+    pub events: Vec<SoundEvent>,
+    /// the articles this sound is played for instead of the generic sounds
+    pub article_ids: Vec<DatabaseId>,
+}
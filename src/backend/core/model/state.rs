@@ -1,6 +1,10 @@
 #![cfg(feature = "ssr")]
 use {
-    crate::backend::{core::Settings, database::DB},
+    crate::backend::{
+        core::{EventBus, PinSessions, Settings},
+        database::DB,
+    },
+    arc_swap::ArcSwap,
     std::sync::Arc,
     tokio::sync::Mutex,
 };
@@ -9,5 +13,8 @@ pub type ServerState = Arc<State>;
 
 pub struct State {
     pub db: Mutex<DB>,
-    pub settings: Settings,
+    /// swapped as a whole when the config file changes, load it once per request
+    pub settings: ArcSwap<Settings>,
+    pub events: EventBus,
+    pub pin_sessions: PinSessions,
 }
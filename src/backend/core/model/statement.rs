@@ -0,0 +1,28 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+
+use crate::model::{DatabaseId, StatementMonth};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatementEntry {
+    pub transaction_id: DatabaseId,
+    pub timestamp: DateTime<Utc>,
+    pub description: String,
+    /// the change of the balance of the user, in cents
+    pub delta: i64,
+    /// transactions undone before the time of undos was recorded are listed,
+    /// but don't count towards the balances. Later undos are entries of their own.
+    pub is_undone: bool,
+}
+
+/// The monthly statement of a single user. All amounts are in cents.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Statement {
+    pub user_id: DatabaseId,
+    pub nickname: String,
+    pub month: StatementMonth,
+    pub opening_balance: i64,
+    pub closing_balance: i64,
+    pub entries: Vec<StatementEntry>,
+}
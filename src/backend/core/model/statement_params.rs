@@ -0,0 +1,12 @@
+#![cfg(feature = "ssr")]
+
+use serde::Deserialize;
+
+use crate::backend::core::StatementFormat;
+
+/// Query parameters of the statement endpoint
+#[derive(Deserialize, Clone, Copy, Debug, Default)]
+pub struct StatementParams {
+    #[serde(default)]
+    pub format: StatementFormat,
+}
@@ -1,11 +1,27 @@
 use serde::{Deserialize, Serialize};
 
-use crate::model::{Money, UserId};
+use crate::model::{DatabaseId, Money, UserId};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct User {
     pub id: UserId,
     pub nickname: String,
     pub card_number: Option<String>,
+    /// used for debt reminders
+    pub email: Option<String>,
     pub money: Money,
+    /// whether the user wants to show up in the global statistics
+    pub show_in_stats: bool,
+    /// overrides `accounts.lower_limit` for this user
+    pub lower_limit: Option<Money>,
+    /// overrides `accounts.upper_limit` for this user
+    pub upper_limit: Option<Money>,
+    /// users under 18 may not buy articles of `adults_only` consumption rules
+    pub is_minor: bool,
+    /// purchases, transfers and settings changes need the PIN or the card, see `PinSessions`
+    pub has_pin: bool,
+    /// played instead of the article sounds when the user buys something
+    pub purchase_sound_id: Option<DatabaseId>,
+    /// played instead of the deposit sounds when the user deposits money
+    pub deposit_sound_id: Option<DatabaseId>,
 }
@@ -0,0 +1,47 @@
+#![cfg(feature = "ssr")]
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::{ServerEvent, TransactionKind};
+
+/// The events a webhook can subscribe to
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEvent {
+    Purchase,
+    Deposit,
+    Withdraw,
+    Transfer,
+    Undo,
+    UserCreated,
+    LowBalance,
+}
+
+impl WebhookEvent {
+    pub fn from_server_event(event: &ServerEvent) -> Option<Self> {
+        match event {
+            ServerEvent::TransactionCreated { kind, .. } => Some(match kind {
+                TransactionKind::Purchase => WebhookEvent::Purchase,
+                TransactionKind::Deposit => WebhookEvent::Deposit,
+                TransactionKind::Withdraw => WebhookEvent::Withdraw,
+                TransactionKind::Transfer => WebhookEvent::Transfer,
+            }),
+            ServerEvent::TransactionUndone { .. } => Some(WebhookEvent::Undo),
+            ServerEvent::UserCreated { .. } => Some(WebhookEvent::UserCreated),
+            ServerEvent::LowBalance { .. } => Some(WebhookEvent::LowBalance),
+            ServerEvent::UserUpdated { .. } | ServerEvent::ArticleUpdated { .. } => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            WebhookEvent::Purchase => "purchase",
+            WebhookEvent::Deposit => "deposit",
+            WebhookEvent::Withdraw => "withdraw",
+            WebhookEvent::Transfer => "transfer",
+            WebhookEvent::Undo => "undo",
+            WebhookEvent::UserCreated => "user_created",
+            WebhookEvent::LowBalance => "low_balance",
+        }
+    }
+}
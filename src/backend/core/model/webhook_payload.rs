@@ -0,0 +1,14 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::{backend::core::WebhookEvent, model::ServerEvent};
+
+/// The json body that is posted to the webhooks
+#[derive(Serialize, Clone, Debug)]
+pub struct WebhookPayload {
+    pub event: WebhookEvent,
+    pub timestamp: DateTime<Utc>,
+    pub data: ServerEvent,
+}
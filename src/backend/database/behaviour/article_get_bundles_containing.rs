@@ -0,0 +1,34 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query, Executor};
+
+use crate::{
+    backend::database::{ArticleDB, DBError, DatabaseResponse, DatabaseType},
+    model::DatabaseId,
+};
+
+impl ArticleDB {
+    pub async fn get_bundles_containing<T>(
+        conn: &mut T,
+        article_id: DatabaseId,
+    ) -> DatabaseResponse<Vec<DatabaseId>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        let result = query!(
+            "
+                select bundle_id from ArticleBundleComponents
+                where article_id = ?
+            ",
+            article_id
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(DBError::new)?
+        .into_iter()
+        .map(|elem| elem.bundle_id)
+        .collect();
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query_as, Executor};
+
+use crate::{
+    backend::database::{ArticleComponentDB, ArticleDB, DBError, DatabaseResponse, DatabaseType},
+    model::DatabaseId,
+};
+
+impl ArticleDB {
+    pub async fn get_components<T>(
+        conn: &mut T,
+        bundle_id: DatabaseId,
+    ) -> DatabaseResponse<Vec<ArticleComponentDB>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query_as!(
+            ArticleComponentDB,
+            "
+                select * from ArticleBundleComponents
+                where bundle_id = ?
+            ",
+            bundle_id
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(DBError::new)
+    }
+}
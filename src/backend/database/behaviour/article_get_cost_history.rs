@@ -0,0 +1,37 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use sqlx::{query_as, Executor};
+
+use crate::{
+    backend::database::{ArticleCostMapDB, ArticleDB, DBError, DatabaseResponse, DatabaseType},
+    model::DatabaseId,
+};
+
+impl ArticleDB {
+    /// Returns all prices the article ever had, oldest first
+    pub async fn get_cost_history<T>(
+        conn: &mut T,
+        article_id: DatabaseId,
+    ) -> DatabaseResponse<Vec<ArticleCostMapDB>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query_as!(
+            ArticleCostMapDB,
+            r#"
+                select
+                    article_id,
+                    cost,
+                    effective_since as "effective_since: DateTime<Utc>"
+                from ArticleCostMap
+                where article_id = ?
+                order by effective_since asc
+            "#,
+            article_id
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(DBError::new)
+    }
+}
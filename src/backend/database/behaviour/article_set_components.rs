@@ -0,0 +1,50 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query, Executor};
+
+use crate::{
+    backend::database::{ArticleDB, DBError, DatabaseResponse, DatabaseType},
+    model::DatabaseId,
+};
+
+impl ArticleDB {
+    /// Replaces all components of the bundle. An empty list turns the bundle back into a normal article.
+    pub async fn set_components<T>(
+        conn: &mut T,
+        bundle_id: DatabaseId,
+        components: &[(DatabaseId, i64)],
+    ) -> DatabaseResponse<()>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        _ = query!(
+            "
+                delete from ArticleBundleComponents
+                where bundle_id = ?
+            ",
+            bundle_id
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(DBError::new)?;
+
+        for (article_id, quantity) in components.iter() {
+            _ = query!(
+                "
+                    insert into ArticleBundleComponents
+                        (bundle_id, article_id, quantity)
+                    values
+                        (?, ?, ?)
+                ",
+                bundle_id,
+                article_id,
+                quantity
+            )
+            .execute(&mut *conn)
+            .await
+            .map_err(DBError::new)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,5 +1,8 @@
 #[cfg(feature = "ssr")]
-use sqlx::Executor;
+use {
+    chrono::{DateTime, Utc},
+    sqlx::Executor,
+};
 
 #[cfg(feature = "ssr")]
 use crate::{
@@ -17,12 +20,23 @@ impl ArticleDB {
     where
         for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
     {
-        use chrono::Utc;
+        Self::set_price_since(conn, article_id, cost, Utc::now()).await
+    }
+
+    /// Like [ArticleDB::set_price], but the price is effective since the given time
+    pub async fn set_price_since<T>(
+        conn: &mut T,
+        article_id: DatabaseId,
+        cost: i64,
+        effective_since: DateTime<Utc>,
+    ) -> DatabaseResponse<()>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
         use sqlx::query;
 
         use crate::backend::database::DBError;
 
-        let now = Utc::now();
         _ = query!(
             "
                 insert into ArticleCostMap
@@ -32,7 +46,7 @@ impl ArticleDB {
             ",
             article_id,
             cost,
-            now
+            effective_since
         )
         .execute(&mut *conn)
         .await
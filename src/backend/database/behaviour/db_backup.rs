@@ -0,0 +1,92 @@
+#![cfg(feature = "ssr")]
+
+use std::path::Path;
+
+use sqlx::{
+    migrate::Migrate,
+    query, query_scalar,
+    sqlite::{SqliteConnectOptions, SqliteConnection},
+    Connection,
+};
+
+use crate::backend::database::{DBError, DatabaseResponse, DB, MIGRATOR};
+
+impl DB {
+    /// Writes a consistent copy of the database to `path`, which must not exist yet.
+    /// Other connections may keep reading and writing meanwhile.
+    pub async fn backup_into(&self, path: &Path) -> DatabaseResponse<()> {
+        let path = path
+            .to_str()
+            .ok_or_else(|| DBError::new(format!("Invalid path: {}", path.display())))?;
+
+        let mut conn = self.get_conn().await?;
+
+        _ = query!("vacuum into ?", path)
+            .execute(&mut *conn)
+            .await
+            .map_err(DBError::new)?;
+
+        Ok(())
+    }
+
+    /// Opens the database file at `path` read-only and checks its integrity and that every
+    /// applied migration is one of ours. Returns the version of the newest applied migration.
+    pub async fn verify_file(path: &Path) -> DatabaseResponse<i64> {
+        let opts = SqliteConnectOptions::new().filename(path).read_only(true);
+
+        let mut conn = SqliteConnection::connect_with(&opts)
+            .await
+            .map_err(DBError::new)?;
+
+        let problems = query_scalar!("pragma integrity_check")
+            .fetch_all(&mut conn)
+            .await
+            .map_err(DBError::new)?
+            .into_iter()
+            .flatten()
+            .filter(|line| line != "ok")
+            .collect::<Vec<_>>();
+
+        if !problems.is_empty() {
+            return Err(DBError::new(format!(
+                "Integrity check failed: {}",
+                problems.join(", ")
+            )));
+        }
+
+        let applied = conn
+            .list_applied_migrations()
+            .await
+            .map_err(|e| DBError::new(format!("Failed to read the migrations: {e}")))?;
+
+        for migration in applied.iter() {
+            let known = MIGRATOR.iter().find(|known| {
+                known.version == migration.version && !known.migration_type.is_down_migration()
+            });
+
+            match known {
+                None => {
+                    return Err(DBError::new(format!(
+                        "Unknown migration {}, the database is from a newer version",
+                        migration.version
+                    )))
+                }
+                Some(known) if known.checksum != migration.checksum => {
+                    return Err(DBError::new(format!(
+                        "Migration {} has been modified",
+                        migration.version
+                    )))
+                }
+                Some(_) => {}
+            }
+        }
+
+        conn.close().await.map_err(DBError::new)?;
+
+        applied
+            .iter()
+            .map(|migration| migration.version)
+            .max()
+            .ok_or_else(|| DBError::new("No migrations have been applied"))
+    }
+}
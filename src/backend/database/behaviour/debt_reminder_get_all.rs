@@ -0,0 +1,27 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use sqlx::{query_as, Executor};
+
+use crate::backend::database::{DatabaseResponse, DatabaseType, DebtReminderDB};
+
+impl DebtReminderDB {
+    pub async fn get_all<T>(conn: &mut T) -> DatabaseResponse<Vec<Self>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query_as!(
+            DebtReminderDB,
+            r#"
+                select
+                    user_id,
+                    below_threshold_since as "below_threshold_since: DateTime<Utc>",
+                    last_sent as "last_sent: DateTime<Utc>"
+                from DebtReminders
+            "#
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(From::from)
+    }
+}
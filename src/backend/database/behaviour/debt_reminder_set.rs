@@ -0,0 +1,32 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query, Executor};
+
+use crate::backend::database::{DatabaseResponse, DatabaseType, DebtReminderDB};
+
+impl DebtReminderDB {
+    pub async fn set<T>(&self, conn: &mut T) -> DatabaseResponse<()>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query!(
+            "
+                insert into DebtReminders
+                    (user_id, below_threshold_since, last_sent)
+                values
+                    (?, ?, ?)
+                on conflict (user_id) do update
+                set
+                    below_threshold_since = excluded.below_threshold_since,
+                    last_sent = excluded.last_sent
+            ",
+            self.user_id,
+            self.below_threshold_since,
+            self.last_sent
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(From::from)
+        .map(|_| ())
+    }
+}
@@ -20,7 +20,11 @@ impl GroupDB {
                     Users.money,
                     Users.is_system_user,
                     Users.created_at as "created_at: DateTime<Utc>",
-                    Users.disabled
+                    Users.disabled,
+                    Users.show_in_stats,
+                    Users.lower_limit,
+                    Users.upper_limit,
+                    Users.is_minor
                     from UserGroupMap
                 join Users on Users.id = UserGroupMap.uid 
                     where UserGroupMap.gid = ?
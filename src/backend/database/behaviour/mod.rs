@@ -4,12 +4,19 @@ pub mod article_get_all;
 pub mod article_get_article_id_by_barcode;
 pub mod article_get_articles_for_users;
 pub mod article_get_barcodes;
+pub mod article_get_bundles_containing;
+pub mod article_get_components;
+pub mod article_get_cost_history;
 pub mod article_get_effective_cost;
 pub mod article_get_latest_cost;
 pub mod article_get_single;
 pub mod article_remove_barcode;
+pub mod article_set_components;
 pub mod article_set_name;
 pub mod article_set_price;
+pub mod db_backup;
+pub mod debt_reminder_get_all;
+pub mod debt_reminder_set;
 pub mod group_create;
 pub mod group_create_group_for_multiple_users;
 pub mod group_get;
@@ -18,18 +25,58 @@ pub mod group_get_groups;
 pub mod group_get_members;
 pub mod group_get_single_group;
 pub mod group_link_user;
+pub mod sound_create;
+pub mod sound_delete;
+pub mod sound_get_all;
+pub mod sound_get_article_ids;
+pub mod sound_get_data;
+pub mod sound_get_events;
+pub mod sound_get_id_by_name;
+pub mod sound_get_ids_for_article;
+pub mod sound_get_ids_for_event;
+pub mod sound_set_articles;
+pub mod sound_set_events;
+pub mod transaction_add_article;
 pub mod transaction_create;
 pub mod transaction_get;
+pub mod transaction_get_all_sales_counts;
+pub mod transaction_get_booked_articles;
+pub mod transaction_get_in_range;
+pub mod transaction_get_purchases;
+pub mod transaction_get_sales_counts;
+pub mod transaction_get_undone_since;
+pub mod transaction_get_user_deposit_count_since;
+pub mod transaction_get_user_in_range;
+pub mod transaction_get_user_sales_counts_since;
 pub mod transaction_get_user_transactions;
+pub mod transaction_get_user_undone_in_range;
+pub mod transaction_get_user_undone_since;
 pub mod transaction_set_money;
+pub mod transaction_set_timestamp;
 pub mod transaction_set_undone;
+pub mod user_email_get_all;
+pub mod user_email_opt_out;
 pub mod user_get;
+pub mod user_get_active_count;
 pub mod user_get_all;
+pub mod user_get_balance_totals;
 pub mod user_get_by_nick;
 pub mod user_get_card_number;
+pub mod user_get_email;
 pub mod user_get_id_by_card_number;
+pub mod user_get_pin_hash;
+pub mod user_get_sound_ids;
 pub mod user_insert;
 pub mod user_insert_card;
 pub mod user_set_card_number;
+pub mod user_set_email;
+pub mod user_set_is_minor;
+pub mod user_set_limits;
 pub mod user_set_money;
 pub mod user_set_name;
+pub mod user_set_pin_hash;
+pub mod user_set_show_in_stats;
+pub mod user_set_sound_ids;
+pub mod webhook_delivery_create;
+pub mod webhook_delivery_get_due;
+pub mod webhook_delivery_set_result;
@@ -0,0 +1,31 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query, Executor};
+
+use crate::{
+    backend::database::{DBError, DatabaseResponse, DatabaseType, SoundDB},
+    model::DatabaseId,
+};
+
+impl SoundDB {
+    pub async fn create<T>(conn: &mut T, name: &str, data: &[u8]) -> DatabaseResponse<DatabaseId>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query!(
+            "
+                insert into Sounds
+                    (name, data)
+                values
+                    (?, ?)
+                returning id
+            ",
+            name,
+            data
+        )
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(DBError::new)
+        .map(|elem| elem.id)
+    }
+}
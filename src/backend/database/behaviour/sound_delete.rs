@@ -0,0 +1,75 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query, Executor};
+
+use crate::{
+    backend::database::{DBError, DatabaseResponse, DatabaseType, SoundDB},
+    model::DatabaseId,
+};
+
+impl SoundDB {
+    /// Deletes the sound together with its assignments, users that picked it get the default back
+    pub async fn delete<T>(conn: &mut T, sound_id: DatabaseId) -> DatabaseResponse<()>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        _ = query!(
+            "
+                update Users
+                set purchase_sound_id = null
+                where purchase_sound_id = ?
+            ",
+            sound_id
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(DBError::new)?;
+
+        _ = query!(
+            "
+                update Users
+                set deposit_sound_id = null
+                where deposit_sound_id = ?
+            ",
+            sound_id
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(DBError::new)?;
+
+        _ = query!(
+            "
+                delete from SoundEvents
+                where sound_id = ?
+            ",
+            sound_id
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(DBError::new)?;
+
+        _ = query!(
+            "
+                delete from ArticleSounds
+                where sound_id = ?
+            ",
+            sound_id
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(DBError::new)?;
+
+        _ = query!(
+            "
+                delete from Sounds
+                where id = ?
+            ",
+            sound_id
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(DBError::new)?;
+
+        Ok(())
+    }
+}
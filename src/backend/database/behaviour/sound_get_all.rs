@@ -0,0 +1,23 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query_as, Executor};
+
+use crate::backend::database::{DBError, DatabaseResponse, DatabaseType, SoundDB};
+
+impl SoundDB {
+    pub async fn get_all<T>(conn: &mut T) -> DatabaseResponse<Vec<SoundDB>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query_as!(
+            SoundDB,
+            "
+                select id, name from Sounds
+                order by name
+            "
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(DBError::new)
+    }
+}
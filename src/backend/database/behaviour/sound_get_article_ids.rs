@@ -0,0 +1,30 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query_scalar, Executor};
+
+use crate::{
+    backend::database::{DBError, DatabaseResponse, DatabaseType, SoundDB},
+    model::DatabaseId,
+};
+
+impl SoundDB {
+    /// Returns the ids of the articles the sound is assigned to
+    pub async fn get_article_ids<T>(
+        conn: &mut T,
+        sound_id: DatabaseId,
+    ) -> DatabaseResponse<Vec<DatabaseId>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query_scalar!(
+            "
+                select article_id from ArticleSounds
+                where sound_id = ?
+            ",
+            sound_id
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(DBError::new)
+    }
+}
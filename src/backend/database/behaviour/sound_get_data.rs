@@ -0,0 +1,29 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query_scalar, Executor};
+
+use crate::{
+    backend::database::{DBError, DatabaseResponse, DatabaseType, SoundDB},
+    model::DatabaseId,
+};
+
+impl SoundDB {
+    pub async fn get_data<T>(
+        conn: &mut T,
+        sound_id: DatabaseId,
+    ) -> DatabaseResponse<Option<Vec<u8>>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query_scalar!(
+            "
+                select data from Sounds
+                where id = ?
+            ",
+            sound_id
+        )
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(DBError::new)
+    }
+}
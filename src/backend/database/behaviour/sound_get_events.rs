@@ -0,0 +1,27 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query_scalar, Executor};
+
+use crate::{
+    backend::database::{DBError, DatabaseResponse, DatabaseType, SoundDB},
+    model::DatabaseId,
+};
+
+impl SoundDB {
+    /// Returns the names of the events the sound is assigned to
+    pub async fn get_events<T>(conn: &mut T, sound_id: DatabaseId) -> DatabaseResponse<Vec<String>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query_scalar!(
+            "
+                select event from SoundEvents
+                where sound_id = ?
+            ",
+            sound_id
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(DBError::new)
+    }
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query_scalar, Executor};
+
+use crate::{
+    backend::database::{DBError, DatabaseResponse, DatabaseType, SoundDB},
+    model::DatabaseId,
+};
+
+impl SoundDB {
+    /// Returns the oldest sound with the name
+    pub async fn get_id_by_name<T>(conn: &mut T, name: &str) -> DatabaseResponse<Option<DatabaseId>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query_scalar!(
+            "
+                select id from Sounds
+                where name = ?
+                order by id
+                limit 1
+            ",
+            name
+        )
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(DBError::new)
+    }
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query_scalar, Executor};
+
+use crate::{
+    backend::database::{DBError, DatabaseResponse, DatabaseType, SoundDB},
+    model::DatabaseId,
+};
+
+impl SoundDB {
+    pub async fn get_ids_for_article<T>(
+        conn: &mut T,
+        article_id: DatabaseId,
+    ) -> DatabaseResponse<Vec<DatabaseId>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query_scalar!(
+            "
+                select sound_id from ArticleSounds
+                where article_id = ?
+            ",
+            article_id
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(DBError::new)
+    }
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query, Executor};
+
+use crate::{
+    backend::database::{DBError, DatabaseResponse, DatabaseType, SoundDB},
+    model::DatabaseId,
+};
+
+impl SoundDB {
+    /// Replaces the articles the sound is assigned to
+    pub async fn set_articles<T>(
+        conn: &mut T,
+        sound_id: DatabaseId,
+        article_ids: &[DatabaseId],
+    ) -> DatabaseResponse<()>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        _ = query!(
+            "
+                delete from ArticleSounds
+                where sound_id = ?
+            ",
+            sound_id
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(DBError::new)?;
+
+        for article_id in article_ids.iter() {
+            _ = query!(
+                "
+                    insert into ArticleSounds
+                        (sound_id, article_id)
+                    values
+                        (?, ?)
+                ",
+                sound_id,
+                article_id
+            )
+            .execute(&mut *conn)
+            .await
+            .map_err(DBError::new)?;
+        }
+
+        Ok(())
+    }
+}
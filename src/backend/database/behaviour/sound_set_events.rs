@@ -0,0 +1,49 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query, Executor};
+
+use crate::{
+    backend::database::{DBError, DatabaseResponse, DatabaseType, SoundDB},
+    model::DatabaseId,
+};
+
+impl SoundDB {
+    /// Replaces the events the sound is assigned to
+    pub async fn set_events<T>(
+        conn: &mut T,
+        sound_id: DatabaseId,
+        events: &[&str],
+    ) -> DatabaseResponse<()>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        _ = query!(
+            "
+                delete from SoundEvents
+                where sound_id = ?
+            ",
+            sound_id
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(DBError::new)?;
+
+        for event in events.iter() {
+            _ = query!(
+                "
+                    insert into SoundEvents
+                        (sound_id, event)
+                    values
+                        (?, ?)
+                ",
+                sound_id,
+                event
+            )
+            .execute(&mut *conn)
+            .await
+            .map_err(DBError::new)?;
+        }
+
+        Ok(())
+    }
+}
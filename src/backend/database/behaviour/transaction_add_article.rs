@@ -0,0 +1,37 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query, Executor};
+
+use crate::{
+    backend::database::{DBError, DatabaseResponse, DatabaseType, TransactionDB},
+    model::DatabaseId,
+};
+
+impl TransactionDB {
+    pub async fn add_article<T>(
+        conn: &mut T,
+        transaction_id: DatabaseId,
+        article_id: DatabaseId,
+        amount: i64,
+    ) -> DatabaseResponse<()>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        _ = query!(
+            "
+                insert into TransactionArticleMap
+                    (transaction_id, article_id, amount)
+                values
+                    (?, ?, ?)
+            ",
+            transaction_id,
+            article_id,
+            amount
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(DBError::new)?;
+
+        Ok(())
+    }
+}
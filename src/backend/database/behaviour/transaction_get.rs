@@ -25,7 +25,8 @@ impl TransactionDB {
                     t_type_data,
                     money as "money: u64",
                     description,
-                    timestamp as "timestamp: DateTime<Utc>"
+                    timestamp as "timestamp: DateTime<Utc>",
+                    undone_at as "undone_at: DateTime<Utc>"
                 from Transactions
                 where id = ?
             "#,
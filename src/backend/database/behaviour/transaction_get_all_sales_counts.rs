@@ -0,0 +1,38 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query_as, Executor};
+
+use crate::backend::database::{
+    ArticleSalesCountDB, DBError, DatabaseResponse, DatabaseType, TransactionDB,
+    DBGROUP_SNACKBAR_ID,
+};
+
+impl TransactionDB {
+    /// Returns the total sales of every article that has been sold at least once.
+    /// Bundles are counted as their components.
+    pub async fn get_all_sales_counts<T>(conn: &mut T) -> DatabaseResponse<Vec<ArticleSalesCountDB>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query_as!(
+            ArticleSalesCountDB,
+            r#"
+                select
+                    Articles.id as "article_id!: i64",
+                    Articles.name as "name!: String",
+                    sum(TAM.amount) as "sold!: i64"
+                from TransactionArticleMap as TAM
+                join Transactions as T on T.id = TAM.transaction_id
+                join Articles on Articles.id = TAM.article_id
+                where
+                    T.receiver = ?
+                    and T.is_undone = 0
+                group by Articles.id, Articles.name
+            "#,
+            DBGROUP_SNACKBAR_ID.0
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(DBError::new)
+    }
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use sqlx::{query_as, Executor};
+
+use crate::{
+    backend::database::{
+        ArticleSaleDB, DBError, DatabaseResponse, DatabaseType, TransactionDB, DBGROUP_SNACKBAR_ID,
+    },
+    model::UserId,
+};
+
+impl TransactionDB {
+    /// Returns the articles booked for purchases, meaning bundles are resolved into their components.
+    /// If `user_id` is `None` the articles of all users are returned.
+    pub async fn get_booked_articles<T>(
+        conn: &mut T,
+        user_id: Option<UserId>,
+    ) -> DatabaseResponse<Vec<ArticleSaleDB>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        let user_id = user_id.map(|elem| elem.0);
+
+        query_as!(
+            ArticleSaleDB,
+            r#"
+                select
+                    TAM.transaction_id as "transaction_id!: i64",
+                    TAM.article_id as "article_id!: i64",
+                    TAM.amount as "amount!: i64",
+                    UGM.uid as "user_id!: i64",
+                    T.timestamp as "timestamp!: DateTime<Utc>"
+                from TransactionArticleMap as TAM
+                join Transactions as T on T.id = TAM.transaction_id
+                join UserGroupMap as UGM on UGM.gid = T.sender
+                where
+                    T.receiver = ?
+                    and T.is_undone = 0
+                    and UGM.uid = coalesce(?, UGM.uid)
+                order by T.timestamp asc
+            "#,
+            DBGROUP_SNACKBAR_ID.0,
+            user_id
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(DBError::new)
+    }
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use sqlx::{query_as, Executor};
+
+use crate::backend::database::{DBError, DatabaseResponse, DatabaseType, TransactionDB};
+
+impl TransactionDB {
+    /// Returns all transactions with `from <= timestamp < until`, oldest first
+    pub async fn get_in_range<T>(
+        conn: &mut T,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> DatabaseResponse<Vec<Self>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query_as!(
+            TransactionDB,
+            r#"
+                select
+                    id as "id: i64",
+                    sender as "sender: i64",
+                    receiver as "receiver: i64",
+                    is_undone,
+                    t_type_data,
+                    money as "money: u64",
+                    description,
+                    timestamp as "timestamp: DateTime<Utc>",
+                    undone_at as "undone_at: DateTime<Utc>"
+                from Transactions
+                where timestamp >= ? and timestamp < ?
+                order by timestamp asc
+            "#,
+            from,
+            until
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(DBError::new)
+    }
+}
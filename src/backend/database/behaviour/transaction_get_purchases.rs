@@ -0,0 +1,50 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use sqlx::{query_as, Executor};
+
+use crate::{
+    backend::database::{
+        ArticleSaleDB, DBError, DatabaseResponse, DatabaseType, TransactionDB, DBGROUP_SNACKBAR_ID,
+    },
+    model::UserId,
+};
+
+impl TransactionDB {
+    /// Returns one entry per purchase with the article that was bought (which may be a bundle).
+    /// If `user_id` is `None` the purchases of all users are returned.
+    pub async fn get_purchases<T>(
+        conn: &mut T,
+        user_id: Option<UserId>,
+    ) -> DatabaseResponse<Vec<ArticleSaleDB>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        let user_id = user_id.map(|elem| elem.0);
+
+        query_as!(
+            ArticleSaleDB,
+            r#"
+                select
+                    Transactions.id as "transaction_id!: i64",
+                    Transactions.t_type_data as "article_id!: i64",
+                    1 as "amount!: i64",
+                    UserGroupMap.uid as "user_id!: i64",
+                    Transactions.timestamp as "timestamp!: DateTime<Utc>"
+                from Transactions
+                join UserGroupMap on UserGroupMap.gid = Transactions.sender
+                where
+                    Transactions.receiver = ?
+                    and Transactions.is_undone = 0
+                    and Transactions.t_type_data is not null
+                    and UserGroupMap.uid = coalesce(?, UserGroupMap.uid)
+                order by Transactions.timestamp asc
+            "#,
+            DBGROUP_SNACKBAR_ID.0,
+            user_id
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(DBError::new)
+    }
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query_as, Executor};
+
+use crate::{
+    backend::database::{
+        ArticleSalesCountDB, DBError, DatabaseResponse, DatabaseType, TransactionDB,
+        DBGROUP_SNACKBAR_ID,
+    },
+    model::DatabaseId,
+};
+
+impl TransactionDB {
+    /// Returns the total sales of every article that was booked by the given transaction.
+    /// Bundles are counted as their components.
+    pub async fn get_sales_counts<T>(
+        conn: &mut T,
+        transaction_id: DatabaseId,
+    ) -> DatabaseResponse<Vec<ArticleSalesCountDB>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query_as!(
+            ArticleSalesCountDB,
+            r#"
+                select
+                    Articles.id as "article_id!: i64",
+                    Articles.name as "name!: String",
+                    coalesce(sum(case when T.is_undone = 0 then TAM.amount else 0 end), 0) as "sold!: i64"
+                from TransactionArticleMap as TAM
+                join Transactions as T on T.id = TAM.transaction_id
+                join Articles on Articles.id = TAM.article_id
+                where
+                    T.receiver = ?
+                    and TAM.article_id in (
+                        select article_id from TransactionArticleMap where transaction_id = ?
+                    )
+                group by Articles.id, Articles.name
+            "#,
+            DBGROUP_SNACKBAR_ID.0,
+            transaction_id
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(DBError::new)
+    }
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use sqlx::{query, Executor};
+
+use crate::{
+    backend::database::{
+        DBError, DatabaseResponse, DatabaseType, TransactionDB, DBGROUP_AUFLADUNG_ID,
+    },
+    model::UserId,
+};
+
+impl TransactionDB {
+    /// Returns how many (not undone) deposits the user made since the given point in time
+    pub async fn get_user_deposit_count_since<T>(
+        conn: &mut T,
+        user_id: UserId,
+        since: DateTime<Utc>,
+    ) -> DatabaseResponse<i64>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query!(
+            r#"
+                select
+                    count(*) as "count!: i64"
+                from Transactions as T
+                join UserGroupMap as UGM on UGM.gid = T.receiver
+                where
+                    T.sender = ?
+                    and T.is_undone = 0
+                    and T.timestamp >= ?
+                    and UGM.uid = ?
+            "#,
+            DBGROUP_AUFLADUNG_ID.0,
+            since,
+            user_id.0
+        )
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(DBError::new)
+        .map(|elem| elem.count)
+    }
+}
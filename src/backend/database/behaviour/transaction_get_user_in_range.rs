@@ -0,0 +1,51 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use sqlx::{query_as, Executor};
+
+use crate::{
+    backend::database::{DBError, DatabaseResponse, DatabaseType, TransactionDB},
+    model::UserId,
+};
+
+impl TransactionDB {
+    /// Returns the transactions of the user with `from <= timestamp < until`, oldest first
+    pub async fn get_user_in_range<T>(
+        conn: &mut T,
+        user_id: UserId,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> DatabaseResponse<Vec<Self>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query_as!(
+            TransactionDB,
+            r#"
+                select
+                    id as "id: i64",
+                    sender as "sender: i64",
+                    receiver as "receiver: i64",
+                    is_undone,
+                    t_type_data,
+                    money as "money: u64",
+                    description,
+                    timestamp as "timestamp: DateTime<Utc>",
+                    undone_at as "undone_at: DateTime<Utc>"
+                from Transactions
+                where timestamp >= ? and timestamp < ?
+                    and exists (
+                        select 1 from UserGroupMap as UGM
+                        where UGM.uid = ? and (UGM.gid = sender or UGM.gid = receiver)
+                    )
+                order by timestamp asc
+            "#,
+            from,
+            until,
+            user_id.0
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(DBError::new)
+    }
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use sqlx::{query_as, Executor};
+
+use crate::{
+    backend::database::{
+        ArticleSalesCountDB, DBError, DatabaseResponse, DatabaseType, TransactionDB,
+        DBGROUP_SNACKBAR_ID,
+    },
+    model::UserId,
+};
+
+impl TransactionDB {
+    /// Returns how often the user bought every article since the given point in time.
+    /// Bundles are counted as their components.
+    pub async fn get_user_sales_counts_since<T>(
+        conn: &mut T,
+        user_id: UserId,
+        since: DateTime<Utc>,
+    ) -> DatabaseResponse<Vec<ArticleSalesCountDB>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query_as!(
+            ArticleSalesCountDB,
+            r#"
+                select
+                    Articles.id as "article_id!: i64",
+                    Articles.name as "name!: String",
+                    coalesce(sum(TAM.amount), 0) as "sold!: i64"
+                from TransactionArticleMap as TAM
+                join Transactions as T on T.id = TAM.transaction_id
+                join UserGroupMap as UGM on UGM.gid = T.sender
+                join Articles on Articles.id = TAM.article_id
+                where
+                    T.receiver = ?
+                    and T.is_undone = 0
+                    and T.timestamp >= ?
+                    and UGM.uid = ?
+                group by Articles.id, Articles.name
+            "#,
+            DBGROUP_SNACKBAR_ID.0,
+            since,
+            user_id.0
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(DBError::new)
+    }
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use sqlx::{query_as, Executor};
+
+use crate::{
+    backend::database::{DBError, DatabaseResponse, DatabaseType, TransactionDB},
+    model::UserId,
+};
+
+impl TransactionDB {
+    /// Returns the transactions of the user that were undone with `from <= undone_at < until`,
+    /// in the order they were undone
+    pub async fn get_user_undone_in_range<T>(
+        conn: &mut T,
+        user_id: UserId,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> DatabaseResponse<Vec<Self>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query_as!(
+            TransactionDB,
+            r#"
+                select
+                    id as "id: i64",
+                    sender as "sender: i64",
+                    receiver as "receiver: i64",
+                    is_undone,
+                    t_type_data,
+                    money as "money: u64",
+                    description,
+                    timestamp as "timestamp: DateTime<Utc>",
+                    undone_at as "undone_at: DateTime<Utc>"
+                from Transactions
+                where is_undone = 1 and undone_at >= ? and undone_at < ?
+                    and exists (
+                        select 1 from UserGroupMap as UGM
+                        where UGM.uid = ? and (UGM.gid = sender or UGM.gid = receiver)
+                    )
+                order by undone_at asc
+            "#,
+            from,
+            until,
+            user_id.0
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(DBError::new)
+    }
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use sqlx::{query_as, Executor};
+
+use crate::{
+    backend::database::{DBError, DatabaseResponse, DatabaseType, TransactionDB},
+    model::UserId,
+};
+
+impl TransactionDB {
+    /// Like [`TransactionDB::get_undone_since`], but only the transactions of the user
+    pub async fn get_user_undone_since<T>(
+        conn: &mut T,
+        user_id: UserId,
+        at: DateTime<Utc>,
+    ) -> DatabaseResponse<Vec<Self>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query_as!(
+            TransactionDB,
+            r#"
+                select
+                    id as "id: i64",
+                    sender as "sender: i64",
+                    receiver as "receiver: i64",
+                    is_undone,
+                    t_type_data,
+                    money as "money: u64",
+                    description,
+                    timestamp as "timestamp: DateTime<Utc>",
+                    undone_at as "undone_at: DateTime<Utc>"
+                from Transactions
+                where timestamp < ? and is_undone = 1 and undone_at >= ?
+                    and exists (
+                        select 1 from UserGroupMap as UGM
+                        where UGM.uid = ? and (UGM.gid = sender or UGM.gid = receiver)
+                    )
+                order by timestamp asc
+            "#,
+            at,
+            at,
+            user_id.0
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(DBError::new)
+    }
+}
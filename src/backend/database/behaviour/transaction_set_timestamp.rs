@@ -0,0 +1,32 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use sqlx::{query, Executor};
+
+use crate::backend::database::{DBError, DatabaseResponse, DatabaseType, TransactionDB};
+
+impl TransactionDB {
+    pub async fn set_timestamp<T>(
+        conn: &mut T,
+        id: i64,
+        timestamp: DateTime<Utc>,
+    ) -> DatabaseResponse<()>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        _ = query!(
+            "
+                update Transactions
+                set timestamp = ?
+                where id = ?
+            ",
+            timestamp,
+            id
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(DBError::new)?;
+
+        Ok(())
+    }
+}
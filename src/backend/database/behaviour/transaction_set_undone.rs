@@ -1,21 +1,26 @@
 #![cfg(feature = "ssr")]
 
+use chrono::Utc;
 use sqlx::{query, Executor};
 
 use crate::backend::database::{DBError, DatabaseResponse, DatabaseType, TransactionDB};
 
 impl TransactionDB {
+    /// Also records when the transaction was undone, so balances in the past can be rebuilt
     pub async fn set_undone<T>(conn: &mut T, id: i64, new_value: bool) -> DatabaseResponse<()>
     where
         for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
     {
+        let undone_at = new_value.then(Utc::now);
+
         _ = query!(
             "
                 update Transactions
-                set is_undone = ?
+                set is_undone = ?, undone_at = ?
                 where id = ?
             ",
             new_value,
+            undone_at,
             id
         )
         .execute(&mut *conn)
@@ -0,0 +1,22 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query_as, Executor};
+
+use crate::backend::database::{DatabaseResponse, DatabaseType, UserEmailDB};
+
+impl UserEmailDB {
+    pub async fn get_all<T>(conn: &mut T) -> DatabaseResponse<Vec<Self>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query_as!(
+            UserEmailDB,
+            "
+                select * from UserEmailMap
+            "
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(From::from)
+    }
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query, Executor};
+
+use crate::{
+    backend::database::{DatabaseResponse, DatabaseType, UserEmailDB},
+    model::DatabaseId,
+};
+
+impl UserEmailDB {
+    /// Disables the reminders for the user with the given token. Returns the id of that user.
+    pub async fn opt_out<T>(conn: &mut T, token: &str) -> DatabaseResponse<Option<DatabaseId>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query!(
+            "
+                update UserEmailMap
+                set reminders_opt_out = true
+                where opt_out_token = ?
+                returning user_id
+            ",
+            token
+        )
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(From::from)
+        .map(|result| result.map(|elem| elem.user_id))
+    }
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use sqlx::{query, Executor};
+
+use crate::backend::database::{DatabaseResponse, DatabaseType, UserDB};
+
+impl UserDB {
+    /// Returns the number of users that took part in a (not undone) transaction since the given timestamp
+    pub async fn get_active_count<T>(conn: &mut T, since: DateTime<Utc>) -> DatabaseResponse<i64>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query!(
+            r#"
+                select
+                    count(distinct Users.id) as "count!: i64"
+                from Transactions
+                join UserGroupMap on
+                    UserGroupMap.gid = Transactions.sender
+                    or UserGroupMap.gid = Transactions.receiver
+                join Users on Users.id = UserGroupMap.uid
+                where
+                    Transactions.is_undone = false
+                    and Transactions.timestamp >= ?
+                    and Users.is_system_user = false
+            "#,
+            since
+        )
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(From::from)
+        .map(|elem| elem.count)
+    }
+}
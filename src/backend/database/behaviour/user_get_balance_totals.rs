@@ -0,0 +1,28 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query_as, Executor};
+
+use crate::backend::database::{BalanceTotalsDB, DatabaseResponse, DatabaseType, UserDB};
+
+impl UserDB {
+    pub async fn get_balance_totals<T>(conn: &mut T) -> DatabaseResponse<BalanceTotalsDB>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query_as!(
+            BalanceTotalsDB,
+            r#"
+                select
+                    coalesce(sum(case when money < 0 then -money else 0 end), 0) as "debt!: i64",
+                    coalesce(sum(case when money > 0 then money else 0 end), 0) as "credit!: i64"
+                from
+                    Users
+                where
+                    is_system_user = false
+            "#,
+        )
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(From::from)
+    }
+}
@@ -18,7 +18,11 @@ impl UserDB {
                     money,
                     is_system_user,
                     created_at as "created_at: DateTime<Utc>",
-                    disabled
+                    disabled,
+                    show_in_stats,
+                    lower_limit,
+                    upper_limit,
+                    is_minor
                 from Users
                 where nickname = ?
             "#,
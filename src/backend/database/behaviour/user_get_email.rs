@@ -0,0 +1,24 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query_as, Executor};
+
+use crate::backend::database::{DatabaseResponse, DatabaseType, UserDB, UserEmailDB};
+
+impl UserDB {
+    pub async fn get_email<T>(conn: &mut T, user_id: i64) -> DatabaseResponse<Option<UserEmailDB>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query_as!(
+            UserEmailDB,
+            "
+                select * from UserEmailMap
+                where user_id = ?
+            ",
+            user_id
+        )
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(From::from)
+    }
+}
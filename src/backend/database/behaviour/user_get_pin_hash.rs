@@ -0,0 +1,25 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query, Executor};
+
+use crate::backend::database::{DatabaseResponse, DatabaseType, UserDB};
+
+impl UserDB {
+    pub async fn get_pin_hash<T>(conn: &mut T, user_id: i64) -> DatabaseResponse<Option<String>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query!(
+            "
+                select pin_hash
+                from Users
+                where id = ?
+            ",
+            user_id
+        )
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(From::from)
+        .map(|result| result.and_then(|elem| elem.pin_hash))
+    }
+}
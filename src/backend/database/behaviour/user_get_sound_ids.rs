@@ -0,0 +1,36 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query, Executor};
+
+use crate::{
+    backend::database::{DatabaseResponse, DatabaseType, UserDB},
+    model::DatabaseId,
+};
+
+impl UserDB {
+    /// Returns the ids of the purchase and the deposit sound the user picked
+    pub async fn get_sound_ids<T>(
+        conn: &mut T,
+        user_id: i64,
+    ) -> DatabaseResponse<(Option<DatabaseId>, Option<DatabaseId>)>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query!(
+            "
+                select purchase_sound_id, deposit_sound_id
+                from Users
+                where id = ?
+            ",
+            user_id
+        )
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(From::from)
+        .map(|result| {
+            result
+                .map(|elem| (elem.purchase_sound_id, elem.deposit_sound_id))
+                .unwrap_or_default()
+        })
+    }
+}
@@ -25,7 +25,7 @@ impl UserDB {
             user_id.0,
             card_number
         )
-        .fetch_one(&mut *conn)
+        .execute(&mut *conn)
         .await
         .map_err(From::from)
         .map(|_| ())
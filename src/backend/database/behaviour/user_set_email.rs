@@ -0,0 +1,75 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query, Executor};
+
+use crate::{
+    backend::database::{DatabaseResponse, DatabaseType, UserDB},
+    model::UserId,
+};
+
+impl UserDB {
+    /// Setting a new address resets the opt-out of the reminders.
+    /// The token is only used if the user had no address before.
+    pub async fn set_email<T>(
+        conn: &mut T,
+        user_id: UserId,
+        new_value: Option<String>,
+        opt_out_token: String,
+    ) -> DatabaseResponse<()>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        let user_id = user_id.0;
+        match new_value {
+            Some(new_value) => {
+                let email_exists = Self::get_email(&mut *conn, user_id).await?;
+
+                match email_exists {
+                    None => query!(
+                        "
+                                insert into UserEmailMap
+                                    (user_id, email, opt_out_token)
+                                values
+                                    (?, ?, ?)
+                            ",
+                        user_id,
+                        new_value,
+                        opt_out_token
+                    )
+                    .execute(&mut *conn)
+                    .await
+                    .map_err(From::from)
+                    .map(|_| ()),
+
+                    Some(_) => query!(
+                        "
+                                update UserEmailMap
+                                set
+                                    email = ?,
+                                    reminders_opt_out = false
+                                where user_id = ?
+                            ",
+                        new_value,
+                        user_id
+                    )
+                    .execute(&mut *conn)
+                    .await
+                    .map_err(From::from)
+                    .map(|_| ()),
+                }
+            }
+
+            None => query!(
+                "
+                        delete from UserEmailMap
+                        where user_id = ?
+                    ",
+                user_id
+            )
+            .execute(&mut *conn)
+            .await
+            .map_err(From::from)
+            .map(|_| ()),
+        }
+    }
+}
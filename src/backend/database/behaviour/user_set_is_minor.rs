@@ -0,0 +1,28 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query, Executor};
+
+use crate::{
+    backend::database::{DBError, DatabaseType, UserDB},
+    model::UserId,
+};
+impl UserDB {
+    pub async fn set_is_minor<T>(conn: &mut T, id: UserId, new_value: bool) -> Result<(), DBError>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query!(
+            "
+                update Users
+                set is_minor = ?
+                where id = ?
+            ",
+            new_value,
+            id.0
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(From::from)
+        .map(|_| ())
+    }
+}
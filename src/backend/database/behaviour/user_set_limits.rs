@@ -0,0 +1,34 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query, Executor};
+
+use crate::{
+    backend::database::{DBError, DatabaseType, UserDB},
+    model::UserId,
+};
+impl UserDB {
+    pub async fn set_limits<T>(
+        conn: &mut T,
+        id: UserId,
+        lower_limit: Option<i64>,
+        upper_limit: Option<i64>,
+    ) -> Result<(), DBError>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query!(
+            "
+                update Users
+                set lower_limit = ?, upper_limit = ?
+                where id = ?
+            ",
+            lower_limit,
+            upper_limit,
+            id.0
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(From::from)
+        .map(|_| ())
+    }
+}
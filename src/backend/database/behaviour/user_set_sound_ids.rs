@@ -0,0 +1,34 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query, Executor};
+
+use crate::{
+    backend::database::{DBError, DatabaseType, UserDB},
+    model::{DatabaseId, UserId},
+};
+impl UserDB {
+    pub async fn set_sound_ids<T>(
+        conn: &mut T,
+        id: UserId,
+        purchase_sound_id: Option<DatabaseId>,
+        deposit_sound_id: Option<DatabaseId>,
+    ) -> Result<(), DBError>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query!(
+            "
+                update Users
+                set purchase_sound_id = ?, deposit_sound_id = ?
+                where id = ?
+            ",
+            purchase_sound_id,
+            deposit_sound_id,
+            id.0
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(From::from)
+        .map(|_| ())
+    }
+}
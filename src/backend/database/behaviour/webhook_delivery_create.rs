@@ -0,0 +1,42 @@
+#![cfg(feature = "ssr")]
+
+use chrono::Utc;
+use sqlx::{query, Executor};
+
+use crate::{
+    backend::database::{DatabaseResponse, DatabaseType, WebhookDeliveryDB},
+    model::DatabaseId,
+};
+
+impl WebhookDeliveryDB {
+    /// Queues a new delivery that is due immediately
+    pub async fn create<T>(
+        conn: &mut T,
+        url: &str,
+        event: &str,
+        payload: &str,
+    ) -> DatabaseResponse<DatabaseId>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        let now = Utc::now();
+        query!(
+            "
+                insert into WebhookDeliveries
+                    (url, event, payload, created_at, next_attempt)
+                values
+                    (?, ?, ?, ?, ?)
+                returning id
+            ",
+            url,
+            event,
+            payload,
+            now,
+            now
+        )
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(From::from)
+        .map(|elem| elem.id)
+    }
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use sqlx::{query_as, Executor};
+
+use crate::backend::database::{DatabaseResponse, DatabaseType, WebhookDeliveryDB};
+
+impl WebhookDeliveryDB {
+    /// Returns the oldest pending deliveries whose next attempt is due
+    pub async fn get_due<T>(
+        conn: &mut T,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> DatabaseResponse<Vec<Self>>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query_as!(
+            WebhookDeliveryDB,
+            r#"
+                select
+                    id,
+                    url,
+                    event,
+                    payload,
+                    status,
+                    attempts,
+                    response_status,
+                    last_error,
+                    created_at as "created_at: DateTime<Utc>",
+                    next_attempt as "next_attempt: DateTime<Utc>"
+                from WebhookDeliveries
+                where status = 'pending' and next_attempt <= ?
+                order by next_attempt asc, id asc
+                limit ?
+            "#,
+            now,
+            limit
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(From::from)
+    }
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "ssr")]
+
+use sqlx::{query, Executor};
+
+use crate::backend::database::{DatabaseResponse, DatabaseType, WebhookDeliveryDB};
+
+impl WebhookDeliveryDB {
+    /// Stores the outcome of a delivery attempt
+    pub async fn set_result<T>(&self, conn: &mut T) -> DatabaseResponse<()>
+    where
+        for<'a> &'a mut T: Executor<'a, Database = DatabaseType>,
+    {
+        query!(
+            "
+                update WebhookDeliveries
+                set
+                    status = ?,
+                    attempts = ?,
+                    response_status = ?,
+                    last_error = ?,
+                    next_attempt = ?
+                where id = ?
+            ",
+            self.status,
+            self.attempts,
+            self.response_status,
+            self.last_error,
+            self.next_attempt,
+            self.id
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(From::from)
+        .map(|_| ())
+    }
+}
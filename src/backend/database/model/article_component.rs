@@ -0,0 +1,13 @@
+#![cfg(feature = "ssr")]
+
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+
+use crate::model::DatabaseId;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Type, FromRow)]
+pub struct ArticleComponentDB {
+    pub bundle_id: DatabaseId,
+    pub article_id: DatabaseId,
+    pub quantity: i64,
+}
@@ -0,0 +1,16 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::model::DatabaseId;
+
+/// A number of articles that left the shelf with a (not undone) purchase
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, sqlx::Type, sqlx::FromRow)]
+pub struct ArticleSaleDB {
+    pub transaction_id: DatabaseId,
+    pub article_id: DatabaseId,
+    pub amount: i64,
+    pub user_id: i64,
+    pub timestamp: DateTime<Utc>,
+}
@@ -0,0 +1,13 @@
+#![cfg(feature = "ssr")]
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::DatabaseId;
+
+/// How often an article left the shelf with a (not undone) purchase
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, sqlx::Type, sqlx::FromRow)]
+pub struct ArticleSalesCountDB {
+    pub article_id: DatabaseId,
+    pub name: String,
+    pub sold: i64,
+}
@@ -0,0 +1,12 @@
+#![cfg(feature = "ssr")]
+
+use serde::{Deserialize, Serialize};
+
+/// The summed up balances of all users, without the system users
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, sqlx::Type, sqlx::FromRow)]
+pub struct BalanceTotalsDB {
+    /// the sum of all negative balances, as a positive amount of cents
+    pub debt: i64,
+    /// the sum of all positive balances in cents
+    pub credit: i64,
+}
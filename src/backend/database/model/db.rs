@@ -1,10 +1,18 @@
 use std::str::FromStr;
 
-use sqlx::{pool::PoolConnection, sqlite::SqliteConnectOptions, Sqlite, SqlitePool, Transaction};
+use sqlx::{
+    migrate::Migrator,
+    pool::PoolConnection,
+    sqlite::{SqliteConnectOptions, SqlitePoolOptions},
+    Sqlite, SqlitePool, Transaction,
+};
 use tracing::info;
 
 use crate::backend::database::DBError;
 
+/// The migrations compiled into this version, used to set up and to verify databases
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
 pub struct DB {
     pool: SqlitePool,
 }
@@ -29,6 +37,26 @@ impl DB {
         Ok(db)
     }
 
+    /// A fresh database that only lives as long as the returned handle, used by the tests
+    pub async fn new_in_memory() -> Result<Self, DBError> {
+        let opts = SqliteConnectOptions::from_str("sqlite::memory:").map_err(DBError::new)?;
+
+        // every connection would get its own database, so there must only ever be one
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect_with(opts)
+            .await
+            .map_err(DBError::new)?;
+
+        let db = DB { pool };
+
+        db.setup().await?;
+
+        Ok(db)
+    }
+
     pub async fn close(self) {
         self.pool.close().await;
     }
@@ -47,7 +75,7 @@ impl DB {
     async fn setup(&self) -> Result<(), DBError> {
         let mut transaction = self.get_conn_transaction().await.unwrap();
 
-        sqlx::migrate!("./migrations")
+        MIGRATOR
             .run(&mut *transaction)
             .await
             .map_err(DBError::new)?;
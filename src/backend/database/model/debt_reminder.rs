@@ -0,0 +1,15 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+
+use crate::model::DatabaseId;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Type, FromRow)]
+pub struct DebtReminderDB {
+    pub user_id: DatabaseId,
+    /// when the balance of the user was first seen below the threshold
+    pub below_threshold_since: Option<DateTime<Utc>>,
+    pub last_sent: Option<DateTime<Utc>>,
+}
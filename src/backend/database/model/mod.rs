@@ -1,17 +1,33 @@
 pub mod article;
+pub mod article_component;
 pub mod article_cost_map;
+pub mod article_sale;
+pub mod article_sales_count;
+pub mod balance_totals;
 pub mod barcode;
 pub mod db;
 pub mod db_error;
+pub mod debt_reminder;
 pub mod group;
+pub mod sound;
 pub mod transaction;
 pub mod user;
+pub mod user_email;
+pub mod webhook_delivery;
 
 pub use article::*;
+pub use article_component::*;
 pub use article_cost_map::*;
+pub use article_sale::*;
+pub use article_sales_count::*;
+pub use balance_totals::*;
 pub use barcode::*;
 pub use db::*;
 pub use db_error::*;
+pub use debt_reminder::*;
 pub use group::*;
+pub use sound::*;
 pub use transaction::*;
 pub use user::*;
+pub use user_email::*;
+pub use webhook_delivery::*;
@@ -0,0 +1,13 @@
+#![cfg(feature = "ssr")]
+
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+
+use crate::model::DatabaseId;
+
+/// A stored sound without its data
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Type, FromRow)]
+pub struct SoundDB {
+    pub id: DatabaseId,
+    pub name: String,
+}
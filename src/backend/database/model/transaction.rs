@@ -13,4 +13,6 @@ pub struct TransactionDB {
     pub money: u64,
     pub description: Option<String>,
     pub timestamp: DateTime<Utc>,
+    /// when the transaction was undone, not known for undos before it was recorded
+    pub undone_at: Option<DateTime<Utc>>,
 }
@@ -9,4 +9,8 @@ pub struct UserDB {
     pub is_system_user: bool,
     pub created_at: DateTime<Utc>,
     pub disabled: bool,
+    pub show_in_stats: bool,
+    pub lower_limit: Option<i64>,
+    pub upper_limit: Option<i64>,
+    pub is_minor: bool,
 }
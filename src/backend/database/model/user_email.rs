@@ -0,0 +1,14 @@
+#![cfg(feature = "ssr")]
+
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+
+use crate::model::DatabaseId;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Type, FromRow)]
+pub struct UserEmailDB {
+    pub user_id: DatabaseId,
+    pub email: String,
+    pub reminders_opt_out: bool,
+    pub opt_out_token: String,
+}
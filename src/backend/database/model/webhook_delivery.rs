@@ -0,0 +1,26 @@
+#![cfg(feature = "ssr")]
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+
+use crate::model::DatabaseId;
+
+pub const WEBHOOK_STATUS_PENDING: &str = "pending";
+pub const WEBHOOK_STATUS_DELIVERED: &str = "delivered";
+pub const WEBHOOK_STATUS_FAILED: &str = "failed";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Type, FromRow)]
+pub struct WebhookDeliveryDB {
+    pub id: DatabaseId,
+    pub url: String,
+    pub event: String,
+    pub payload: String,
+    pub status: String,
+    pub attempts: i64,
+    /// the http status of the last attempt, if there was a response
+    pub response_status: Option<i64>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub next_attempt: DateTime<Utc>,
+}
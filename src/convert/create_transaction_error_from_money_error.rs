@@ -0,0 +1,7 @@
+use crate::model::{CreateTransactionError, MoneyError};
+
+impl From<MoneyError> for CreateTransactionError {
+    fn from(value: MoneyError) -> Self {
+        Self::MoneyError(value)
+    }
+}
@@ -1,8 +1,9 @@
+pub mod create_transaction_error_from_money_error;
 pub mod create_transaction_error_from_server_fn_error;
 pub mod group_id_from_i64;
 pub mod money_from_i64;
-pub mod money_from_u64;
-pub mod money_try_from_string;
+pub mod money_try_from_str_currency;
 pub mod split_cost_error_from_create_transaction_error;
 pub mod split_cost_error_from_server_fn_error;
+pub mod transaction_kind_from_transaction_type;
 pub mod user_id_from_i64;
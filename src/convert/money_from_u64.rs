@@ -1,9 +0,0 @@
-use crate::model::Money;
-
-impl From<u64> for Money {
-    fn from(value: u64) -> Self {
-        Self {
-            value: value as i64,
-        }
-    }
-}
@@ -0,0 +1,388 @@
+use crate::model::{Currency, Money, MoneyParseError};
+
+/// Accepts amounts as people type them, e.g. `5`, `-0.50`, `5,5`, `.50`, `1 234.50 €` or `+€12`.
+/// The configured decimal separator is always accepted, `,` and `.` as long as they don't group thousands.
+/// Digits beyond the minor units of the currency are rejected instead of truncated.
+impl TryFrom<(&str, &Currency)> for Money {
+    type Error = MoneyParseError;
+    fn try_from((value, currency): (&str, &Currency)) -> Result<Self, Self::Error> {
+        type Error = MoneyParseError;
+
+        let mut string = value.to_string();
+
+        if !currency.symbol.is_empty() {
+            string = string.replacen(&currency.symbol, "", 1);
+        }
+
+        // also removes thousands separators like ' ' or the narrow no-break space
+        string.retain(|c| !c.is_whitespace());
+
+        let (negative, string) = match string.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, string.strip_prefix('+').unwrap_or(&string)),
+        };
+
+        if string.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        let (major, minor) = split_decimal(string, currency)?;
+
+        let major = strip_thousands_separators(major, currency)?;
+
+        check_digits(&major)?;
+        check_digits(minor)?;
+
+        if major.is_empty() && minor.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        let minor_digits = currency.minor_digits as usize;
+
+        // trailing zeros don't change the amount, so `5.500` is fine for euros
+        let minor = match minor.len() > minor_digits {
+            true if minor[minor_digits..].chars().all(|c| c == '0') => &minor[..minor_digits],
+            true => {
+                return Err(Error::TooManyDecimalPlaces {
+                    max: currency.minor_digits,
+                })
+            }
+            false => minor,
+        };
+
+        let major = match major.is_empty() {
+            true => 0,
+            // only digits are left, so parsing can only fail on overflow
+            false => major.parse::<i64>().map_err(|_| Error::Overflow)?,
+        };
+
+        let minor = match minor.is_empty() {
+            true => 0,
+            false => format!("{minor:0<minor_digits$}")
+                .parse::<i64>()
+                .map_err(|_| Error::Overflow)?,
+        };
+
+        let value = major
+            .checked_mul(currency.minor_units())
+            .and_then(|value| value.checked_add(minor))
+            .ok_or(Error::Overflow)?;
+
+        Ok(Money {
+            value: if negative { -value } else { value },
+        })
+    }
+}
+
+/// Splits the amount at the decimal separator into the major and the minor part
+fn split_decimal<'a>(
+    string: &'a str,
+    currency: &Currency,
+) -> Result<(&'a str, &'a str), MoneyParseError> {
+    let separators = [currency.decimal_separator.as_str(), ",", "."]
+        .into_iter()
+        .filter(|separator| !separator.is_empty() && *separator != currency.thousands_separator)
+        .collect::<Vec<&str>>();
+
+    let mut decimal = None;
+    let mut index = 0;
+
+    while let Some(c) = string[index..].chars().next() {
+        match separators
+            .iter()
+            .find(|separator| string[index..].starts_with(**separator))
+        {
+            Some(separator) => {
+                if decimal.is_some() {
+                    return Err(MoneyParseError::MultipleDecimalSeparators);
+                }
+
+                decimal = Some((index, separator.len()));
+                index += separator.len();
+            }
+            None => index += c.len_utf8(),
+        }
+    }
+
+    Ok(match decimal {
+        Some((start, len)) => (&string[..start], &string[start + len..]),
+        None => (string, ""),
+    })
+}
+
+/// Removes the thousands separators, they have to split the major units into groups of three
+/// digits. Otherwise `0.50` would silently become `50,00` if `.` groups thousands.
+fn strip_thousands_separators(major: &str, currency: &Currency) -> Result<String, MoneyParseError> {
+    let separator = currency.thousands_separator.as_str();
+
+    if separator.is_empty() || !major.contains(separator) {
+        return Ok(major.to_string());
+    }
+
+    let mut groups = major.split(separator);
+
+    let first_is_valid = groups
+        .next()
+        .is_some_and(|group| (1..=3).contains(&group.chars().count()));
+
+    if !first_is_valid || groups.any(|group| group.chars().count() != 3) {
+        return Err(MoneyParseError::MisplacedThousandsSeparator);
+    }
+
+    Ok(major.replace(separator, ""))
+}
+
+fn check_digits(string: &str) -> Result<(), MoneyParseError> {
+    match string.chars().find(|c| !c.is_ascii_digit()) {
+        None => Ok(()),
+        Some('-' | '+') => Err(MoneyParseError::MisplacedSign),
+        Some(c) => Err(MoneyParseError::InvalidCharacter(c)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::model::{Currency, Money, MoneyParseError, SymbolPosition};
+
+    fn euro() -> Currency {
+        Currency::default()
+    }
+
+    fn currency(decimal_separator: &str, thousands_separator: &str, minor_digits: u32) -> Currency {
+        Currency {
+            decimal_separator: decimal_separator.to_string(),
+            thousands_separator: thousands_separator.to_string(),
+            minor_digits,
+            ..Currency::default()
+        }
+    }
+
+    fn parse(value: &str, currency: &Currency) -> Result<i64, MoneyParseError> {
+        Money::try_from((value, currency)).map(|money| money.value)
+    }
+
+    #[test]
+    fn parses_plain_amounts() {
+        assert_eq!(parse("5", &euro()), Ok(500));
+        assert_eq!(parse("5.5", &euro()), Ok(550));
+        assert_eq!(parse("5,5", &euro()), Ok(550));
+        assert_eq!(parse("5.05", &euro()), Ok(505));
+        assert_eq!(parse(".50", &euro()), Ok(50));
+        assert_eq!(parse(",5", &euro()), Ok(50));
+        assert_eq!(parse("5.", &euro()), Ok(500));
+        assert_eq!(parse("0", &euro()), Ok(0));
+        assert_eq!(parse("007.00", &euro()), Ok(700));
+    }
+
+    #[test]
+    fn parses_signs() {
+        assert_eq!(parse("-0.50", &euro()), Ok(-50));
+        assert_eq!(parse("-.5", &euro()), Ok(-50));
+        assert_eq!(parse("-5", &euro()), Ok(-500));
+        assert_eq!(parse("+5", &euro()), Ok(500));
+        assert_eq!(parse("-0", &euro()), Ok(0));
+        assert_eq!(parse("- 5", &euro()), Ok(-500));
+    }
+
+    #[test]
+    fn ignores_whitespace_and_symbol() {
+        assert_eq!(parse("  12.50  ", &euro()), Ok(1250));
+        assert_eq!(parse("12.50€", &euro()), Ok(1250));
+        assert_eq!(parse("12.50 €", &euro()), Ok(1250));
+        assert_eq!(parse("€12.50", &euro()), Ok(1250));
+        assert_eq!(parse("-€12.50", &euro()), Ok(-1250));
+        assert_eq!(parse("€-12.50", &euro()), Ok(-1250));
+        assert_eq!(parse("-12.50\u{a0}€", &euro()), Ok(-1250));
+
+        let krona = Currency {
+            symbol: "kr".to_string(),
+            symbol_spacing: true,
+            ..euro()
+        };
+        assert_eq!(parse("12,50 kr", &krona), Ok(1250));
+    }
+
+    #[test]
+    fn parses_thousands_separators() {
+        let german = currency(",", ".", 2);
+        assert_eq!(parse("1.234,50", &german), Ok(123450));
+        assert_eq!(parse("1.234.567", &german), Ok(123456700));
+        assert_eq!(parse("1234,5", &german), Ok(123450));
+        assert_eq!(parse("12.345", &german), Ok(1234500));
+        assert_eq!(parse("-1.234,50 €", &german), Ok(-123450));
+
+        let english = currency(".", ",", 2);
+        assert_eq!(parse("1,234.50", &english), Ok(123450));
+
+        let swiss = currency(".", "'", 2);
+        assert_eq!(parse("1'234.50", &swiss), Ok(123450));
+        assert_eq!(parse("1'234,50", &swiss), Ok(123450));
+
+        let french = currency(",", " ", 2);
+        assert_eq!(parse("1 234,50", &french), Ok(123450));
+        assert_eq!(parse("1\u{202f}234,50", &french), Ok(123450));
+    }
+
+    #[test]
+    fn rejects_misplaced_thousands_separators() {
+        let german = currency(",", ".", 2);
+
+        for input in [
+            "0.50", ".50", "5.5", "-0.50", "1.23", "12.3456", "1.234.56", "1234.567", "1..234",
+        ] {
+            assert_eq!(
+                parse(input, &german),
+                Err(MoneyParseError::MisplacedThousandsSeparator),
+                "{input}"
+            );
+        }
+
+        assert_eq!(parse("0,50", &german), Ok(50));
+        assert_eq!(parse(",50", &german), Ok(50));
+        assert_eq!(parse("5,5", &german), Ok(550));
+    }
+
+    #[test]
+    fn respects_minor_digits() {
+        let yen = currency(".", ",", 0);
+        assert_eq!(parse("500", &yen), Ok(500));
+        assert_eq!(parse("1,500", &yen), Ok(1500));
+        assert_eq!(parse("500.0", &yen), Ok(500));
+        assert_eq!(
+            parse("500.5", &yen),
+            Err(MoneyParseError::TooManyDecimalPlaces { max: 0 })
+        );
+
+        let dinar = currency(".", "", 3);
+        assert_eq!(parse("1.5", &dinar), Ok(1500));
+        assert_eq!(parse("1.005", &dinar), Ok(1005));
+    }
+
+    #[test]
+    fn rejects_excess_precision() {
+        assert_eq!(
+            parse("5.505", &euro()),
+            Err(MoneyParseError::TooManyDecimalPlaces { max: 2 })
+        );
+        assert_eq!(
+            parse("0.001", &euro()),
+            Err(MoneyParseError::TooManyDecimalPlaces { max: 2 })
+        );
+        assert_eq!(parse("5.500", &euro()), Ok(550));
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        assert_eq!(parse("", &euro()), Err(MoneyParseError::Empty));
+        assert_eq!(parse("   ", &euro()), Err(MoneyParseError::Empty));
+        assert_eq!(parse("-", &euro()), Err(MoneyParseError::Empty));
+        assert_eq!(parse("€", &euro()), Err(MoneyParseError::Empty));
+        assert_eq!(parse(".", &euro()), Err(MoneyParseError::Empty));
+        assert_eq!(
+            parse("5.5.5", &euro()),
+            Err(MoneyParseError::MultipleDecimalSeparators)
+        );
+        assert_eq!(
+            parse("1.234,50", &euro()),
+            Err(MoneyParseError::MultipleDecimalSeparators)
+        );
+        assert_eq!(parse("5-", &euro()), Err(MoneyParseError::MisplacedSign));
+        assert_eq!(parse("--5", &euro()), Err(MoneyParseError::MisplacedSign));
+        assert_eq!(parse("+-5", &euro()), Err(MoneyParseError::MisplacedSign));
+        assert_eq!(
+            parse("abc", &euro()),
+            Err(MoneyParseError::InvalidCharacter('a'))
+        );
+        assert_eq!(
+            parse("5.5x", &euro()),
+            Err(MoneyParseError::InvalidCharacter('x'))
+        );
+        assert_eq!(
+            parse("5$", &euro()),
+            Err(MoneyParseError::InvalidCharacter('$'))
+        );
+        assert_eq!(
+            parse("1.234,50", &currency(",", "", 2)),
+            Err(MoneyParseError::MultipleDecimalSeparators)
+        );
+    }
+
+    #[test]
+    fn detects_overflow() {
+        assert_eq!(parse("92233720368547758.07", &euro()), Ok(i64::MAX));
+        assert_eq!(parse("-92233720368547758.07", &euro()), Ok(-i64::MAX));
+        assert_eq!(
+            parse("92233720368547758.08", &euro()),
+            Err(MoneyParseError::Overflow)
+        );
+        assert_eq!(
+            parse("99999999999999999999", &euro()),
+            Err(MoneyParseError::Overflow)
+        );
+    }
+
+    fn any_currency() -> impl Strategy<Value = Currency> {
+        (
+            prop::sample::select(vec!["€", "$", "kr", "CHF", "Fr."]),
+            prop::bool::ANY,
+            prop::bool::ANY,
+            prop::sample::select(vec![".", ","]),
+            prop::sample::select(vec!["", " ", ".", ",", "'"]),
+            0..=4_u32,
+        )
+            .prop_filter(
+                "the separators have to differ",
+                |(_, _, _, decimal, thousands, _)| decimal != thousands,
+            )
+            .prop_map(
+                |(symbol, before, symbol_spacing, decimal, thousands, minor_digits)| Currency {
+                    symbol: symbol.to_string(),
+                    symbol_position: match before {
+                        true => SymbolPosition::Before,
+                        false => SymbolPosition::After,
+                    },
+                    symbol_spacing,
+                    decimal_separator: decimal.to_string(),
+                    thousands_separator: thousands.to_string(),
+                    minor_digits,
+                },
+            )
+    }
+
+    proptest! {
+        #[test]
+        fn formatted_amounts_parse_back(value in -1_000_000_000_000_i64..1_000_000_000_000, currency in any_currency()) {
+            prop_assert_eq!(parse(&currency.format(value), &currency), Ok(value));
+            prop_assert_eq!(parse(&currency.format_amount(value), &currency), Ok(value));
+        }
+
+        #[test]
+        fn negation_is_symmetric(value in 0_i64..1_000_000_000_000, currency in any_currency()) {
+            let amount = currency.format_amount(value);
+
+            prop_assert_eq!(parse(&format!("-{amount}"), &currency), Ok(-value));
+            prop_assert_eq!(parse(&format!("+{amount}"), &currency), Ok(value));
+        }
+
+        #[test]
+        fn never_panics(input in "\\PC*", currency in any_currency()) {
+            _ = parse(&input, &currency);
+        }
+
+        #[test]
+        fn never_truncates(major in 0_i64..1_000_000, minor in 1_u32..10, currency in any_currency()) {
+            let input = format!(
+                "{major}{}{}{minor}",
+                currency.decimal_separator,
+                "0".repeat(currency.minor_digits as usize)
+            );
+
+            prop_assert_eq!(
+                parse(&input, &currency),
+                Err(MoneyParseError::TooManyDecimalPlaces { max: currency.minor_digits })
+            );
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use crate::model::{TransactionKind, TransactionType};
+
+impl From<TransactionType> for TransactionKind {
+    fn from(value: TransactionType) -> Self {
+        match value {
+            TransactionType::Deposit => TransactionKind::Deposit,
+            TransactionType::Withdraw => TransactionKind::Withdraw,
+            TransactionType::Bought(_) => TransactionKind::Purchase,
+            TransactionType::Received(_)
+            | TransactionType::Sent(_)
+            | TransactionType::SentAndReceived(_) => TransactionKind::Transfer,
+        }
+    }
+}
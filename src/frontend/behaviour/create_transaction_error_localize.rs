@@ -0,0 +1,42 @@
+use crate::{
+    frontend::model::texts::Texts,
+    model::{ConsumptionRuleViolation, CreateTransactionError, LimitViolation},
+};
+
+fn localize_violations(violations: &[LimitViolation], texts: &Texts) -> String {
+    violations
+        .iter()
+        .map(|violation| violation.localize(texts))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+impl CreateTransactionError {
+    /// The error message in the language of the given texts
+    pub fn localize(&self, texts: &Texts) -> String {
+        match self {
+            CreateTransactionError::TooLittleMoneyError(users) => {
+                (texts.too_little_money)(&localize_violations(users, texts))
+            }
+            CreateTransactionError::TooMuchMoneyError(users) => {
+                (texts.too_much_money)(&localize_violations(users, texts))
+            }
+            CreateTransactionError::ConsumptionRuleViolated(violation) => match violation {
+                ConsumptionRuleViolation::DailyLimitReached { rule, max_per_day } => {
+                    (texts.daily_limit_reached)(rule, &max_per_day.to_string())
+                }
+                ConsumptionRuleViolation::AdultsOnly { rule } => (texts.adults_only)(rule),
+            },
+            CreateTransactionError::MoneyError(e) => e.localize(texts),
+            CreateTransactionError::PinRequired(_) => texts.pin_required.to_string(),
+            CreateTransactionError::StringMessage(msg) => (texts.failed_to_create_transaction)(msg),
+            CreateTransactionError::ServerFn(e) => (texts.server_error)(&e.to_string()),
+            CreateTransactionError::ArticleDoesNotExist(id) => {
+                (texts.article_does_not_exist)(&id.to_string())
+            }
+            CreateTransactionError::UserDoesNotExist(id) => {
+                (texts.user_does_not_exist)(&id.to_string())
+            }
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{
+    frontend::{model::texts::Texts, shared::current_currency},
+    model::LimitViolation,
+};
+
+impl LimitViolation {
+    /// The limit that was hit, e.g. `personal limit -5.00€`
+    pub fn localize_limit(&self, texts: &Texts) -> String {
+        let limit = self.limit.format(&current_currency());
+
+        match self.personal {
+            true => (texts.personal_limit)(&limit),
+            false => (texts.global_limit)(&limit),
+        }
+    }
+
+    /// The user together with the limit that was hit
+    pub fn localize(&self, texts: &Texts) -> String {
+        format!("{} ({})", self.nickname, self.localize_limit(texts))
+    }
+}
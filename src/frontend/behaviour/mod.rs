@@ -1,2 +1,7 @@
+pub mod create_transaction_error_localize;
+pub mod limit_violation_localize;
+pub mod money_error_localize;
+pub mod money_parse_error_localize;
 pub mod scaninput_manager_register;
 pub mod scaninput_manager_setup;
+pub mod split_cost_error_localize;
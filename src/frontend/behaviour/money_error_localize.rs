@@ -0,0 +1,13 @@
+use crate::{frontend::model::texts::Texts, model::MoneyError};
+
+impl MoneyError {
+    /// The error message in the language of the given texts
+    pub fn localize(&self, texts: &Texts) -> String {
+        match self {
+            MoneyError::Overflow => texts.amount_too_large,
+            MoneyError::Negative => texts.amount_is_negative,
+            MoneyError::NoShares => texts.no_shares,
+        }
+        .to_string()
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{frontend::model::texts::Texts, model::MoneyParseError};
+
+impl MoneyParseError {
+    /// The error message in the language of the given texts
+    pub fn localize(&self, texts: &Texts) -> String {
+        match self {
+            MoneyParseError::Empty => texts.amount_is_empty.to_string(),
+            MoneyParseError::InvalidCharacter(c) => (texts.invalid_character)(&c.to_string()),
+            MoneyParseError::MisplacedSign => texts.misplaced_sign.to_string(),
+            MoneyParseError::MultipleDecimalSeparators => {
+                texts.multiple_decimal_separators.to_string()
+            }
+            MoneyParseError::MisplacedThousandsSeparator => {
+                texts.misplaced_thousands_separator.to_string()
+            }
+            MoneyParseError::TooManyDecimalPlaces { max } => {
+                (texts.too_many_decimal_places)(&max.to_string())
+            }
+            MoneyParseError::Overflow => texts.amount_too_large.to_string(),
+        }
+    }
+}
@@ -2,12 +2,14 @@ use chrono::Utc;
 use leptos::{
     ev,
     prelude::{
-        window_event_listener, Get, ReadSignal, ReadUntracked, RwSignal, UpdateUntracked, Write,
+        window_event_listener, Get, GetUntracked, ReadSignal, ReadUntracked, RwSignal,
+        UpdateUntracked, Write,
     },
 };
 
-use crate::frontend::model::scaninput_manager::{
-    Entry, ScanInputManager, SECONDS_UNTIL_INPUT_CLEARED,
+use crate::frontend::{
+    model::scaninput_manager::{Entry, ScanInputManager},
+    shared::use_kiosk_settings,
 };
 
 impl ScanInputManager {
@@ -23,9 +25,11 @@ impl ScanInputManager {
 
         let input_signal = RwSignal::new(String::new());
         let last_input = RwSignal::new(Utc::now());
+        let kiosk_settings = use_kiosk_settings();
 
         let should_clear_input = move || {
-            (Utc::now() - last_input.get()).num_seconds() > SECONDS_UNTIL_INPUT_CLEARED as i64
+            (Utc::now() - last_input.get()).num_seconds()
+                > kiosk_settings.get_untracked().input_clear_timeout_sec as i64
         };
 
         let clear_input = move || {
@@ -64,7 +68,7 @@ impl ScanInputManager {
                         return;
                     }
 
-                    // Clear input if nothing was typed for 30 seconds
+                    // Clear input if nothing was typed for a while
                     if should_clear_input() {
                         clear_input()
                     }
@@ -0,0 +1,26 @@
+use crate::{frontend::model::texts::Texts, model::SplitCostError};
+
+impl SplitCostError {
+    /// The error message in the language of the given texts
+    pub fn localize(&self, texts: &Texts) -> String {
+        match self {
+            SplitCostError::ServerFn(e) => (texts.server_error)(&e.to_string()),
+            SplitCostError::DatabaseError(msg) => (texts.database_error)(msg),
+            SplitCostError::MoneyParseError(e) => (texts.failed_to_parse_money)(&e.localize(texts)),
+            SplitCostError::MoneyError(_) => texts.money_must_be_positive.to_string(),
+            SplitCostError::UserDoesNotExistError(nickname) => {
+                (texts.nickname_does_not_exist)(nickname)
+            }
+            SplitCostError::CreateTransactionError(e) => e.localize(texts),
+            SplitCostError::MayNotBeEmptyError(field) => {
+                // the server names the form field in english
+                let field = match field.as_str() {
+                    "User" => texts.user,
+                    "Other users" => texts.other_users,
+                    _ => field,
+                };
+                (texts.may_not_be_empty)(field)
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use codee::string::JsonSerdeCodec;
+use leptos::prelude::*;
+use leptos_use::{
+    core::ConnectionReadyState, use_event_source_with_options, ReconnectLimit,
+    UseEventSourceOptions, UseEventSourceReturn,
+};
+
+use crate::{
+    frontend::model::caching_layer::{CachingLayer, CachingLayerType},
+    model::ServerEvent,
+};
+
+impl CachingLayer {
+    /// Listens to the event stream of the server and invalidates the affected entries,
+    /// so changes made on other devices show up without reloading.
+    pub fn subscribe_to_events(layer: CachingLayerType) {
+        let UseEventSourceReturn {
+            data, ready_state, ..
+        } = use_event_source_with_options::<ServerEvent, JsonSerdeCodec>(
+            "/events",
+            UseEventSourceOptions::default().reconnect_limit(ReconnectLimit::Infinite),
+        );
+
+        Effect::new(move || {
+            if let Some(event) = data.get() {
+                CachingLayer::handle_event(layer, event);
+            }
+        });
+
+        let was_connected = StoredValue::new(false);
+        Effect::new(move || {
+            if ready_state.get() != ConnectionReadyState::Open {
+                return;
+            }
+
+            // events may have been missed while the connection was down
+            if was_connected.get_value() {
+                CachingLayer::invalidate_all(layer);
+            }
+
+            was_connected.set_value(true);
+        });
+    }
+
+    fn handle_event(layer: CachingLayerType, event: ServerEvent) {
+        let layer = layer.read_untracked();
+
+        let users = event.affected_users();
+        if !users.is_empty() {
+            *layer.users_revision.write() += 1;
+            layer.user_revisions.update(|revisions| {
+                for user_id in users.into_iter() {
+                    *revisions.entry(user_id).or_default() += 1;
+                }
+            });
+        }
+
+        if let ServerEvent::ArticleUpdated { .. } = event {
+            *layer.articles_revision.write() += 1;
+        }
+    }
+
+    fn invalidate_all(layer: CachingLayerType) {
+        let layer = layer.read_untracked();
+
+        let users = layer.cached_users.read_untracked().value.get_untracked();
+
+        *layer.users_revision.write() += 1;
+        layer.user_revisions.update(|revisions| {
+            for user in users.iter() {
+                *revisions.entry(user.id).or_default() += 1;
+            }
+        });
+        *layer.articles_revision.write() += 1;
+    }
+}
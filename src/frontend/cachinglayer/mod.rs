@@ -1 +1,2 @@
+pub mod live_updates;
 pub mod routes;
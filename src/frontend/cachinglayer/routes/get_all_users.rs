@@ -1,19 +1,25 @@
 use leptos::{
-    prelude::{Effect, ReadSignal, ReadUntracked, Set, Write},
+    prelude::{Effect, ReadSignal, ReadUntracked, Set, Track, Write},
     reactive::spawn_local,
 };
 
 use crate::{
     backend::core::{behaviour::user_get_all::get_all_users, User},
-    frontend::model::{
-        caching_entry::CachingEntry,
-        caching_layer::{CachingLayer, CachingLayerType},
+    frontend::{
+        model::{
+            caching_entry::CachingEntry,
+            caching_layer::{CachingLayer, CachingLayerType},
+        },
+        shared::use_texts,
     },
 };
 
 impl CachingLayer {
     fn fetch_all_users(layer: CachingLayerType) {
         Effect::new(move || {
+            // refetch whenever a user changes on the server
+            layer.read_untracked().users_revision.track();
+
             spawn_local(async move {
                 let write_cached = layer.write_only();
 
@@ -29,7 +35,9 @@ impl CachingLayer {
                         .cached_users
                         .write()
                         .error
-                        .set(Some(format!("Failed to fetch users: {e}"))),
+                        .set(Some((use_texts().failed_to_fetch_users_because)(
+                            &e.to_string(),
+                        ))),
                 };
             })
         });
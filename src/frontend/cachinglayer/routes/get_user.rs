@@ -1,21 +1,36 @@
 use leptos::{
-    prelude::{Effect, GetUntracked, ReadSignal, ReadUntracked, RwSignal, Set, Write},
+    prelude::{
+        Effect, Get, GetUntracked, Memo, ReadSignal, ReadUntracked, RwSignal, Set, With, Write,
+    },
     reactive::spawn_local,
 };
 
 use crate::{
     backend::core::{behaviour::user_get::get_user, User},
-    frontend::model::{
-        caching_entry::CachingEntry,
-        caching_layer::{CachingLayer, CachingLayerType},
+    frontend::{
+        model::{
+            caching_entry::CachingEntry,
+            caching_layer::{CachingLayer, CachingLayerType},
+        },
+        shared::use_texts,
     },
     model::UserId,
 };
 
 impl CachingLayer {
-    fn fetch_user(user_id: UserId, entry: RwSignal<CachingEntry<Option<User>>>) {
+    fn fetch_user(
+        layer: CachingLayerType,
+        user_id: UserId,
+        entry: RwSignal<CachingEntry<Option<User>>>,
+    ) {
+        let user_revisions = layer.read_untracked().user_revisions;
+        let revision = Memo::new(move |_| user_revisions.with(|map| map.get(&user_id).copied()));
+
         entry.write().is_fetching.set(true);
         Effect::new(move || {
+            // refetch whenever this user changes on the server
+            revision.get();
+
             spawn_local(async move {
                 match get_user(user_id).await {
                     Ok(value) => {
@@ -27,7 +42,7 @@ impl CachingLayer {
                         entry
                             .write()
                             .error
-                            .set(Some(format!("Failed to load user: {e}")));
+                            .set(Some((use_texts().failed_to_load_user)(&e.to_string())));
                     }
                 }
             })
@@ -51,7 +66,7 @@ impl CachingLayer {
 
         let cache_entry = RwSignal::new(cache_entry);
 
-        CachingLayer::fetch_user(user_id, cache_entry);
+        CachingLayer::fetch_user(layer, user_id, cache_entry);
 
         cache_entry.read_only()
     }
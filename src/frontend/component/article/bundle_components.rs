@@ -0,0 +1,156 @@
+use leptos::{html, prelude::*};
+
+use crate::{
+    backend::core::{behaviour::article_get_all::get_all_articles, Article, ArticleComponent},
+    frontend::{
+        component::icon::DeleteIcon,
+        shared::{throw_error_none_view, use_texts},
+    },
+};
+
+/// Lets the user compose a bundle out of existing (non-bundle) articles
+#[component]
+pub fn BundleComponents(
+    article_id: i64,
+    components: RwSignal<Vec<ArticleComponent>>,
+) -> impl IntoView {
+    let texts = use_texts();
+    let articles_resource = OnceResource::new(get_all_articles(None));
+    let articles_signal = RwSignal::new(Vec::<Article>::new());
+
+    let new_component_node = NodeRef::<html::Select>::new();
+    let new_quantity_node = NodeRef::<html::Input>::new();
+
+    let article_name = move |id: i64| {
+        articles_signal
+            .get()
+            .into_iter()
+            .find(|elem| elem.id == id)
+            .map(|elem| elem.name)
+            .unwrap_or_else(|| (texts.unknown_article)(&id.to_string()))
+    };
+
+    let on_add = move |_| {
+        let component_node = new_component_node
+            .get()
+            .expect("new_component_select should be mounted!");
+        let quantity_node = new_quantity_node
+            .get()
+            .expect("new_quantity_input should be mounted!");
+
+        let Ok(component_id) = component_node.value().parse::<i64>() else {
+            return;
+        };
+
+        let quantity = quantity_node.value().parse::<i64>().unwrap_or(1);
+        if quantity <= 0 {
+            return;
+        }
+
+        components.update(|vec| {
+            match vec.iter_mut().find(|elem| elem.article_id == component_id) {
+                Some(existing) => existing.quantity += quantity,
+                None => vec.push(ArticleComponent {
+                    article_id: component_id,
+                    quantity,
+                }),
+            }
+        });
+
+        quantity_node.set_value("1");
+    };
+
+    view! {
+        {move || {
+            articles_resource
+                .get()
+                .map(|value| {
+                    match value {
+                        Ok(value) => {
+                            articles_signal.set(value);
+                            ().into_any()
+                        }
+                        Err(e) => {
+                            let msg = match e {
+                                ServerFnError::ServerError(msg) => msg,
+                                _ => e.to_string(),
+                            };
+                            throw_error_none_view((texts.failed_to_fetch_articles)(&msg))
+                        }
+                    }
+                })
+        }}
+        <div>
+            <table class="w-full text-white border-collapse border-spacing-5">
+                <tr class="bg-black">
+                    <th class="pl-2">{texts.bundle_components}</th>
+                    <th class="px-2">{texts.quantity}</th>
+                    <th></th>
+                </tr>
+                {move || {
+                    components
+                        .get()
+                        .into_iter()
+                        .map(|component| {
+                            let component_id = component.article_id;
+                            view! {
+                                <tr class="even:bg-gray-700 odd:bg-gray-500 text-center">
+                                    <td class="px-2">
+                                        <p>{move || article_name(component_id)}</p>
+                                    </td>
+                                    <td class="px-2">
+                                        <p>{component.quantity}</p>
+                                    </td>
+                                    <td class="px-2">
+                                        <button
+                                            class="size-8 pt-2"
+                                            on:click=move |_| {
+                                                components
+                                                    .update(|vec| {
+                                                        vec.retain(|elem| elem.article_id != component_id)
+                                                    });
+                                            }
+                                        >
+                                            <DeleteIcon />
+                                        </button>
+                                    </td>
+                                </tr>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </table>
+        </div>
+        <div class="flex justify-center pt-5 gap-5">
+            <select class="text-black rounded-[5px] text-center" node_ref=new_component_node>
+                {move || {
+                    articles_signal
+                        .get()
+                        .into_iter()
+                        .filter(|elem| elem.id != article_id && !elem.is_bundle())
+                        .map(|elem| {
+                            view! { <option value=elem.id.to_string()>{elem.name}</option> }
+                        })
+                        .collect_view()
+                }}
+            </select>
+            <input
+                class="text-black rounded-[5px] text-center w-[5em]"
+                type="number"
+                min="1"
+                value="1"
+                node_ref=new_quantity_node
+            />
+            <div class="w-[10vw]">
+                <button
+                    type="button"
+                    class="w-full bg-indigo-700 hover:bg-pink-700 text-white font-bold py-2 px-4 mb-6 rounded"
+                    on:click=on_add
+                >
+                    {texts.add_component}
+                </button>
+            </div>
+        </div>
+    }
+    .into_any()
+}
@@ -6,19 +6,20 @@ use crate::{
     frontend::{
         component::article::search_article::ArticleSearch,
         model::money_args::MoneyArgs,
-        shared::{buy_article, throw_error_none_view},
+        shared::{buy_article, current_currency, throw_error_none_view, use_texts},
     },
 };
 
 #[component]
 pub fn BuyArticle(args: RwSignal<MoneyArgs>) -> impl IntoView {
+    let texts = use_texts();
     let toaster = ToasterInjection::expect_context();
 
     let personal_articles = OnceResource::new(get_articles_per_user(args.get_untracked().user_id));
     view! {
         <div>
             <Suspense fallback=move || {
-                view! { <Spinner label="Loading Articles!" /> }
+                view! { <Spinner label=texts.loading_articles /> }
             }>
                 <div class="grid grid-cols-3 text-white text-center gap-2 text-[1.25em] p-2 pt-4">
                     {move || {
@@ -33,7 +34,7 @@ pub fn BuyArticle(args: RwSignal<MoneyArgs>) -> impl IntoView {
                                             _ => e.to_string(),
                                         };
                                         return throw_error_none_view(
-                                            format!("Failed to fetch articles: {}", msg),
+                                            (texts.failed_to_fetch_articles)(&msg),
                                         );
                                     }
                                 };
@@ -49,7 +50,7 @@ pub fn BuyArticle(args: RwSignal<MoneyArgs>) -> impl IntoView {
                                                     buy_article(id, cost, args, toaster);
                                                 }
                                             >
-                                                <div>{name}" | "{cost.format_eur()}</div>
+                                                <div>{name}" | "{cost.format(&current_currency())}</div>
                                             </Button>
                                         }
                                     })
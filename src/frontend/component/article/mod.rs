@@ -1,7 +1,9 @@
+pub mod bundle_components;
 pub mod buy_article;
 pub mod search_article;
 pub mod show_articles;
 pub mod single_article_view;
 
+pub use bundle_components::*;
 pub use show_articles::*;
 pub use single_article_view::*;
@@ -4,8 +4,11 @@ use thaw::ToasterInjection;
 use crate::{
     backend::core::{behaviour::article_get_all::get_all_articles, Article},
     frontend::{
-        model::money_args::MoneyArgs,
-        shared::{buy_article, throw_error_none_view},
+        model::{
+            frontend_store::{FrontendStoreStoreFields, FrontendStoreType},
+            money_args::MoneyArgs,
+        },
+        shared::{buy_article, current_currency, throw_error_none_view, use_texts},
     },
 };
 
@@ -13,7 +16,17 @@ const ARTICLE_SEARCH_DISPLAY_LIMIT: usize = 5;
 
 #[component]
 pub fn ArticleSearch(money_args: RwSignal<MoneyArgs>) -> impl IntoView {
-    let articles_resource = OnceResource::new(get_all_articles(None));
+    let texts = use_texts();
+    let store = expect_context::<FrontendStoreType>();
+    let articles_revision = store
+        .cachinglayer()
+        .get_untracked()
+        .read_untracked()
+        .articles_revision;
+
+    // refetched whenever an article changes on the server
+    let articles_resource =
+        Resource::new(move || articles_revision.get(), |_| get_all_articles(None));
     let toaster = ToasterInjection::expect_context();
 
     let dropdown_div = NodeRef::<html::Div>::new();
@@ -56,7 +69,7 @@ pub fn ArticleSearch(money_args: RwSignal<MoneyArgs>) -> impl IntoView {
                                 ServerFnError::ServerError(msg) => msg,
                                 _ => e.to_string(),
                             };
-                            throw_error_none_view(format!("Failed to fetch articles: {}", msg))
+                            throw_error_none_view((texts.failed_to_fetch_articles)(&msg))
                         }
                     }
                 })
@@ -65,7 +78,7 @@ pub fn ArticleSearch(money_args: RwSignal<MoneyArgs>) -> impl IntoView {
             <div class="relative">
                 <input
                     class="text-white w-full bg-transparent placeholder:text-slate-400 text-slate-700 text-sm border border-slate-200 rounded-md pl-3 pr-28 py-2 transition duration-300 ease focus:outline-none focus:border-slate-400 hover:border-slate-300 shadow-sm focus:shadow"
-                    placeholder="Search for articles"
+                    placeholder=texts.search_for_articles
                     autocomplete=false
                     bind:value=search_term
                     on:input=on_input
@@ -87,7 +100,7 @@ pub fn ArticleSearch(money_args: RwSignal<MoneyArgs>) -> impl IntoView {
                         />
                     </svg>
 
-                    Search
+                    {texts.search}
                 </button>
             </div>
             <div node_ref=dropdown_div class=("hidden", move || search_term.get().is_empty())>
@@ -102,7 +115,7 @@ pub fn ArticleSearch(money_args: RwSignal<MoneyArgs>) -> impl IntoView {
                                     search_term.set(String::new());
                                 }>
                                     <div class="p-2 m-2 rounded text-white bg-gray-700">
-                                        <p>{elem.name}" | "{elem.cost.format_eur()}</p>
+                                        <p>{elem.name}" | "{elem.cost.format(&current_currency())}</p>
                                     </div>
                                 </button>
                             }
@@ -3,15 +3,27 @@ use thaw::{Spinner, Table, TableBody, TableCell, TableHeader, TableRow};
 
 use crate::{
     backend::core::behaviour::article_get_all::get_all_articles,
-    frontend::shared::throw_error_none_view,
+    frontend::{
+        model::frontend_store::{FrontendStoreStoreFields, FrontendStoreType},
+        shared::{current_currency, throw_error_none_view, use_texts},
+    },
 };
 
 #[component]
 pub fn ShowArticles() -> impl IntoView {
-    let all_articles = OnceResource::new(get_all_articles(None));
+    let texts = use_texts();
+    let store = expect_context::<FrontendStoreType>();
+    let articles_revision = store
+        .cachinglayer()
+        .get_untracked()
+        .read_untracked()
+        .articles_revision;
+
+    // refetched whenever an article changes on the server
+    let all_articles = Resource::new(move || articles_revision.get(), |_| get_all_articles(None));
     view! {
         <Suspense fallback=move || {
-            view! { <Spinner label="Loading Articles" /> }
+            view! { <Spinner label=texts.loading_articles /> }
         }>
             {move || {
                 all_articles
@@ -23,7 +35,7 @@ pub fn ShowArticles() -> impl IntoView {
                                     ServerFnError::ServerError(msg) => msg,
                                     _ => err.to_string(),
                                 };
-                                throw_error_none_view(format!("Failed to fetch article: {msg}"))
+                                throw_error_none_view((texts.failed_to_fetch_articles)(&msg))
                             }
                             Ok(mut articles) => {
                                 view! {
@@ -31,8 +43,8 @@ pub fn ShowArticles() -> impl IntoView {
                                     <Table>
                                         <TableHeader>
                                             <tr class="bg-black">
-                                                <th>"Name"</th>
-                                                <th>"Preis"</th>
+                                                <th>{texts.name}</th>
+                                                <th>{texts.price}</th>
                                                 <th></th>
                                             </tr>
                                         </TableHeader>
@@ -50,11 +62,11 @@ pub fn ShowArticles() -> impl IntoView {
                                                             <TableRow class="even:bg-gray-700 odd:bg-gray-500">
                                                                 <TableCell class="text-center">{article.name}</TableCell>
                                                                 <TableCell class="text-center">
-                                                                    {article.cost.format_eur()}
+                                                                    {article.cost.format(&current_currency())}
                                                                 </TableCell>
                                                                 <TableCell class="bg-green-700 p-2">
                                                                     <a href=format!("/articles/{}", article.id)>
-                                                                        <p class="text-center">"Edit"</p>
+                                                                        <p class="text-center">{texts.edit}</p>
                                                                     </a>
                                                                 </TableCell>
                                                             </TableRow>
@@ -7,19 +7,25 @@ use thaw::{Flex, FlexAlign, Input, Label};
 
 use crate::{
     backend::core::{behaviour::update_article::update_article, Article, Barcode, BarcodeDiff},
-    frontend::shared::throw_error_none_view,
+    frontend::{
+        component::article::BundleComponents,
+        shared::{current_currency, throw_error_none_view, use_texts},
+    },
 };
 
 #[component]
 pub fn SingleArticleView(article: Article) -> impl IntoView {
+    let texts = use_texts();
     let name = RwSignal::new(article.name.clone());
-    let cost = RwSignal::new(article.cost.format());
+    let cost = RwSignal::new(article.cost.format_amount(&current_currency()));
 
     let new_barcode_node = NodeRef::<html::Input>::new();
 
     let barcodes_signal = RwSignal::new(article.barcodes.clone());
     let barcodes_diff_signal = RwSignal::new(Vec::<BarcodeDiff>::new());
 
+    let components_signal = RwSignal::new(article.components.clone());
+
     let error_signal = RwSignal::new(String::new());
 
     let class_css = "flex flex-col gap-5";
@@ -40,6 +46,7 @@ pub fn SingleArticleView(article: Article) -> impl IntoView {
                 name.get_untracked(),
                 cost.get_untracked(),
                 Some(barcodes),
+                Some(components_signal.get_untracked()),
             )
             .await
             {
@@ -48,7 +55,7 @@ pub fn SingleArticleView(article: Article) -> impl IntoView {
                     _ => e.to_string(),
                 };
 
-                error_signal.set(msg);
+                error_signal.set((texts.failed_to_update_article)(&msg));
             }
         });
     };
@@ -67,8 +74,8 @@ pub fn SingleArticleView(article: Article) -> impl IntoView {
                     align=FlexAlign::Center
                     style=format!("{} items-center", class_css)
                 >
-                    <Label>"Name: "</Label>
-                    <Label>"Cost: "</Label>
+                    <Label>{texts.name}": "</Label>
+                    <Label>{texts.cost}": "</Label>
                 </Flex>
                 <Flex
                     vertical=true
@@ -82,7 +89,7 @@ pub fn SingleArticleView(article: Article) -> impl IntoView {
             <div>
                 <table class="w-full text-white border-collapse border-spacing-5">
                     <tr class="bg-black">
-                        <th class="pl-2">"Barcodes"</th>
+                        <th class="pl-2">{texts.barcodes}</th>
                         <th></th>
                     </tr>
                     {move || {
@@ -190,16 +197,18 @@ pub fn SingleArticleView(article: Article) -> impl IntoView {
                             barcodes_diff_signal.write().push(BarcodeDiff::Added(new_barcode));
                         }
                     >
-                        "Add Barcode"
+                        {texts.add_barcode}
                     </button>
                 </div>
             </div>
 
+            <BundleComponents article_id=article.id components=components_signal />
+
             <div class="w-[30vw]">
                 <input
                     class="w-full bg-indigo-700 hover:bg-pink-700 text-white font-bold py-2 px-4 mb-6 rounded"
                     type="submit"
-                    value="Upate article"
+                    value=texts.update_article
                     on:click=on_click
                 />
             </div>
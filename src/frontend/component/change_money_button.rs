@@ -1,9 +1,9 @@
 use leptos::prelude::*;
 use thaw::{Button, ButtonAppearance, ButtonRef, ComponentRef};
 
-use crate::{
-    frontend::{model::money_args::MoneyArgs, shared::change_money},
-    model::Money,
+use crate::frontend::{
+    model::money_args::MoneyArgs,
+    shared::{change_money, current_currency},
 };
 
 #[component]
@@ -28,7 +28,7 @@ pub fn ChangeMoneyButton(money: i64, args: RwSignal<MoneyArgs>) -> impl IntoView
                 self_ref.get().unwrap().blur()
             }
         >
-            {Money::format_eur_diff_value(money)}
+            {current_currency().format_diff(money)}
         </Button>
     }
 }
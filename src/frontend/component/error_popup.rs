@@ -5,10 +5,14 @@ use thaw::{
     DialogSurface, DialogTitle,
 };
 
-use crate::frontend::model::throw_error::{ThrowError, THROW_ERROR_HARD};
+use crate::frontend::{
+    model::throw_error::{ThrowError, THROW_ERROR_HARD},
+    shared::use_texts,
+};
 
 #[component]
 pub fn ErrorDisplay() -> impl IntoView {
+    let texts = use_texts();
     let store = expect_context::<Store<ThrowError<THROW_ERROR_HARD>>>();
     // needed because we need the effect to force execution on client
     let content = RwSignal::new(Vec::<String>::new());
@@ -24,7 +28,7 @@ pub fn ErrorDisplay() -> impl IntoView {
             <Dialog open>
                 <DialogSurface>
                     <DialogBody>
-                        <DialogTitle>"An error occured"</DialogTitle>
+                        <DialogTitle>{texts.an_error_occured}</DialogTitle>
                         <DialogContent>
                             {move || {
                                 content
@@ -41,7 +45,7 @@ pub fn ErrorDisplay() -> impl IntoView {
                                     *store.write() = ThrowError(vec![]);
                                 }
                             >
-                                "Ok"
+                                {texts.ok}
                             </Button>
                         </DialogActions>
                     </DialogBody>
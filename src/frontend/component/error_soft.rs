@@ -2,10 +2,14 @@ use leptos::prelude::*;
 use reactive_stores::Store;
 use thaw::{Toast, ToastBody, ToastIntent, ToastOptions, ToastTitle, ToasterInjection};
 
-use crate::frontend::model::throw_error::{ThrowError, THROW_ERROR_SOFT};
+use crate::frontend::{
+    model::throw_error::{ThrowError, THROW_ERROR_SOFT},
+    shared::use_texts,
+};
 
 #[component]
 pub fn ErrorSoftDisplay() -> impl IntoView {
+    let texts = use_texts();
     let store = expect_context::<Store<ThrowError<THROW_ERROR_SOFT>>>();
     let toaster = ToasterInjection::expect_context();
     Effect::new(move |_| {
@@ -16,7 +20,7 @@ pub fn ErrorSoftDisplay() -> impl IntoView {
                 move || {
                     view! {
                         <Toast>
-                            <ToastTitle>"An Error occured"</ToastTitle>
+                            <ToastTitle>{texts.an_error_occured}</ToastTitle>
                             <ToastBody>{e.to_string()}</ToastBody>
                         </Toast>
                     }
@@ -6,6 +6,8 @@ pub mod clear_filter;
 pub mod delete_button;
 pub mod send_money;
 pub mod settings_icon;
+pub mod statement_icon;
+pub mod stats_icon;
 
 pub use arrow_left::*;
 pub use arrow_left_right::*;
@@ -14,3 +16,5 @@ pub use basket::*;
 pub use delete_button::*;
 pub use send_money::*;
 pub use settings_icon::*;
+pub use statement_icon::*;
+pub use stats_icon::*;
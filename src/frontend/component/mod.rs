@@ -8,5 +8,7 @@ pub mod multi_user_selection;
 pub mod navbar;
 pub mod return_to;
 pub mod single_user_selection;
+pub mod sound;
+pub mod statistic;
 pub mod transaction;
 pub mod user;
@@ -1,7 +1,10 @@
 use leptos::prelude::*;
 use thaw::{Button, ButtonAppearance};
 
-use crate::frontend::component::{icon::DeleteIcon, single_user_selection::SelectSingleUser};
+use crate::frontend::{
+    component::{icon::DeleteIcon, single_user_selection::SelectSingleUser},
+    shared::use_texts,
+};
 
 #[component]
 pub fn SelectMultiUser(
@@ -9,6 +12,7 @@ pub fn SelectMultiUser(
     users_input: RwSignal<Vec<String>>,
     #[prop(optional)] children: Option<ChildrenFn>,
 ) -> impl IntoView {
+    let texts = use_texts();
     let new_user_input = RwSignal::new(String::new());
     view! {
         <div>
@@ -22,7 +26,7 @@ pub fn SelectMultiUser(
                             new_user_input.write().clear();
                         }
                     >
-                        "Add User"
+                        {texts.add_user}
                     </Button>
                 </div>
                 <div class="flex flex-col items-center pt-5 gap-10 text-[1.25em]">
@@ -1,14 +1,20 @@
 use leptos::prelude::*;
 
+use crate::frontend::shared::use_texts;
+
 #[component]
 pub fn View() -> impl IntoView {
+    let texts = use_texts();
+
     view! {
         // navbar
         <nav>
             <div class="flex flex-row gap-4 p-8 bg-[#1d2832] text-gray-200">
                 <a href="/">"Strichliste"</a>
-                <a href="/articles">"Article list"</a>
-                <a href="/split_cost">"Split cost"</a>
+                <a href="/articles">{texts.article_list}</a>
+                <a href="/split_cost">{texts.split_cost}</a>
+                <a href="/stats">{texts.statistics}</a>
+                <a href="/sounds">{texts.sounds}</a>
             </div>
         </nav>
     }
@@ -3,7 +3,7 @@ use thaw::{AutoComplete, AutoCompleteOption, Field};
 
 use crate::{
     backend::core::behaviour::user_get_all::get_all_users,
-    frontend::component::error_message::ErrorMessage,
+    frontend::{component::error_message::ErrorMessage, shared::use_texts},
 };
 
 const MAX_USER_DISPLAY_LIMIT: usize = 5;
@@ -15,20 +15,21 @@ pub fn SelectSingleUser(
     #[prop(optional)] children: Option<ChildrenFn>,
     #[prop(optional)] filter_names: Option<RwSignal<Vec<String>>>,
 ) -> impl IntoView {
+    let texts = use_texts();
     let all_users_resource = OnceResource::new(get_all_users());
 
     let title = RwSignal::new(title);
 
     view! {
         <Suspense fallback=move || {
-            view! { <p class="text-white text-center">"Loading users"</p> }
+            view! { <p class="text-white text-center">{texts.loading_users}</p> }
         }>
             {move || {
                 let all_users = match all_users_resource.get() {
                     Some(Ok(value)) => RwSignal::new(value),
                     _ => {
                         return view! {
-                            <ErrorMessage error=String::from("Failed to fetch users!") />
+                            <ErrorMessage error=String::from(texts.failed_to_fetch_users) />
                         }
                             .into_any();
                     }
@@ -0,0 +1,5 @@
+pub mod sound_row;
+pub mod user_sound_select;
+
+pub use sound_row::*;
+pub use user_sound_select::*;
@@ -0,0 +1,203 @@
+use leptos::{html, prelude::*, task::spawn_local};
+
+use crate::{
+    backend::core::{
+        behaviour::{sound_delete::delete_sound, sound_set_assignments::set_sound_assignments},
+        Article, Sound,
+    },
+    frontend::{
+        component::icon::DeleteIcon,
+        model::texts::Texts,
+        shared::{play_sound_by_id, throw_error, use_texts},
+    },
+    model::SoundEvent,
+};
+
+fn event_text(texts: &Texts, event: SoundEvent) -> &'static str {
+    match event {
+        SoundEvent::Generic => texts.event_generic,
+        SoundEvent::Failed => texts.event_failed,
+        SoundEvent::Refused => texts.event_refused,
+        SoundEvent::Deposit => texts.event_deposit,
+        SoundEvent::Withdraw => texts.event_withdraw,
+        SoundEvent::Milestone => texts.event_milestone,
+    }
+}
+
+/// One stored sound with its assignments, `sounds_revision` is bumped when it was deleted
+#[component]
+pub fn SoundRow(
+    sound: Sound,
+    articles: RwSignal<Vec<Article>>,
+    sounds_revision: RwSignal<u64>,
+) -> impl IntoView {
+    let texts = use_texts();
+    let sound_id = sound.id;
+
+    let events = RwSignal::new(sound.events);
+    let article_ids = RwSignal::new(sound.article_ids);
+
+    let new_article_node = NodeRef::<html::Select>::new();
+
+    let article_name = move |id: i64| {
+        articles
+            .get()
+            .into_iter()
+            .find(|elem| elem.id == id)
+            .map(|elem| elem.name)
+            .unwrap_or_else(|| (texts.unknown_article)(&id.to_string()))
+    };
+
+    let on_add_article = move |_| {
+        let article_node = new_article_node
+            .get()
+            .expect("new_article_select should be mounted!");
+
+        let Ok(article_id) = article_node.value().parse::<i64>() else {
+            return;
+        };
+
+        article_ids.update(|vec| {
+            if !vec.contains(&article_id) {
+                vec.push(article_id);
+            }
+        });
+    };
+
+    let on_save = move |_| {
+        spawn_local(async move {
+            if let Err(e) = set_sound_assignments(
+                sound_id,
+                events.get_untracked(),
+                article_ids.get_untracked(),
+            )
+            .await
+            {
+                let msg = match e {
+                    ServerFnError::ServerError(msg) => msg,
+                    _ => e.to_string(),
+                };
+                throw_error((texts.failed_to_update_sound)(&msg));
+            }
+        });
+    };
+
+    let on_delete = move |_| {
+        spawn_local(async move {
+            match delete_sound(sound_id).await {
+                Ok(_) => sounds_revision.update(|revision| *revision += 1),
+                Err(e) => {
+                    let msg = match e {
+                        ServerFnError::ServerError(msg) => msg,
+                        _ => e.to_string(),
+                    };
+                    throw_error((texts.failed_to_delete_sound)(&msg));
+                }
+            }
+        });
+    };
+
+    let button_css = "bg-indigo-700 hover:bg-pink-700 text-white font-bold py-2 px-4 rounded";
+
+    view! {
+        <tr class="even:bg-gray-700 odd:bg-gray-500 text-center">
+            <td class="px-2">
+                <p>{sound.name}</p>
+            </td>
+            <td class="px-2">
+                <button class=button_css on:click=move |_| play_sound_by_id(sound_id)>
+                    {texts.preview}
+                </button>
+            </td>
+            <td class="px-2">
+                <div class="flex flex-col items-start">
+                    {SoundEvent::ALL
+                        .into_iter()
+                        .map(|event| {
+                            view! {
+                                <label class="flex gap-2 items-center">
+                                    <input
+                                        type="checkbox"
+                                        prop:checked=move || events.get().contains(&event)
+                                        on:change=move |ev| {
+                                            let checked = event_target_checked(&ev);
+                                            events
+                                                .update(|vec| {
+                                                    vec.retain(|elem| *elem != event);
+                                                    if checked {
+                                                        vec.push(event);
+                                                    }
+                                                });
+                                        }
+                                    />
+                                    {event_text(texts, event)}
+                                </label>
+                            }
+                        })
+                        .collect_view()}
+                </div>
+            </td>
+            <td class="px-2">
+                <div class="flex flex-col gap-2 py-2">
+                    {move || {
+                        article_ids
+                            .get()
+                            .into_iter()
+                            .map(|article_id| {
+                                view! {
+                                    <div class="flex gap-2 items-center justify-between">
+                                        <p>{move || article_name(article_id)}</p>
+                                        <button
+                                            class="size-6"
+                                            on:click=move |_| {
+                                                article_ids
+                                                    .update(|vec| vec.retain(|elem| *elem != article_id));
+                                            }
+                                        >
+                                            <DeleteIcon />
+                                        </button>
+                                    </div>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                    <div class="flex gap-2">
+                        <select
+                            class="text-black rounded-[5px] text-center"
+                            node_ref=new_article_node
+                        >
+                            {move || {
+                                articles
+                                    .get()
+                                    .into_iter()
+                                    .filter(|elem| !elem.is_disabled)
+                                    .map(|elem| {
+                                        view! {
+                                            <option value=elem.id.to_string()>{elem.name}</option>
+                                        }
+                                    })
+                                    .collect_view()
+                            }}
+                        </select>
+                        <button class=button_css on:click=on_add_article>
+                            {texts.add_article}
+                        </button>
+                    </div>
+                </div>
+            </td>
+            <td class="px-2">
+                <div class="flex flex-col gap-2">
+                    <button class=button_css on:click=on_save>
+                        {texts.save}
+                    </button>
+                    <button
+                        class="bg-red-700 hover:bg-pink-700 text-white font-bold py-2 px-4 rounded"
+                        on:click=on_delete
+                    >
+                        {texts.delete}
+                    </button>
+                </div>
+            </td>
+        </tr>
+    }
+}
@@ -0,0 +1,35 @@
+use leptos::prelude::*;
+
+use crate::{backend::core::Sound, frontend::shared::use_texts, model::DatabaseId};
+
+/// Lets a user pick one of the stored sounds, the empty value stands for the default sounds
+#[component]
+pub fn UserSoundSelect(
+    label: &'static str,
+    name: &'static str,
+    sounds: Vec<Sound>,
+    selected: Option<DatabaseId>,
+) -> impl IntoView {
+    let texts = use_texts();
+
+    view! {
+        <label class="text-white flex gap-2 items-center">
+            {label}
+            <select class="text-black rounded-[5px] text-center" name=name>
+                <option value="" selected=selected.is_none()>
+                    {texts.default_sound}
+                </option>
+                {sounds
+                    .into_iter()
+                    .map(|sound| {
+                        view! {
+                            <option value=sound.id selected=selected == Some(sound.id)>
+                                {sound.name}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+        </label>
+    }
+}
@@ -0,0 +1,32 @@
+use leptos::prelude::*;
+
+use crate::{
+    frontend::shared::{current_currency, use_texts},
+    model::ArticleStatistic,
+};
+
+#[component]
+pub fn ArticleStatisticsTable(articles: Vec<ArticleStatistic>) -> impl IntoView {
+    let texts = use_texts();
+    view! {
+        <table class="w-full text-white border-collapse border-spacing-5">
+            <tr class="bg-black">
+                <th class="pl-2">{texts.article}</th>
+                <th class="px-2">{texts.amount}</th>
+                <th class="px-2">{texts.value}</th>
+            </tr>
+            {articles
+                .into_iter()
+                .map(|article| {
+                    view! {
+                        <tr class="even:bg-gray-700 odd:bg-gray-500 text-center">
+                            <td class="px-2">{article.name}</td>
+                            <td class="px-2">{article.amount}</td>
+                            <td class="px-2">{article.value.format(&current_currency())}</td>
+                        </tr>
+                    }
+                })
+                .collect_view()}
+        </table>
+    }
+}
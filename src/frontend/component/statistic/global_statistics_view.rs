@@ -0,0 +1,90 @@
+use leptos::prelude::*;
+use thaw::Spinner;
+
+use crate::{
+    backend::core::behaviour::global_statistics_get::get_global_statistics,
+    frontend::{
+        component::statistic::{money_points, ArticleStatisticsTable, StatisticBarChart},
+        shared::{current_currency, throw_error_none_view, use_texts},
+    },
+};
+
+#[component]
+pub fn GlobalStatisticsView() -> impl IntoView {
+    let texts = use_texts();
+    let statistics_resource = OnceResource::new(get_global_statistics());
+
+    view! {
+        <Suspense fallback=move || {
+            view! { <Spinner label=texts.loading_statistics /> }
+        }>
+            {move || {
+                let statistics = match statistics_resource.get() {
+                    None => return ().into_any(),
+                    Some(Ok(value)) => value,
+                    Some(Err(e)) => {
+                        let msg = match e {
+                            ServerFnError::ServerError(msg) => msg,
+                            _ => e.to_string(),
+                        };
+                        return throw_error_none_view(
+                            (texts.failed_to_fetch_statistics)(&msg),
+                        );
+                    }
+                };
+
+                view! {
+                    <div class="flex flex-col gap-10 p-5 text-white">
+                        <div class="grid grid-cols-2 gap-10">
+                            <div>
+                                <p class="text-[1.25em] text-center">{texts.top_articles}</p>
+                                <ArticleStatisticsTable articles=statistics.top_articles />
+                            </div>
+                            <div>
+                                <p class="text-[1.25em] text-center">{texts.top_consumers}</p>
+                                <table class="w-full text-white border-collapse border-spacing-5">
+                                    <tr class="bg-black">
+                                        <th class="pl-2">{texts.user}</th>
+                                        <th class="px-2">{texts.purchases}</th>
+                                        <th class="px-2">{texts.spent}</th>
+                                    </tr>
+                                    {statistics
+                                        .top_consumers
+                                        .into_iter()
+                                        .map(|consumer| {
+                                            view! {
+                                                <tr class="even:bg-gray-700 odd:bg-gray-500 text-center">
+                                                    <td class="px-2">
+                                                        <a href=format!(
+                                                            "/user/{}/stats",
+                                                            consumer.user_id,
+                                                        )>{consumer.nickname}</a>
+                                                    </td>
+                                                    <td class="px-2">{consumer.amount}</td>
+                                                    <td class="px-2">{consumer.spent.format(&current_currency())}</td>
+                                                </tr>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </table>
+                            </div>
+                        </div>
+                        <div class="grid grid-cols-2 gap-10">
+                            <StatisticBarChart
+                                title=texts.revenue_per_day
+                                points=money_points(statistics.revenue_per_day)
+                                format=|value: i64| current_currency().format(value)
+                            />
+                            <StatisticBarChart
+                                title=texts.revenue_per_week
+                                points=money_points(statistics.revenue_per_week)
+                                format=|value: i64| current_currency().format(value)
+                            />
+                        </div>
+                    </div>
+                }
+                    .into_any()
+            }}
+        </Suspense>
+    }
+}
@@ -0,0 +1,9 @@
+pub mod article_statistics_table;
+pub mod global_statistics_view;
+pub mod statistic_bar_chart;
+pub mod user_statistics_view;
+
+pub use article_statistics_table::*;
+pub use global_statistics_view::*;
+pub use statistic_bar_chart::*;
+pub use user_statistics_view::*;
@@ -0,0 +1,52 @@
+use leptos::prelude::*;
+
+use crate::model::{Money, StatisticPoint};
+
+pub fn money_points(points: Vec<StatisticPoint<Money>>) -> Vec<StatisticPoint<i64>> {
+    points
+        .into_iter()
+        .map(|point| StatisticPoint {
+            label: point.label,
+            value: point.value.value,
+        })
+        .collect()
+}
+
+/// Renders a time series as horizontal bars, scaled to the largest value
+#[component]
+pub fn StatisticBarChart(
+    title: &'static str,
+    points: Vec<StatisticPoint<i64>>,
+    format: fn(i64) -> String,
+) -> impl IntoView {
+    let max = points
+        .iter()
+        .map(|point| point.value)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    view! {
+        <div class="flex flex-col gap-1 text-white">
+            <p class="text-[1.25em] text-center">{title}</p>
+            {points
+                .into_iter()
+                .map(|point| {
+                    let width = (point.value.max(0) * 100) / max;
+                    view! {
+                        <div class="grid grid-cols-[8em_1fr_6em] gap-2 items-center">
+                            <p class="text-right">{point.label}</p>
+                            <div class="bg-gray-700 rounded h-4">
+                                <div
+                                    class="bg-indigo-700 rounded h-4"
+                                    style=format!("width: {}%", width)
+                                ></div>
+                            </div>
+                            <p>{format(point.value)}</p>
+                        </div>
+                    }
+                })
+                .collect_view()}
+        </div>
+    }
+}
@@ -0,0 +1,64 @@
+use leptos::prelude::*;
+use thaw::Spinner;
+
+use crate::{
+    backend::core::behaviour::user_statistics_get::get_user_statistics,
+    frontend::{
+        component::statistic::{money_points, ArticleStatisticsTable, StatisticBarChart},
+        shared::{current_currency, throw_error_none_view, use_texts},
+    },
+    model::UserId,
+};
+
+#[component]
+pub fn UserStatisticsView(user_id: UserId) -> impl IntoView {
+    let texts = use_texts();
+    let statistics_resource = OnceResource::new(get_user_statistics(user_id));
+
+    view! {
+        <Suspense fallback=move || {
+            view! { <Spinner label=texts.loading_statistics /> }
+        }>
+            {move || {
+                let statistics = match statistics_resource.get() {
+                    None => return ().into_any(),
+                    Some(Ok(value)) => value,
+                    Some(Err(e)) => {
+                        let msg = match e {
+                            ServerFnError::ServerError(msg) => msg,
+                            _ => e.to_string(),
+                        };
+                        return throw_error_none_view(
+                            (texts.failed_to_fetch_statistics)(&msg),
+                        );
+                    }
+                };
+                view! {
+                    <div class="flex flex-col gap-10 p-5 text-white">
+                        <div class="flex justify-evenly text-[1.5em]">
+                            <p>{texts.total_spent}": " {statistics.total_spent.format(&current_currency())}</p>
+                            <p>{texts.average_per_week}": " {statistics.average_per_week.format(&current_currency())}</p>
+                        </div>
+                        <div class="grid grid-cols-2 gap-10">
+                            <StatisticBarChart
+                                title=texts.spent_per_month
+                                points=money_points(statistics.spent_per_month)
+                                format=|value: i64| current_currency().format(value)
+                            />
+                            <StatisticBarChart
+                                title=texts.articles_per_week
+                                points=statistics.consumption_per_week
+                                format=|value: i64| value.to_string()
+                            />
+                        </div>
+                        <div>
+                            <p class="text-[1.25em] text-center">{texts.top_articles}</p>
+                            <ArticleStatisticsTable articles=statistics.top_articles />
+                        </div>
+                    </div>
+                }
+                    .into_any()
+            }}
+        </Suspense>
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
     },
     frontend::{
         component::icon::{ArticleBasketIcon, LeftArrowIcon, LeftRightArrowIcon, RightArrowIcon},
-        shared::{throw_error, throw_error_none_view},
+        shared::{current_currency, throw_error, throw_error_none_view, use_texts},
     },
     model::{Money, Transaction, TransactionType, UserId},
 };
@@ -20,6 +20,7 @@ pub fn FormatTransaction(
     user_id: UserId,
     money_signal: RwSignal<Money>,
 ) -> impl IntoView {
+    let texts = use_texts();
     let now: DateTime<Utc> = Utc::now();
     let diff = now - transaction.timestamp;
 
@@ -54,7 +55,7 @@ pub fn FormatTransaction(
             {match transaction.t_type {
                 TransactionType::Withdraw => {
                     view! {
-                        <p class="text-red-400">"-"{transaction.money.format_eur()}</p>
+                        <p class="text-red-400">"-"{transaction.money.format(&current_currency())}</p>
                         <p></p>
                     }
                         .into_any()
@@ -62,7 +63,7 @@ pub fn FormatTransaction(
                 TransactionType::Deposit => {
 
                     view! {
-                        <p class="text-green-500">{transaction.money.format_eur_diff()}</p>
+                        <p class="text-green-500">{transaction.money.format_diff(&current_currency())}</p>
                         <p></p>
                     }
                         .into_any()
@@ -70,7 +71,7 @@ pub fn FormatTransaction(
                 TransactionType::Bought(_) => {
 
                     view! {
-                        <p class="text-red-400">"-"{transaction.money.format_eur()}</p>
+                        <p class="text-red-400">"-"{transaction.money.format(&current_currency())}</p>
                         <p class="text-white">
                             <ArticleBasketIcon class="inline" />
                             " "
@@ -87,7 +88,7 @@ pub fn FormatTransaction(
 
                     view! {
                         <Suspense fallback=move || {
-                            view! { <Spinner label="Loading users" /> }
+                            view! { <Spinner label=texts.loading_users /> }
                         }>
                             {move || {
                                 group_members_resource
@@ -96,7 +97,7 @@ pub fn FormatTransaction(
                                         match members {
                                             Err(e) => {
                                                 throw_error_none_view(
-                                                    format!("Failed to fetch group members: {e}"),
+                                                    (texts.failed_to_fetch_group_members)(&e.to_string()),
                                                 )
                                             }
                                             Ok(members) => {
@@ -115,7 +116,7 @@ pub fn FormatTransaction(
                                                     {if cost >= 0 {
                                                         view! {
                                                             <p class="text-green-500">
-                                                                "+"{transaction.money.format_eur()}
+                                                                "+"{transaction.money.format(&current_currency())}
                                                             </p>
                                                         }
                                                             .into_any()
@@ -123,7 +124,7 @@ pub fn FormatTransaction(
 
                                                         view! {
                                                             <p class="text-red-400">
-                                                                "-"{transaction.money.format_eur()}
+                                                                "-"{transaction.money.format(&current_currency())}
                                                             </p>
                                                         }
                                                             .into_any()
@@ -165,17 +166,17 @@ pub fn FormatTransaction(
                     view! {
                         {if money_value < 0 {
                             view! {
-                                <p class="text-red-400">"-"{transaction.money.format_eur()}</p>
+                                <p class="text-red-400">"-"{transaction.money.format(&current_currency())}</p>
                             }
                                 .into_any()
                         } else {
                             view! {
-                                <p class="text-green-500">"+"{transaction.money.format_eur()}</p>
+                                <p class="text-green-500">"+"{transaction.money.format(&current_currency())}</p>
                             }
                                 .into_any()
                         }}
                         <Suspense fallback=move || {
-                            view! { <p>"Loading users"</p> }
+                            view! { <p>{texts.loading_users}</p> }
                         }>
                             {move || {
                                 let description = transaction
@@ -228,7 +229,7 @@ pub fn FormatTransaction(
                                                     _ => error.to_string(),
                                                 };
                                                 throw_error_none_view(
-                                                    format!("Failed to fetch members: {message}"),
+                                                    (texts.failed_to_fetch_group_members)(&message),
                                                 )
                                             }
                                         }
@@ -253,7 +254,7 @@ pub fn FormatTransaction(
                             <ActionForm action=undo_action>
                                 <input type="hidden" name="user_id" value=user_id.0 />
                                 <input type="hidden" name="transaction_id" value=transaction_id />
-                                <input type="submit" class="text-white" value="Undo" />
+                                <input type="submit" class="text-white" value=texts.undo />
                             </ActionForm>
                         }
                             .into_any()
@@ -274,7 +275,7 @@ pub fn FormatTransaction(
                                 ServerFnError::ServerError(msg) => msg,
                                 _ => e.to_string(),
                             };
-                            throw_error(msg);
+                            throw_error((texts.failed_to_undo_transaction)(&msg));
                         }
                     }
                 }
@@ -1,6 +1,6 @@
 use leptos::{html, prelude::*};
 
-use crate::model::Transaction;
+use crate::{frontend::shared::use_texts, model::Transaction};
 
 #[component]
 pub fn ShowNavigationButtons(
@@ -8,6 +8,7 @@ pub fn ShowNavigationButtons(
     transaction_signal: RwSignal<Vec<Transaction>>,
     transactions_per_page: usize,
 ) -> impl IntoView {
+    let texts = use_texts();
     view! {
         <div class="flex justify-between p-2">
             {
@@ -37,7 +38,7 @@ pub fn ShowNavigationButtons(
                                 });
                         }
                     >
-                        "Previous page"
+                        {texts.previous_page}
                     </button>
                     <button
                         class="rounded p-5"
@@ -60,7 +61,7 @@ pub fn ShowNavigationButtons(
                             page_count.update(|value| *value += 1);
                         }
                     >
-                        "Next page"
+                        {texts.next_page}
                     </button>
                 }
             }
@@ -6,29 +6,27 @@ use thaw::Spinner;
 use crate::{
     backend::core::behaviour::transaction_get_user_transactions::get_user_transactions,
     frontend::{
-        component::transaction::FormatTransaction, model::money_args::MoneyArgs,
-        shared::throw_error_none_view,
+        component::transaction::FormatTransaction,
+        model::money_args::MoneyArgs,
+        shared::{throw_error_none_view, use_texts},
     },
     model::{PageRequestParams, PageResponseParams, Transaction, UserId},
 };
 
 #[component]
 pub fn ShowTransactions(arguments: RwSignal<MoneyArgs>) -> impl IntoView {
+    let texts = use_texts();
     let params = use_params_map();
     let user_id_string = match params.read_untracked().get("id") {
         Some(s) => s,
         None => {
-            return throw_error_none_view("Failed to obtain id from url");
+            return throw_error_none_view(texts.failed_to_obtain_id);
         }
     };
 
     let user_id = match user_id_string.parse::<i64>() {
         Ok(user_id) => UserId(user_id),
-        Err(_) => {
-            return throw_error_none_view(format!(
-                "Failed to convert id to a number!: {user_id_string}"
-            ))
-        }
+        Err(_) => return throw_error_none_view((texts.invalid_id)(&user_id_string)),
     };
 
     let previous_transactions_response_params: RwSignal<Option<PageResponseParams>> =
@@ -41,7 +39,7 @@ pub fn ShowTransactions(arguments: RwSignal<MoneyArgs>) -> impl IntoView {
 
     view! {
         <Suspense fallback=move || {
-            view! { <Spinner label="Loading transactions" /> }
+            view! { <Spinner label=texts.loading_transactions /> }
         }>
             {move || {
                 let transactions = match transaction_data.get() {
@@ -55,11 +53,9 @@ pub fn ShowTransactions(arguments: RwSignal<MoneyArgs>) -> impl IntoView {
                     Err(err) => {
                         let msg = match err {
                             ServerFnError::ServerError(msg) => msg,
-                            _ => "Failed to fetch transactions".to_string(),
+                            _ => err.to_string(),
                         };
-                        return throw_error_none_view(
-                            format!("Failed to fetch users because: {msg}"),
-                        );
+                        return throw_error_none_view((texts.failed_to_fetch_transactions)(&msg));
                     }
                 };
                 let el = NodeRef::<leptos::html::Div>::new();
@@ -1,5 +1,9 @@
+pub mod pin_lock;
 pub mod show_users;
+pub mod statement_list;
 pub mod user_preview;
 
+pub use pin_lock::*;
 pub use show_users::*;
+pub use statement_list::*;
 pub use user_preview::*;
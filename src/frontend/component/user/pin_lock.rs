@@ -0,0 +1,75 @@
+use leptos::{ev, prelude::*};
+use thaw::{Button, ButtonType, Flex, FlexAlign, FlexGap, FlexJustify, Input, InputType};
+
+use crate::{
+    backend::core::behaviour::pin_session_get::get_pin_session,
+    frontend::shared::{lock_after_session, throw_error_none_view, unlock_account, use_texts},
+    model::UserId,
+};
+
+/// Shows its children only while the account is unlocked with its PIN or card.
+/// Accounts without a PIN are never locked. The server checks the session as well,
+/// this only keeps the ui from offering actions that would fail.
+#[component]
+pub fn PinLock(
+    user_id: UserId,
+    unlocked: RwSignal<bool>,
+    /// set while the PIN input is focused, so scanners can ignore the typed PIN
+    #[prop(optional)]
+    pin_is_focused: Option<RwSignal<bool>>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let texts = use_texts();
+
+    let session = OnceResource::new(get_pin_session(user_id));
+    let pin = RwSignal::new(String::new());
+    let pin_is_focused = pin_is_focused.unwrap_or(RwSignal::new(false));
+
+    Effect::new(move || {
+        if let Some(Ok(session)) = session.get() {
+            lock_after_session(session, unlocked);
+        }
+    });
+
+    let on_submit = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        unlock_account(user_id, pin.get_untracked(), unlocked);
+        pin.set(String::new());
+    };
+
+    view! {
+        <Suspense>
+            {move || match session.get() {
+                None => ().into_any(),
+                Some(Err(e)) => {
+                    throw_error_none_view((texts.failed_to_fetch_pin_session)(&e.to_string()))
+                }
+                Some(Ok(_)) if unlocked.get() => children().into_any(),
+                Some(Ok(_)) => {
+                    view! {
+                        <form class="pt-5" on:submit=on_submit>
+                            <Flex
+                                justify=FlexJustify::Center
+                                align=FlexAlign::Center
+                                gap=FlexGap::Medium
+                                vertical=true
+                            >
+                                <p class="text-white text-[1.5em]">{texts.account_locked}</p>
+                                <p class="text-white">{texts.enter_pin_or_scan_card}</p>
+                                <Input
+                                    value=pin
+                                    input_type=InputType::Password
+                                    placeholder=texts.pin
+                                    on:focus=move |_| pin_is_focused.set(true)
+                                    on:blur=move |_| pin_is_focused.set(false)
+                                />
+                                <Button button_type=ButtonType::Submit>{texts.unlock}</Button>
+                            </Flex>
+                        </form>
+                    }
+                        .into_any()
+                }
+            }}
+        </Suspense>
+    }
+}
@@ -9,11 +9,12 @@ use crate::frontend::{
         frontend_store::{FrontendStoreStoreFields, FrontendStoreType},
     },
     route::home::PREFIX_FILTER_NON_ALPHABETIC_VALUE,
-    shared::throw_error,
+    shared::{throw_error, use_texts},
 };
 
 #[component]
 pub fn ShowUsers(prefix_filter: Signal<Option<char>>) -> impl IntoView {
+    let texts = use_texts();
     let store = expect_context::<FrontendStoreType>();
 
     let cache = store.cachinglayer().get_untracked();
@@ -29,7 +30,7 @@ pub fn ShowUsers(prefix_filter: Signal<Option<char>>) -> impl IntoView {
     view! {
         {move || {
             if users.read().is_fetching.get() && users.read().value.get().is_empty() {
-                return view! { <Spinner label="Loading users!" /> }.into_any();
+                return view! { <Spinner label=texts.loading_users /> }.into_any();
             }
             let filterd_users = match prefix_filter.get() {
                 Some(filter) => {
@@ -45,12 +46,11 @@ pub fn ShowUsers(prefix_filter: Signal<Option<char>>) -> impl IntoView {
                                 .next()
                                 .expect("nickname isn't allowed empty")
                                 .to_ascii_lowercase();
-                            if (filter == PREFIX_FILTER_NON_ALPHABETIC_VALUE
-                                && first_letter.is_alphabetic()) || (first_letter != filter)
-                            {
-                                return None;
-                            }
-                            Some(user)
+                            let matches_filter = match filter {
+                                PREFIX_FILTER_NON_ALPHABETIC_VALUE => !first_letter.is_alphabetic(),
+                                filter => first_letter == filter,
+                            };
+                            matches_filter.then_some(user)
                         })
                         .collect_vec()
                 }
@@ -0,0 +1,62 @@
+use chrono::Utc;
+use leptos::prelude::*;
+
+use crate::{
+    frontend::shared::use_texts,
+    model::{StatementMonth, UserId},
+};
+
+/// how many months are offered for download
+const STATEMENT_MONTHS: usize = 12;
+
+#[component]
+pub fn StatementList(user_id: UserId) -> impl IntoView {
+    let texts = use_texts();
+    let mut month = StatementMonth::of(Utc::now());
+    let mut months = Vec::<StatementMonth>::new();
+    for _ in 0..STATEMENT_MONTHS {
+        months.push(month);
+        month = month.previous();
+    }
+
+    view! {
+        <div class="flex flex-col items-center gap-3 pt-5">
+            <h1 class="text-white text-[2em]">{texts.monthly_statements}</h1>
+            <table class="text-white border-collapse border-spacing-5 w-1/2 text-[1.25em]">
+                <tbody>
+                    {months
+                        .into_iter()
+                        .map(|month| {
+                            let link = format!("/statement/{}/{}", user_id, month);
+                            view! {
+                                <tr class="even:bg-gray-700 odd:bg-gray-500">
+                                    <td class="px-2">{month.to_string()}</td>
+                                    <td class="px-2 text-center">
+                                        <a
+                                            href=format!("{link}?format=html")
+                                            rel="external"
+                                            target="_blank"
+                                            class="underline"
+                                        >
+                                            "HTML"
+                                        </a>
+                                    </td>
+                                    <td class="px-2 text-center">
+                                        <a
+                                            href=format!("{link}?format=pdf")
+                                            rel="external"
+                                            target="_blank"
+                                            class="underline"
+                                        >
+                                            "PDF"
+                                        </a>
+                                    </td>
+                                </tr>
+                            }
+                        })
+                        .collect_view()}
+                </tbody>
+            </table>
+        </div>
+    }
+}
@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 
-use crate::{backend::core::User, model::Money};
+use crate::{backend::core::User, frontend::shared::current_currency};
 
 #[component]
 pub fn UserPreview(user: User) -> impl IntoView {
@@ -12,7 +12,7 @@ pub fn UserPreview(user: User) -> impl IntoView {
                 class=("text-red-500", move || { user.money.value < 0 })
                 class=("text-green-500", move || { user.money.value >= 0 })
             >
-                {Money::format_eur_diff_value(user.money.value)}
+                {current_currency().format_diff(user.money.value)}
             </p>
         </div>
     }
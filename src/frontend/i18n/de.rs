@@ -0,0 +1,182 @@
+use crate::frontend::model::texts::Texts;
+
+pub static DE: Texts = Texts {
+    article_list: "Artikelliste",
+    split_cost: "Kosten teilen",
+    statistics: "Statistiken",
+    settings: "Einstellungen",
+    statements: "Kontoauszüge",
+    send_money: "Geld senden",
+    sounds: "Sounds",
+    page_not_found: "Seite nicht gefunden!",
+
+    create_user: "Benutzer anlegen",
+    add_user: "Benutzer hinzufügen",
+    user: "Benutzer",
+    nickname: "Spitzname",
+    card_number: "Kartennummer",
+    email: "E-Mail (für Schulden-Erinnerungen)",
+    show_in_stats: "Mich in der globalen Statistik anzeigen",
+    is_minor: "Unter 18",
+    lower_limit: "Untere Grenze",
+    upper_limit: "Obere Grenze",
+    pin: "PIN",
+    pin_placeholder: "Leer lassen, um die aktuelle PIN zu behalten",
+    remove_pin: "PIN entfernen",
+    purchase_sound: "Mein Kauf-Sound",
+    deposit_sound: "Mein Einzahlungs-Sound",
+    default_sound: "Standard",
+    account_locked: "Dieses Konto ist mit einer PIN geschützt",
+    enter_pin_or_scan_card: "Gib die PIN ein oder scanne die Karte",
+    unlock: "Entsperren",
+    limit_placeholder: "Globale Grenze",
+    limits_hint: "Die Grenzen und das Alter kann nur ein Admin ändern",
+    update_user: "Benutzer aktualisieren",
+    receiver: "Empfänger",
+    receiving_user: "Empfangender Benutzer",
+    send_money_greeting: |nickname| format!("Hallo {nickname}, wem möchtest du Geld senden?"),
+    monthly_statements: "Monatliche Kontoauszüge",
+
+    article: "Artikel",
+    name: "Name",
+    cost: "Kosten",
+    price: "Preis",
+    edit: "Bearbeiten",
+    barcodes: "Barcodes",
+    add_barcode: "Barcode hinzufügen",
+    bundle_components: "Bestandteile des Bündels",
+    quantity: "Anzahl",
+    add_component: "Bestandteil hinzufügen",
+    create_article: "Artikel anlegen",
+    update_article: "Artikel aktualisieren",
+    search: "Suchen",
+    search_for_articles: "Nach Artikeln suchen",
+    unknown_article: |id| format!("Artikel {id}"),
+    item_bought: "Artikel gekauft",
+    you_bought: |article, cost| format!("Du hast {article} für {cost} gekauft"),
+
+    euros: "Euro",
+    amount: "Betrag",
+    description: "Beschreibung",
+    undo: "Rückgängig",
+    previous_page: "Vorherige Seite",
+    next_page: "Nächste Seite",
+    who_are_you: "Wer bist du?",
+    split_cost_with: "Mit wem möchtest du die Kosten teilen?",
+    other_users: "Andere Benutzer",
+    how_much: "Wie viel?",
+
+    value: "Wert",
+    total_spent: "Insgesamt ausgegeben",
+    average_per_week: "Durchschnitt pro Woche",
+    spent_per_month: "Ausgaben pro Monat",
+    articles_per_week: "Artikel pro Woche",
+    top_articles: "Beliebteste Artikel",
+    top_consumers: "Größte Konsumenten",
+    purchases: "Käufe",
+    spent: "Ausgegeben",
+    revenue_per_day: "Umsatz pro Tag",
+    revenue_per_week: "Umsatz pro Woche",
+
+    upload_sounds: "Sounds hochladen",
+    no_sounds: "Es gibt noch keine Sounds",
+    plays_for: "Spielt bei",
+    assigned_articles: "Artikel",
+    add_article: "Artikel hinzufügen",
+    preview: "Anhören",
+    save: "Speichern",
+    delete: "Löschen",
+    event_generic: "Allgemein",
+    event_failed: "Fehler",
+    event_refused: "Abgelehnt",
+    event_deposit: "Einzahlung",
+    event_withdraw: "Auszahlung",
+    event_milestone: "Meilenstein",
+    milestone_reached: "Meilenstein erreicht!",
+
+    loading_user: "Lade Benutzer",
+    loading_users: "Lade Benutzer",
+    loading_article: "Lade Artikel...",
+    loading_articles: "Lade Artikel",
+    loading_statistics: "Lade Statistiken",
+    loading_transactions: "Lade Transaktionen",
+    loading_sounds: "Lade Sounds",
+
+    an_error_occured: "Ein Fehler ist aufgetreten",
+    ok: "Ok",
+    invalid_id: |id| format!("Die id {id} ist keine Zahl!"),
+    failed_to_obtain_id: "Die id konnte nicht aus der url gelesen werden",
+    user_not_found: |id| format!("Es wurde kein Benutzer mit der id {id} gefunden!"),
+    no_user_with_barcode: |barcode| {
+        format!("Es gibt keinen Benutzer mit dem Barcode \"{barcode}\"")
+    },
+    no_article_with_barcode: |barcode| {
+        format!("Es wurde kein Artikel mit dem Barcode '{barcode}' gefunden")
+    },
+    failed_to_add_user: |e| format!("Benutzer konnte nicht angelegt werden: {e}"),
+    failed_to_update_user: |e| format!("Benutzer konnte nicht aktualisiert werden: {e}"),
+    failed_to_fetch_user: |e| format!("Benutzer konnte nicht geladen werden: {e}"),
+    failed_to_fetch_user_by_barcode: |e| {
+        format!("Benutzer konnte nicht über den Barcode geladen werden: {e}")
+    },
+    failed_to_load_user: |e| format!("Benutzer konnte nicht geladen werden: {e}"),
+    failed_to_fetch_users: "Benutzer konnten nicht geladen werden!",
+    failed_to_fetch_users_because: |e| format!("Benutzer konnten nicht geladen werden: {e}"),
+    failed_to_fetch_group_members: |e| {
+        format!("Gruppenmitglieder konnten nicht geladen werden: {e}")
+    },
+    failed_to_create_article: |e| format!("Artikel konnte nicht angelegt werden: {e}"),
+    failed_to_update_article: |e| format!("Artikel konnte nicht aktualisiert werden: {e}"),
+    failed_to_fetch_article: |e| format!("Artikel konnte nicht geladen werden: {e}"),
+    failed_to_fetch_articles: |e| format!("Artikel konnten nicht geladen werden: {e}"),
+    failed_to_fetch_statistics: |e| format!("Statistiken konnten nicht geladen werden: {e}"),
+    failed_to_fetch_transactions: |e| format!("Transaktionen konnten nicht geladen werden: {e}"),
+    failed_to_undo_transaction: |e| {
+        format!("Transaktion konnte nicht rückgängig gemacht werden: {e}")
+    },
+    failed_to_send_money: |e| format!("Geld konnte nicht gesendet werden: {e}"),
+    failed_to_parse_money: |e| format!("Betrag konnte nicht gelesen werden: {e}"),
+    failed_to_get_audio_node: "Das Audio-Element wurde nicht gefunden",
+    failed_to_fetch_sound: |e| format!("Sound konnte nicht geladen werden: {e}"),
+    failed_to_fetch_sounds: |e| format!("Sounds konnten nicht geladen werden: {e}"),
+    failed_to_upload_sounds: |e| format!("Sounds konnten nicht hochgeladen werden: {e}"),
+    failed_to_update_sound: |e| format!("Sound konnte nicht aktualisiert werden: {e}"),
+    failed_to_delete_sound: |e| format!("Sound konnte nicht gelöscht werden: {e}"),
+    failed_to_fetch_kiosk_settings: |e| {
+        format!("Die Kiosk-Einstellungen konnten nicht geladen werden: {e}")
+    },
+    failed_to_fetch_pin_session: |e| format!("Die PIN konnte nicht geprüft werden: {e}"),
+    failed_to_unlock: |e| format!("Das Konto konnte nicht entsperrt werden: {e}"),
+    you_have_too_little_money: |limit| format!("Du hast zu wenig Geld! ({limit})"),
+    you_have_too_much_money: |limit| format!("Du hast zu viel Geld! ({limit})"),
+    global_limit: |limit| format!("Grenze {limit}"),
+    personal_limit: |limit| format!("persönliche Grenze {limit}"),
+
+    too_little_money: |users| format!("Die folgenden Benutzer haben zu wenig Geld: {users}"),
+    too_much_money: |users| format!("Die folgenden Benutzer haben zu viel Geld: {users}"),
+    failed_to_create_transaction: |e| format!("Transaktion konnte nicht angelegt werden: {e}"),
+    daily_limit_reached: |rule, max| format!("Du darfst nur {max} von '{rule}' pro Tag kaufen!"),
+    adults_only: |rule| format!("'{rule}' gibt es nur für Erwachsene!"),
+    pin_required: "Das Konto ist gesperrt, bitte gib die PIN ein!",
+    article_does_not_exist: |id| format!("Der Artikel mit der id {id} existiert nicht!"),
+    user_does_not_exist: |id| format!("Der Benutzer mit der id {id} existiert nicht!"),
+    nickname_does_not_exist: |nickname| {
+        format!("Es gibt keinen Benutzer mit dem Spitznamen '{nickname}'!")
+    },
+    may_not_be_empty: |field| format!("{field} darf nicht leer sein"),
+    money_must_be_positive: "Der Betrag muss positiv sein",
+
+    amount_is_empty: "Der Betrag ist leer",
+    invalid_character: |c| format!("Ungültiges Zeichen '{c}'"),
+    misplaced_sign: "Das Vorzeichen muss vor dem Betrag stehen",
+    multiple_decimal_separators: "Der Betrag enthält mehr als ein Dezimaltrennzeichen",
+    misplaced_thousands_separator: "Das Tausendertrennzeichen muss drei Ziffern gruppieren",
+    too_many_decimal_places: |max| {
+        format!("Der Betrag darf höchstens {max} Nachkommastellen haben")
+    },
+    amount_too_large: "Der Betrag ist zu groß",
+    amount_is_negative: "Der Betrag darf nicht negativ sein",
+    no_shares: "Der Betrag kann nicht auf niemanden aufgeteilt werden",
+    database_error: |e| format!("Datenbankfehler: {e}"),
+    server_error: |e| format!("Serverfehler: {e}"),
+};
@@ -0,0 +1,168 @@
+use crate::frontend::model::texts::Texts;
+
+pub static EN: Texts = Texts {
+    article_list: "Article list",
+    split_cost: "Split cost",
+    statistics: "Statistics",
+    settings: "Settings",
+    statements: "Statements",
+    send_money: "Send money",
+    sounds: "Sounds",
+    page_not_found: "Page not found!",
+
+    create_user: "Create User",
+    add_user: "Add User",
+    user: "User",
+    nickname: "Nickname",
+    card_number: "Card number",
+    email: "Email (for debt reminders)",
+    show_in_stats: "Show me in the global statistics",
+    is_minor: "Under 18",
+    lower_limit: "Lower limit",
+    upper_limit: "Upper limit",
+    pin: "PIN",
+    pin_placeholder: "Leave empty to keep the current PIN",
+    remove_pin: "Remove the PIN",
+    purchase_sound: "My purchase sound",
+    deposit_sound: "My deposit sound",
+    default_sound: "Default",
+    account_locked: "This account is protected by a PIN",
+    enter_pin_or_scan_card: "Enter the PIN or scan the card",
+    unlock: "Unlock",
+    limit_placeholder: "Global limit",
+    limits_hint: "Only an admin can change the limits and the age",
+    update_user: "Update user",
+    receiver: "Receiver",
+    receiving_user: "Receiving User",
+    send_money_greeting: |nickname| format!("Hello {nickname}, who do you want to send money to?"),
+    monthly_statements: "Monthly statements",
+
+    article: "Article",
+    name: "Name",
+    cost: "Cost",
+    price: "Price",
+    edit: "Edit",
+    barcodes: "Barcodes",
+    add_barcode: "Add Barcode",
+    bundle_components: "Bundle components",
+    quantity: "Quantity",
+    add_component: "Add Component",
+    create_article: "Create Article",
+    update_article: "Update article",
+    search: "Search",
+    search_for_articles: "Search for articles",
+    unknown_article: |id| format!("Article {id}"),
+    item_bought: "Item Bought",
+    you_bought: |article, cost| format!("You bought {article} for {cost}"),
+
+    euros: "Euros",
+    amount: "Amount",
+    description: "Description",
+    undo: "Undo",
+    previous_page: "Previous page",
+    next_page: "Next page",
+    who_are_you: "Who are you?",
+    split_cost_with: "Who do you want to split the cost with?",
+    other_users: "Other users",
+    how_much: "How much?",
+
+    value: "Value",
+    total_spent: "Total spent",
+    average_per_week: "Average per week",
+    spent_per_month: "Spent per month",
+    articles_per_week: "Articles per week",
+    top_articles: "Top articles",
+    top_consumers: "Top consumers",
+    purchases: "Purchases",
+    spent: "Spent",
+    revenue_per_day: "Revenue per day",
+    revenue_per_week: "Revenue per week",
+
+    upload_sounds: "Upload sounds",
+    no_sounds: "There are no sounds yet",
+    plays_for: "Plays for",
+    assigned_articles: "Articles",
+    add_article: "Add article",
+    preview: "Preview",
+    save: "Save",
+    delete: "Delete",
+    event_generic: "Generic",
+    event_failed: "Failed",
+    event_refused: "Refused",
+    event_deposit: "Deposit",
+    event_withdraw: "Withdraw",
+    event_milestone: "Milestone",
+    milestone_reached: "Milestone reached!",
+
+    loading_user: "Loading user",
+    loading_users: "Loading users",
+    loading_article: "Loading article...",
+    loading_articles: "Loading articles",
+    loading_statistics: "Loading statistics",
+    loading_transactions: "Loading transactions",
+    loading_sounds: "Loading sounds",
+
+    an_error_occured: "An error occured",
+    ok: "Ok",
+    invalid_id: |id| format!("Failed to convert id: {id} to a number!"),
+    failed_to_obtain_id: "Failed to obtain id from url",
+    user_not_found: |id| format!("No user with the id {id} has been found!"),
+    no_user_with_barcode: |barcode| format!("There is no user with barcode \"{barcode}\""),
+    no_article_with_barcode: |barcode| {
+        format!("No article could be found with barcode '{barcode}'")
+    },
+    failed_to_add_user: |e| format!("Failed to add user: {e}"),
+    failed_to_update_user: |e| format!("Failed to update user: {e}"),
+    failed_to_fetch_user: |e| format!("Failed to fetch user: {e}"),
+    failed_to_fetch_user_by_barcode: |e| format!("Failed to fetch user by barcode: {e}"),
+    failed_to_load_user: |e| format!("Failed to load user: {e}"),
+    failed_to_fetch_users: "Failed to fetch users!",
+    failed_to_fetch_users_because: |e| format!("Failed to fetch users: {e}"),
+    failed_to_fetch_group_members: |e| format!("Failed to fetch group members: {e}"),
+    failed_to_create_article: |e| format!("Failed to create article: {e}"),
+    failed_to_update_article: |e| format!("Failed to update article: {e}"),
+    failed_to_fetch_article: |e| format!("Failed to fetch article: {e}"),
+    failed_to_fetch_articles: |e| format!("Failed to fetch articles: {e}"),
+    failed_to_fetch_statistics: |e| format!("Failed to fetch statistics: {e}"),
+    failed_to_fetch_transactions: |e| format!("Failed to fetch transactions: {e}"),
+    failed_to_undo_transaction: |e| format!("Failed to undo transaction: {e}"),
+    failed_to_send_money: |e| format!("Failed to send money: {e}"),
+    failed_to_parse_money: |e| format!("Failed to parse money: {e}"),
+    failed_to_get_audio_node: "Failed to get audio node",
+    failed_to_fetch_sound: |e| format!("Failed to fetch sound: {e}"),
+    failed_to_fetch_sounds: |e| format!("Failed to fetch sounds: {e}"),
+    failed_to_upload_sounds: |e| format!("Failed to upload sounds: {e}"),
+    failed_to_update_sound: |e| format!("Failed to update sound: {e}"),
+    failed_to_delete_sound: |e| format!("Failed to delete sound: {e}"),
+    failed_to_fetch_kiosk_settings: |e| format!("Failed to fetch the kiosk settings: {e}"),
+    failed_to_fetch_pin_session: |e| format!("Failed to check the PIN: {e}"),
+    failed_to_unlock: |e| format!("Failed to unlock the account: {e}"),
+    you_have_too_little_money: |limit| format!("You have too little money! ({limit})"),
+    you_have_too_much_money: |limit| format!("You have too much money! ({limit})"),
+    global_limit: |limit| format!("limit {limit}"),
+    personal_limit: |limit| format!("personal limit {limit}"),
+
+    too_little_money: |users| format!("The following users have too little money: {users}"),
+    too_much_money: |users| format!("The following users have too much money: {users}"),
+    failed_to_create_transaction: |e| format!("Failed to create transaction: {e}"),
+    daily_limit_reached: |rule, max| format!("You may only buy {max} of '{rule}' per day!"),
+    adults_only: |rule| format!("'{rule}' is only sold to adults!"),
+    pin_required: "The account is locked, please enter the PIN!",
+    article_does_not_exist: |id| format!("The article with id {id} does not exist!"),
+    user_does_not_exist: |id| format!("The user with id {id} does not exist!"),
+    nickname_does_not_exist: |nickname| format!("User with nickname '{nickname}' does not exist!"),
+    may_not_be_empty: |field| format!("{field} may not be empty"),
+    money_must_be_positive: "Money must be positive",
+
+    amount_is_empty: "The amount is empty",
+    invalid_character: |c| format!("Invalid character '{c}'"),
+    misplaced_sign: "The sign has to be in front of the amount",
+    multiple_decimal_separators: "The amount contains more than one decimal separator",
+    misplaced_thousands_separator: "The thousands separator has to group three digits",
+    too_many_decimal_places: |max| format!("The amount may have at most {max} decimal places"),
+    amount_too_large: "The amount is too large",
+    amount_is_negative: "The amount may not be negative",
+    no_shares: "The amount can't be split between nobody",
+    database_error: |e| format!("Database error: {e}"),
+    server_error: |e| format!("Server error: {e}"),
+};
@@ -0,0 +1,5 @@
+pub mod de;
+pub mod en;
+
+pub use de::*;
+pub use en::*;
@@ -1,6 +1,7 @@
 pub mod behaviour;
 pub mod cachinglayer;
 pub mod component;
+pub mod i18n;
 pub mod model;
 pub mod route;
 pub mod shared;
@@ -1,10 +1,18 @@
+use std::collections::HashMap;
+
 use leptos::prelude::RwSignal;
 
-use crate::{backend::core::User, frontend::model::caching_entry::CachingEntry};
+use crate::{backend::core::User, frontend::model::caching_entry::CachingEntry, model::UserId};
 
 pub type CachingLayerType = RwSignal<CachingLayer>;
 
 #[derive(Default)]
 pub struct CachingLayer {
     pub cached_users: RwSignal<CachingEntry<Vec<User>>>,
+    /// bumped whenever a user changes on the server, causes the user list to be refetched
+    pub users_revision: RwSignal<u64>,
+    /// bumped per user whenever that user changes on the server, causes `get_user` entries to be refetched
+    pub user_revisions: RwSignal<HashMap<UserId, u64>>,
+    /// bumped whenever an article changes on the server
+    pub articles_revision: RwSignal<u64>,
 }
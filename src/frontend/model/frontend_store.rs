@@ -2,13 +2,16 @@ use leptos::prelude::NodeRef;
 use reactive_stores::Store;
 use std::collections::HashMap;
 
-use crate::frontend::model::caching_layer::CachingLayerType;
+use crate::{frontend::model::caching_layer::CachingLayerType, model::DatabaseId};
 
 pub type FrontendStoreType = Store<FrontendStore>;
 
 #[derive(Clone, Debug, Store)]
 pub struct FrontendStore {
-    pub cached_sounds: HashMap<String, String>,
+    /// object urls of the fetched sounds, by sound id
+    pub cached_sounds: HashMap<DatabaseId, String>,
+    /// object url of the last speech rendered by the server, revoked when the next one plays
+    pub speech_url: Option<String>,
     pub audio_ref: NodeRef<leptos::html::Audio>,
     pub cachinglayer: CachingLayerType,
 }
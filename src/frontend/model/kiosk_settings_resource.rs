@@ -0,0 +1,5 @@
+use leptos::prelude::{OnceResource, ServerFnError};
+
+use crate::model::KioskSettings;
+
+pub type KioskSettingsResource = OnceResource<Result<KioskSettings, ServerFnError>>;
@@ -1,6 +1,8 @@
 pub mod caching_entry;
 pub mod caching_layer;
 pub mod frontend_store;
+pub mod kiosk_settings_resource;
 pub mod money_args;
 pub mod scaninput_manager;
+pub mod texts;
 pub mod throw_error;
@@ -1,7 +1,5 @@
 use leptos::prelude::WindowListenerHandle;
 
-pub const SECONDS_UNTIL_INPUT_CLEARED: u8 = 30;
-
 pub struct ScanInputManager {
     pub(crate) entries: Option<Vec<Entry>>,
 }
@@ -0,0 +1,192 @@
+use crate::{
+    frontend::i18n::{DE, EN},
+    model::Locale,
+};
+
+/// All texts shown in the ui, one instance per language lives in frontend::i18n.
+/// Texts with arguments are functions, so every language can place them where it needs to.
+pub struct Texts {
+    // navigation
+    pub article_list: &'static str,
+    pub split_cost: &'static str,
+    pub statistics: &'static str,
+    pub settings: &'static str,
+    pub statements: &'static str,
+    pub send_money: &'static str,
+    pub sounds: &'static str,
+    pub page_not_found: &'static str,
+
+    // users
+    pub create_user: &'static str,
+    pub add_user: &'static str,
+    pub user: &'static str,
+    pub nickname: &'static str,
+    pub card_number: &'static str,
+    pub email: &'static str,
+    pub show_in_stats: &'static str,
+    pub is_minor: &'static str,
+    pub lower_limit: &'static str,
+    pub upper_limit: &'static str,
+    pub pin: &'static str,
+    pub pin_placeholder: &'static str,
+    pub remove_pin: &'static str,
+    pub purchase_sound: &'static str,
+    pub deposit_sound: &'static str,
+    pub default_sound: &'static str,
+    pub account_locked: &'static str,
+    pub enter_pin_or_scan_card: &'static str,
+    pub unlock: &'static str,
+    pub limit_placeholder: &'static str,
+    pub limits_hint: &'static str,
+    pub update_user: &'static str,
+    pub receiver: &'static str,
+    pub receiving_user: &'static str,
+    pub send_money_greeting: fn(&str) -> String,
+    pub monthly_statements: &'static str,
+
+    // articles
+    pub article: &'static str,
+    pub name: &'static str,
+    pub cost: &'static str,
+    pub price: &'static str,
+    pub edit: &'static str,
+    pub barcodes: &'static str,
+    pub add_barcode: &'static str,
+    pub bundle_components: &'static str,
+    pub quantity: &'static str,
+    pub add_component: &'static str,
+    pub create_article: &'static str,
+    pub update_article: &'static str,
+    pub search: &'static str,
+    pub search_for_articles: &'static str,
+    pub unknown_article: fn(&str) -> String,
+    pub item_bought: &'static str,
+    pub you_bought: fn(&str, &str) -> String,
+
+    // transactions
+    pub euros: &'static str,
+    pub amount: &'static str,
+    pub description: &'static str,
+    pub undo: &'static str,
+    pub previous_page: &'static str,
+    pub next_page: &'static str,
+    pub who_are_you: &'static str,
+    pub split_cost_with: &'static str,
+    pub other_users: &'static str,
+    pub how_much: &'static str,
+
+    // statistics
+    pub value: &'static str,
+    pub total_spent: &'static str,
+    pub average_per_week: &'static str,
+    pub spent_per_month: &'static str,
+    pub articles_per_week: &'static str,
+    pub top_articles: &'static str,
+    pub top_consumers: &'static str,
+    pub purchases: &'static str,
+    pub spent: &'static str,
+    pub revenue_per_day: &'static str,
+    pub revenue_per_week: &'static str,
+
+    // sounds
+    pub upload_sounds: &'static str,
+    pub no_sounds: &'static str,
+    pub plays_for: &'static str,
+    pub assigned_articles: &'static str,
+    pub add_article: &'static str,
+    pub preview: &'static str,
+    pub save: &'static str,
+    pub delete: &'static str,
+    pub event_generic: &'static str,
+    pub event_failed: &'static str,
+    pub event_refused: &'static str,
+    pub event_deposit: &'static str,
+    pub event_withdraw: &'static str,
+    pub event_milestone: &'static str,
+    pub milestone_reached: &'static str,
+
+    // loading
+    pub loading_user: &'static str,
+    pub loading_users: &'static str,
+    pub loading_article: &'static str,
+    pub loading_articles: &'static str,
+    pub loading_statistics: &'static str,
+    pub loading_transactions: &'static str,
+    pub loading_sounds: &'static str,
+
+    // errors
+    pub an_error_occured: &'static str,
+    pub ok: &'static str,
+    pub invalid_id: fn(&str) -> String,
+    pub failed_to_obtain_id: &'static str,
+    pub user_not_found: fn(&str) -> String,
+    pub no_user_with_barcode: fn(&str) -> String,
+    pub no_article_with_barcode: fn(&str) -> String,
+    pub failed_to_add_user: fn(&str) -> String,
+    pub failed_to_update_user: fn(&str) -> String,
+    pub failed_to_fetch_user: fn(&str) -> String,
+    pub failed_to_fetch_user_by_barcode: fn(&str) -> String,
+    pub failed_to_load_user: fn(&str) -> String,
+    pub failed_to_fetch_users: &'static str,
+    pub failed_to_fetch_users_because: fn(&str) -> String,
+    pub failed_to_fetch_group_members: fn(&str) -> String,
+    pub failed_to_create_article: fn(&str) -> String,
+    pub failed_to_update_article: fn(&str) -> String,
+    pub failed_to_fetch_article: fn(&str) -> String,
+    pub failed_to_fetch_articles: fn(&str) -> String,
+    pub failed_to_fetch_statistics: fn(&str) -> String,
+    pub failed_to_fetch_transactions: fn(&str) -> String,
+    pub failed_to_undo_transaction: fn(&str) -> String,
+    pub failed_to_send_money: fn(&str) -> String,
+    pub failed_to_parse_money: fn(&str) -> String,
+    pub failed_to_get_audio_node: &'static str,
+    pub failed_to_fetch_sound: fn(&str) -> String,
+    pub failed_to_fetch_sounds: fn(&str) -> String,
+    pub failed_to_upload_sounds: fn(&str) -> String,
+    pub failed_to_update_sound: fn(&str) -> String,
+    pub failed_to_delete_sound: fn(&str) -> String,
+    pub failed_to_fetch_kiosk_settings: fn(&str) -> String,
+    pub failed_to_fetch_pin_session: fn(&str) -> String,
+    pub failed_to_unlock: fn(&str) -> String,
+    pub you_have_too_little_money: fn(&str) -> String,
+    pub you_have_too_much_money: fn(&str) -> String,
+    pub global_limit: fn(&str) -> String,
+    pub personal_limit: fn(&str) -> String,
+
+    // CreateTransactionError and SplitCostError
+    pub too_little_money: fn(&str) -> String,
+    pub too_much_money: fn(&str) -> String,
+    pub failed_to_create_transaction: fn(&str) -> String,
+    pub daily_limit_reached: fn(&str, &str) -> String,
+    pub adults_only: fn(&str) -> String,
+    pub pin_required: &'static str,
+    pub article_does_not_exist: fn(&str) -> String,
+    pub user_does_not_exist: fn(&str) -> String,
+    pub nickname_does_not_exist: fn(&str) -> String,
+    pub may_not_be_empty: fn(&str) -> String,
+    pub money_must_be_positive: &'static str,
+
+    // MoneyParseError
+    pub amount_is_empty: &'static str,
+    pub invalid_character: fn(&str) -> String,
+    pub misplaced_sign: &'static str,
+    pub multiple_decimal_separators: &'static str,
+    pub misplaced_thousands_separator: &'static str,
+    pub too_many_decimal_places: fn(&str) -> String,
+    pub amount_too_large: &'static str,
+
+    // MoneyError
+    pub amount_is_negative: &'static str,
+    pub no_shares: &'static str,
+    pub database_error: fn(&str) -> String,
+    pub server_error: fn(&str) -> String,
+}
+
+impl Texts {
+    pub fn get(locale: Locale) -> &'static Texts {
+        match locale {
+            Locale::En => &EN,
+            Locale::De => &DE,
+        }
+    }
+}
@@ -4,14 +4,23 @@ use thaw::{
     FieldContextProvider, Flex, FlexJustify, Input, InputRule,
 };
 
-use crate::{backend::core::behaviour::article_new::CreateArticle, frontend::shared::throw_error};
+use crate::{
+    backend::core::behaviour::article_new::CreateArticle,
+    frontend::shared::{throw_error, use_texts},
+};
 
 #[component]
 pub fn Create() -> impl IntoView {
+    let texts = use_texts();
+
     let create_article_action = ServerAction::<CreateArticle>::new();
     Effect::new(move || {
         if let Some(Err(e)) = create_article_action.value().get() {
-            throw_error(e);
+            let msg = match e {
+                ServerFnError::ServerError(msg) => msg,
+                _ => e.to_string(),
+            };
+            throw_error((texts.failed_to_create_article)(&msg));
         }
     });
     view! {
@@ -19,13 +28,13 @@ pub fn Create() -> impl IntoView {
             <ActionForm action=create_article_action>
                 <FieldContextProvider>
                     <Flex vertical=true justify=FlexJustify::SpaceEvenly>
-                        <Field label="Name" name="name" required=true>
+                        <Field label=texts.name name="name" required=true>
                             <Input
                                 rules=vec![InputRule::required(true.into())]
                                 autocomplete="off"
                             />
                         </Field>
-                        <Field label="Cost" name="cost" required=true>
+                        <Field label=texts.cost name="cost" required=true>
                             <Input
                                 rules=vec![InputRule::required(true.into())]
                                 autocomplete="off"
@@ -44,7 +53,7 @@ pub fn Create() -> impl IntoView {
                                 }
                             }
                         >
-                            "Create Article"
+                            {texts.create_article}
                         </Button>
                     </Flex>
                 </FieldContextProvider>
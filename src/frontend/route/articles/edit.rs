@@ -2,11 +2,16 @@ use leptos::prelude::*;
 
 use crate::{
     backend::core::behaviour::article_get::get_article,
-    frontend::{component::article::SingleArticleView, shared::throw_error_none_view},
+    frontend::{
+        component::article::SingleArticleView,
+        shared::{throw_error_none_view, use_texts},
+    },
 };
 
 #[component]
 pub fn Edit() -> impl IntoView {
+    let texts = use_texts();
+
     use leptos_router::hooks::use_params_map;
     let params = use_params_map();
     let article_id_string = params
@@ -18,7 +23,7 @@ pub fn Edit() -> impl IntoView {
     let article_id = match article_id {
         Ok(value) => value,
         Err(err) => {
-            return throw_error_none_view(format!("Failed to get id from params map: {err}"));
+            return throw_error_none_view((texts.invalid_id)(&err.to_string()));
         }
     };
 
@@ -26,7 +31,7 @@ pub fn Edit() -> impl IntoView {
 
     view! {
         {move || match article_resource.get() {
-            None => view! { <p class="text-white text-center">"Loading article..."</p> }.into_any(),
+            None => view! { <p class="text-white text-center">{texts.loading_article}</p> }.into_any(),
             Some(value) => {
                 let article = match value {
                     Ok(value) => value,
@@ -35,9 +40,7 @@ pub fn Edit() -> impl IntoView {
                             ServerFnError::ServerError(msg) => msg,
                             _ => e.to_string(),
                         };
-                        return throw_error_none_view(
-                            format!("Failed to load Article: {error_msg}"),
-                        );
+                        return throw_error_none_view((texts.failed_to_fetch_article)(&error_msg));
                     }
                 };
 
@@ -14,16 +14,18 @@ use crate::{
     frontend::{
         component::{icon::clear_filter::ClearFilterIcon, return_to::ReturnTo, user::ShowUsers},
         model::scaninput_manager::ScanInputManager,
-        shared::{throw_error, throw_error_soft},
+        shared::{throw_error, throw_error_soft, use_kiosk_settings, use_texts},
     },
 };
 
 pub const PREFIX_FILTER_NON_ALPHABETIC_VALUE: char = '!';
-const PREFIX_FILTER_CLEAR_TIMEOUT_SEC: u64 = 15;
 const PREFIX_FILTER_NAME: &str = "filter";
 
 #[component]
 pub fn View() -> impl IntoView {
+    let texts = use_texts();
+    let kiosk_settings = use_kiosk_settings();
+
     let create_user_action = ServerAction::<CreateUser>::new();
 
     Effect::new(move || {
@@ -32,7 +34,7 @@ pub fn View() -> impl IntoView {
                 ServerFnError::ServerError(msg) => msg,
                 _ => e.to_string(),
             };
-            throw_error(format!("Failed to add user: {msg}"));
+            throw_error((texts.failed_to_add_user)(&msg));
         }
     });
 
@@ -70,16 +72,14 @@ pub fn View() -> impl IntoView {
                 let user = match get_user_by_barcode(input_string.clone()).await {
                     Ok(user) => user,
                     Err(err) => {
-                        throw_error(format!("Failed to fetch user by barcode: {}", err));
+                        throw_error((texts.failed_to_fetch_user_by_barcode)(&err.to_string()));
                         return;
                     }
                 };
                 match user {
                     Some(user) => found_user_signal.set(Some(user)),
                     None => {
-                        throw_error_soft(format!(
-                            "There is no user with barcode \"{input_string}\""
-                        ));
+                        throw_error_soft((texts.no_user_with_barcode)(&input_string));
                     }
                 };
             });
@@ -90,7 +90,14 @@ pub fn View() -> impl IntoView {
         {move || {
             prefix_filter
                 .get()
-                .map(|_| view! { <ReturnTo route="/" after=PREFIX_FILTER_CLEAR_TIMEOUT_SEC /> })
+                .map(|_| {
+                    view! {
+                        <ReturnTo
+                            route="/"
+                            after=kiosk_settings.get().prefix_filter_clear_timeout_sec
+                        />
+                    }
+                })
         }}
         <div class="grid grid-cols-10 gap-10 py-10 h-screen">
             <div class="col-span-1 pl-5 justify-self-center">
@@ -126,7 +133,7 @@ pub fn View() -> impl IntoView {
                                             }
                                         }
                                     >
-                                        "Create User"
+                                        {texts.create_user}
                                     </Button>
                                 </Flex>
                             </FieldContextProvider>
@@ -1,4 +1,6 @@
 pub mod articles;
 pub mod home;
+pub mod sounds;
 pub mod split_cost;
+pub mod stats;
 pub mod user;
@@ -0,0 +1,138 @@
+use leptos::{html, prelude::*, task::spawn_local, web_sys::FormData};
+use thaw::Spinner;
+
+use crate::{
+    backend::core::{
+        behaviour::{
+            article_get_all::get_all_articles, sound_get_all::get_sounds,
+            sound_upload::upload_sounds,
+        },
+        Article,
+    },
+    frontend::{
+        component::sound::SoundRow,
+        shared::{throw_error, throw_error_none_view, use_texts},
+    },
+};
+
+#[component]
+pub fn Show() -> impl IntoView {
+    let texts = use_texts();
+
+    let sounds_revision = RwSignal::new(0_u64);
+    let sounds_resource = Resource::new(move || sounds_revision.get(), |_| get_sounds());
+
+    let articles_resource = OnceResource::new(get_all_articles(None));
+    let articles_signal = RwSignal::new(Vec::<Article>::new());
+
+    let upload_form_node = NodeRef::<html::Form>::new();
+
+    let on_upload = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+
+        let form = upload_form_node
+            .get()
+            .expect("upload_form should be mounted!");
+
+        let Ok(data) = FormData::new_with_form(&form) else {
+            return;
+        };
+
+        spawn_local(async move {
+            match upload_sounds(data.into()).await {
+                Ok(_) => {
+                    form.reset();
+                    sounds_revision.update(|revision| *revision += 1);
+                }
+                Err(e) => {
+                    let msg = match e {
+                        ServerFnError::ServerError(msg) => msg,
+                        _ => e.to_string(),
+                    };
+                    throw_error((texts.failed_to_upload_sounds)(&msg));
+                }
+            }
+        });
+    };
+
+    view! {
+        {move || {
+            articles_resource
+                .get()
+                .map(|value| {
+                    match value {
+                        Ok(value) => {
+                            articles_signal.set(value);
+                            ().into_any()
+                        }
+                        Err(e) => {
+                            let msg = match e {
+                                ServerFnError::ServerError(msg) => msg,
+                                _ => e.to_string(),
+                            };
+                            throw_error_none_view((texts.failed_to_fetch_articles)(&msg))
+                        }
+                    }
+                })
+        }}
+        <div class="flex flex-col items-center pt-5 gap-10 text-white">
+            <form class="flex gap-5 items-center" node_ref=upload_form_node on:submit=on_upload>
+                <input type="file" name="sounds" accept="audio/*" multiple />
+                <button
+                    type="submit"
+                    class="bg-indigo-700 hover:bg-pink-700 text-white font-bold py-2 px-4 rounded"
+                >
+                    {texts.upload_sounds}
+                </button>
+            </form>
+            <Suspense fallback=move || {
+                view! { <Spinner label=texts.loading_sounds /> }
+            }>
+                {move || {
+                    sounds_resource
+                        .get()
+                        .map(|sounds| {
+                            match sounds {
+                                Err(e) => {
+                                    let msg = match e {
+                                        ServerFnError::ServerError(msg) => msg,
+                                        _ => e.to_string(),
+                                    };
+                                    throw_error_none_view((texts.failed_to_fetch_sounds)(&msg))
+                                }
+                                Ok(sounds) if sounds.is_empty() => {
+                                    view! { <p>{texts.no_sounds}</p> }.into_any()
+                                }
+                                Ok(sounds) => {
+                                    view! {
+                                        <table class="w-full border-collapse border-spacing-5">
+                                            <tr class="bg-black">
+                                                <th class="pl-2">{texts.name}</th>
+                                                <th></th>
+                                                <th class="px-2">{texts.plays_for}</th>
+                                                <th class="px-2">{texts.assigned_articles}</th>
+                                                <th></th>
+                                            </tr>
+                                            {sounds
+                                                .into_iter()
+                                                .map(|sound| {
+                                                    view! {
+                                                        <SoundRow
+                                                            sound
+                                                            articles=articles_signal
+                                                            sounds_revision
+                                                        />
+                                                    }
+                                                })
+                                                .collect_view()}
+                                        </table>
+                                    }
+                                        .into_any()
+                                }
+                            }
+                        })
+                }}
+            </Suspense>
+        </div>
+    }
+}
@@ -10,12 +10,14 @@ use crate::{
         component::{
             multi_user_selection::SelectMultiUser, single_user_selection::SelectSingleUser,
         },
-        shared::throw_error_none_view,
+        shared::{throw_error_none_view, use_texts},
     },
 };
 
 #[component]
 pub fn Show() -> impl IntoView {
+    let texts = use_texts();
+
     let primary_user = RwSignal::new(String::new());
     let secondary_users = RwSignal::new(Vec::<String>::new());
     let money_input = RwSignal::new(String::new());
@@ -34,7 +36,7 @@ pub fn Show() -> impl IntoView {
             )
             .await
             {
-                error_signal.update(|value| *value = e.to_string());
+                error_signal.update(|value| *value = e.localize(texts));
             }
         })
     };
@@ -50,22 +52,22 @@ pub fn Show() -> impl IntoView {
             <Grid cols=2 x_gap=10 y_gap=10>
                 <GridItem>
                     <div class="pt-4">
-                        <SelectSingleUser title=String::from("Who are you?") input=primary_user />
+                        <SelectSingleUser title=String::from(texts.who_are_you) input=primary_user />
                     </div>
                 </GridItem>
                 <GridItem>
                     <div class="pt-4">
                         <SelectMultiUser
-                            title=String::from("Who do you want to split the cost with?")
+                            title=String::from(texts.split_cost_with)
                             users_input=secondary_users
                         />
                     </div>
                 </GridItem>
                 <GridItem>
-                    <Field label="How much?" orientation=FieldOrientation::Horizontal required=true>
+                    <Field label=texts.how_much orientation=FieldOrientation::Horizontal required=true>
                         <Input value=money_input />
                     </Field>
-                    <Field label="Description" orientation=FieldOrientation::Horizontal>
+                    <Field label=texts.description orientation=FieldOrientation::Horizontal>
                         <Input value=description_input />
                     </Field>
                 </GridItem>
@@ -76,7 +78,7 @@ pub fn Show() -> impl IntoView {
                         size=ButtonSize::Large
                         on_click=on_click
                     >
-                        "Split cost"
+                        {texts.split_cost}
                     </Button>
                 </GridItem>
             </Grid>
@@ -0,0 +1,8 @@
+use leptos::prelude::*;
+
+use crate::frontend::component::statistic::GlobalStatisticsView;
+
+#[component]
+pub fn Show() -> impl IntoView {
+    view! { <GlobalStatisticsView /> }
+}
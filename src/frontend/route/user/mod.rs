@@ -1,5 +1,7 @@
 pub mod send_money;
 pub mod settings;
 pub mod show_user;
+pub mod statements;
+pub mod stats;
 
 pub use show_user::*;
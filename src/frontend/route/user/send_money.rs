@@ -9,7 +9,10 @@ use crate::{
     backend::core::behaviour::{
         send_money::SendMoney, user_get::get_user, user_get_all::get_all_users,
     },
-    frontend::shared::{throw_error, throw_error_none_view},
+    frontend::{
+        component::user::PinLock,
+        shared::{throw_error, throw_error_none_view, use_texts},
+    },
     model::UserId,
 };
 
@@ -17,15 +20,15 @@ const USER_SEARCH_LIMIT: usize = 10;
 
 #[component]
 pub fn Show() -> impl IntoView {
+    let texts = use_texts();
+
     let params = use_params_map();
     let user_id_string = params.read_untracked().get("id").unwrap_or_default();
 
     let user_id = match user_id_string.parse::<i64>() {
         Ok(value) => value,
         Err(_e) => {
-            return throw_error_none_view(format!(
-                "Failed to convert id: {user_id_string} to a number!"
-            ));
+            return throw_error_none_view((texts.invalid_id)(&user_id_string));
         }
     };
 
@@ -38,7 +41,7 @@ pub fn Show() -> impl IntoView {
 
     view! {
         <Suspense fallback=move || {
-            view! { <p class="text-white text-center">"Loading user"</p> }
+            view! { <p class="text-white text-center">{texts.loading_user}</p> }
         }>
             {move || {
                 user_resource
@@ -47,14 +50,14 @@ pub fn Show() -> impl IntoView {
                         let user = match user {
                             Ok(value) => value,
                             Err(e) => {
-                                return throw_error_none_view(format!("Failed to fetch user: {e}"));
+                                return throw_error_none_view((texts.failed_to_fetch_user)(&e.to_string()));
                             }
                         };
                         let user = match user {
                             Some(value) => value,
                             None => {
                                 return throw_error_none_view(
-                                    format!("No such user with id '{}' exists!", user_id.0),
+                                    (texts.user_not_found)(&user_id.0.to_string()),
                                 );
                             }
                         };
@@ -82,9 +85,10 @@ pub fn Show() -> impl IntoView {
                                 .take(USER_SEARCH_LIMIT)
                                 .collect()
                         });
+                        let unlocked = RwSignal::new(!user.has_pin);
                         Effect::new(move || {
                             if let Some(Err(e)) = send_money_action.value().get() {
-                                throw_error(format!("Failed to send money: {e}"));
+                                throw_error((texts.failed_to_send_money)(&e.to_string()));
                             }
                         });
 
@@ -96,52 +100,54 @@ pub fn Show() -> impl IntoView {
                                     vertical=true
                                 >
                                     <Label>
-                                        "Hello "{user.nickname}", who do you want to send money to?"
+                                        {(texts.send_money_greeting)(&user.nickname)}
                                     </Label>
-                                    <ActionForm action=send_money_action prop:autocomplete="off">
-                                        <FieldContextProvider>
-                                            <Flex
-                                                vertical=true
-                                                align=FlexAlign::Center
-                                                gap=FlexGap::Medium
-                                            >
-                                                <Field name="to_user" label="Receiver" required=true>
-                                                    <AutoComplete
-                                                        value=selected_user_input
-                                                        placeholder="Receiving User"
-                                                    >
-                                                        <For
-                                                            each=move || options.get()
-                                                            key=|option| option.clone()
-                                                            let:option
+                                    <PinLock user_id unlocked>
+                                        <ActionForm action=send_money_action prop:autocomplete="off">
+                                            <FieldContextProvider>
+                                                <Flex
+                                                    vertical=true
+                                                    align=FlexAlign::Center
+                                                    gap=FlexGap::Medium
+                                                >
+                                                    <Field name="to_user" label=texts.receiver required=true>
+                                                        <AutoComplete
+                                                            value=selected_user_input
+                                                            placeholder=texts.receiving_user
                                                         >
-                                                            <AutoCompleteOption value=option
-                                                                .clone()>{option}</AutoCompleteOption>
-                                                        </For>
-                                                    </AutoComplete>
-                                                </Field>
-                                                <Field name="amount" label="Amount" required=true>
-                                                    <Input value=money_amount_input />
-                                                </Field>
+                                                            <For
+                                                                each=move || options.get()
+                                                                key=|option| option.clone()
+                                                                let:option
+                                                            >
+                                                                <AutoCompleteOption value=option
+                                                                    .clone()>{option}</AutoCompleteOption>
+                                                            </For>
+                                                        </AutoComplete>
+                                                    </Field>
+                                                    <Field name="amount" label=texts.amount required=true>
+                                                        <Input value=money_amount_input />
+                                                    </Field>
 
-                                                <input type="hidden" name="user_id" value=user.id.0 />
+                                                    <input type="hidden" name="user_id" value=user.id.0 />
 
-                                                <Button
-                                                    button_type=ButtonType::Submit
-                                                    on_click={
-                                                        let field_context = FieldContextInjection::expect_context();
-                                                        move |e: ev::MouseEvent| {
-                                                            if !field_context.validate() {
-                                                                e.prevent_default()
+                                                    <Button
+                                                        button_type=ButtonType::Submit
+                                                        on_click={
+                                                            let field_context = FieldContextInjection::expect_context();
+                                                            move |e: ev::MouseEvent| {
+                                                                if !field_context.validate() {
+                                                                    e.prevent_default()
+                                                                }
                                                             }
                                                         }
-                                                    }
-                                                >
-                                                    "Send money"
-                                                </Button>
-                                            </Flex>
-                                        </FieldContextProvider>
-                                    </ActionForm>
+                                                    >
+                                                        {texts.send_money}
+                                                    </Button>
+                                                </Flex>
+                                            </FieldContextProvider>
+                                        </ActionForm>
+                                    </PinLock>
                                 </Flex>
 
                             </div>
@@ -1,8 +1,11 @@
 use crate::{
-    backend::core::behaviour::{update_user::UpdateUser, user_get::get_user},
+    backend::core::{
+        behaviour::{sound_get_all::get_sounds, update_user::UpdateUser, user_get::get_user},
+        Sound,
+    },
     frontend::{
-        component::return_to::ReturnTo, route::user::RETURN_TO_MAIN_VIEW_TIMEOUT_SEC,
-        shared::throw_error_none_view,
+        component::{return_to::ReturnTo, sound::UserSoundSelect, user::PinLock},
+        shared::{current_currency, throw_error_none_view, use_kiosk_settings, use_texts},
     },
     model::UserId,
 };
@@ -13,24 +16,26 @@ use leptos::{
 use leptos_router::hooks::use_params_map;
 use thaw::{
     Button, ButtonSize, ButtonType, Field, FieldContextInjection, FieldContextProvider, Flex,
-    FlexAlign, FlexGap, FlexJustify, Input, InputRule,
+    FlexAlign, FlexGap, FlexJustify, Input, InputRule, InputType,
 };
 
 #[component]
 pub fn Show() -> impl IntoView {
+    let texts = use_texts();
+    let kiosk_settings = use_kiosk_settings();
+
     let params = use_params_map();
     let user_id_string = params.read_untracked().get("id").unwrap_or_default();
 
     let user_id = match user_id_string.parse::<i64>() {
         Ok(user_id) => UserId(user_id),
         Err(_) => {
-            return throw_error_none_view(format!(
-                "Failed to convert id: {user_id_string} to a number!"
-            ));
+            return throw_error_none_view((texts.invalid_id)(&user_id_string));
         }
     };
 
     let user_resource = OnceResource::new(get_user(user_id));
+    let sounds_resource = OnceResource::new(get_sounds());
 
     let update_action = ServerAction::<UpdateUser>::new();
 
@@ -45,9 +50,13 @@ pub fn Show() -> impl IntoView {
         enter_handler.remove();
     });
     view! {
-        <ReturnTo after=RETURN_TO_MAIN_VIEW_TIMEOUT_SEC route="/" />
+        {move || {
+            view! {
+                <ReturnTo after=kiosk_settings.get().return_to_main_view_timeout_sec route="/" />
+            }
+        }}
         <Suspense fallback=move || {
-            view! { <p class="text-white text-center pt-5">"Loading User..."</p> }
+            view! { <p class="text-white text-center pt-5">{texts.loading_user}</p> }
         }>
             {move || {
                 let user = match user_resource.get() {
@@ -59,20 +68,34 @@ pub fn Show() -> impl IntoView {
                 let user = match user {
                     Ok(user) => user,
                     Err(err) => {
-                        return throw_error_none_view(
-                            format!("Failed to fetch user because: {err}"),
-                        );
+                        return throw_error_none_view((texts.failed_to_fetch_user)(&err.to_string()));
                     }
                 };
                 let user = match user {
                     Some(user) => user,
                     None => {
                         return throw_error_none_view(
-                            format!("No user with the id {} has been found!", user_id.0),
+                            (texts.user_not_found)(&user_id.0.to_string()),
                         );
                     }
                 };
 
+                let sounds = match sounds_resource.get() {
+                    Some(Ok(sounds)) => sounds,
+                    Some(Err(e)) => {
+                        let msg = match e {
+                            ServerFnError::ServerError(msg) => msg,
+                            _ => e.to_string(),
+                        };
+                        return throw_error_none_view((texts.failed_to_fetch_sounds)(&msg));
+                    }
+                    None => {
+                        return ().into_any();
+                    }
+                };
+
+                let unlocked = RwSignal::new(!user.has_pin);
+
                 view! {
                     {move || match update_action.value().get() {
                         Some(Err(e)) => {
@@ -80,10 +103,61 @@ pub fn Show() -> impl IntoView {
                                 ServerFnError::ServerError(msg) => msg,
                                 _ => e.to_string(),
                             };
-                            throw_error_none_view(format!("Failed to update user: {msg}"))
+                            throw_error_none_view((texts.failed_to_update_user)(&msg))
                         }
                         _ => ().into_any(),
                     }}
+                    <PinLock user_id unlocked>
+                        <Form user_id sounds=sounds.clone() update_action />
+                    </PinLock>
+                }
+                    .into_any()
+            }}
+        </Suspense>
+    }
+    .into_any()
+}
+
+/// Fetches the user again once the account is unlocked, the card number is only sent then
+#[component]
+fn Form(
+    user_id: UserId,
+    sounds: Vec<Sound>,
+    update_action: ServerAction<UpdateUser>,
+) -> impl IntoView {
+    let texts = use_texts();
+
+    let user_resource = OnceResource::new(get_user(user_id));
+
+    view! {
+        <Suspense>
+            {move || {
+                let user = match user_resource.get() {
+                    Some(Ok(Some(user))) => user,
+                    Some(Ok(None)) => {
+                        return throw_error_none_view(
+                            (texts.user_not_found)(&user_id.0.to_string()),
+                        );
+                    }
+                    Some(Err(err)) => {
+                        return throw_error_none_view((texts.failed_to_fetch_user)(&err.to_string()));
+                    }
+                    None => {
+                        return ().into_any();
+                    }
+                };
+                let sounds = sounds.clone();
+                let currency = current_currency();
+                let lower_limit = user
+                    .lower_limit
+                    .map(|limit| limit.format_amount(&currency))
+                    .unwrap_or_default();
+                let upper_limit = user
+                    .upper_limit
+                    .map(|limit| limit.format_amount(&currency))
+                    .unwrap_or_default();
+
+                view! {
                     <ActionForm action=update_action>
                         <FieldContextProvider>
                             <div class="pt-5">
@@ -93,17 +167,85 @@ pub fn Show() -> impl IntoView {
                                     gap=FlexGap::Medium
                                     vertical=true
                                 >
-                                    <Field label="Nickname" required=true name="nickname">
+                                    <Field label=texts.nickname required=true name="nickname">
                                         <Input
-                                            value=user.nickname
+                                            value=user.nickname.clone()
                                             rules=vec![InputRule::required(true.into())]
                                         />
                                     </Field>
 
-                                    <Field label="Card number" name="card_number">
-                                        <Input value=user.card_number.unwrap_or(String::new()) />
+                                    <Field label=texts.card_number name="card_number">
+                                        <Input value=user.card_number.clone().unwrap_or_default() />
+                                    </Field>
+
+                                    <Field label=texts.email name="email">
+                                        <Input value=user.email.clone().unwrap_or_default() />
                                     </Field>
 
+                                    <Field label=texts.lower_limit>
+                                        <Input
+                                            disabled=true
+                                            value=lower_limit
+                                            placeholder=texts.limit_placeholder
+                                        />
+                                    </Field>
+
+                                    <Field label=texts.upper_limit>
+                                        <Input
+                                            disabled=true
+                                            value=upper_limit
+                                            placeholder=texts.limit_placeholder
+                                        />
+                                    </Field>
+
+                                    <p class="text-white text-sm">{texts.limits_hint}</p>
+
+                                    <label class="text-white flex gap-2 items-center">
+                                        <input
+                                            type="checkbox"
+                                            name="show_in_stats"
+                                            checked=user.show_in_stats
+                                        />
+                                        {texts.show_in_stats}
+                                    </label>
+
+                                    <label class="text-white flex gap-2 items-center">
+                                        <input type="checkbox" disabled checked=user.is_minor />
+                                        {texts.is_minor}
+                                    </label>
+
+                                    <Field label=texts.pin name="pin">
+                                        <Input
+                                            input_type=InputType::Password
+                                            placeholder=texts.pin_placeholder
+                                        />
+                                    </Field>
+
+                                    {user
+                                        .has_pin
+                                        .then(|| {
+                                            view! {
+                                                <label class="text-white flex gap-2 items-center">
+                                                    <input type="checkbox" name="remove_pin" />
+                                                    {texts.remove_pin}
+                                                </label>
+                                            }
+                                        })}
+
+                                    <UserSoundSelect
+                                        label=texts.purchase_sound
+                                        name="purchase_sound"
+                                        sounds=sounds.clone()
+                                        selected=user.purchase_sound_id
+                                    />
+
+                                    <UserSoundSelect
+                                        label=texts.deposit_sound
+                                        name="deposit_sound"
+                                        sounds
+                                        selected=user.deposit_sound_id
+                                    />
+
                                     <input type="hidden" value=user.id.0 name="id" />
 
                                     <Button
@@ -118,7 +260,7 @@ pub fn Show() -> impl IntoView {
                                             }
                                         }
                                     >
-                                        "Update user"
+                                        {texts.update_user}
                                     </Button>
                                 </Flex>
                             </div>
@@ -129,5 +271,4 @@ pub fn Show() -> impl IntoView {
             }}
         </Suspense>
     }
-    .into_any()
 }
@@ -11,9 +11,10 @@ use crate::{
         component::{
             article::buy_article::BuyArticle,
             change_money_button::ChangeMoneyButton,
-            icon::{SendMoneyIcon, SettingsIcon},
+            icon::{SendMoneyIcon, SettingsIcon, StatementIcon, StatsIcon},
             return_to::ReturnTo,
             transaction::ShowTransactions,
+            user::PinLock,
         },
         model::{
             caching_layer::CachingLayer,
@@ -21,22 +22,26 @@ use crate::{
             money_args::MoneyArgs,
             scaninput_manager::ScanInputManager,
         },
-        shared::{buy_article, on_custom_money_button_click, throw_error, throw_error_none_view},
+        shared::{
+            buy_article, current_currency, on_custom_money_button_click, throw_error,
+            throw_error_none_view, unlock_account, use_kiosk_settings, use_texts,
+        },
     },
     model::{Transaction, UserId},
 };
 
-pub const RETURN_TO_MAIN_VIEW_TIMEOUT_SEC: u64 = 15;
-
 #[component]
 pub fn ShowUser() -> impl IntoView {
+    let texts = use_texts();
+    let kiosk_settings = use_kiosk_settings();
+
     let params = use_params_map();
     let user_id_string = params.read_untracked().get("id").unwrap_or_default();
 
     let user_id = match user_id_string.parse::<i64>() {
         Ok(id) => UserId(id),
         Err(e) => {
-            return throw_error_none_view(format!("Failed to convert id to a number: {e}"));
+            return throw_error_none_view((texts.invalid_id)(&e.to_string()));
         }
     };
 
@@ -47,7 +52,11 @@ pub fn ShowUser() -> impl IntoView {
     let entry = CachingLayer::get_user(cache, user_id);
 
     view! {
-        <ReturnTo after=RETURN_TO_MAIN_VIEW_TIMEOUT_SEC route="/" />
+        {move || {
+            view! {
+                <ReturnTo after=kiosk_settings.get().return_to_main_view_timeout_sec route="/" />
+            }
+        }}
         <div>
             {move || {
                 let (is_fetching, value) = {
@@ -55,13 +64,13 @@ pub fn ShowUser() -> impl IntoView {
                     (entry.is_fetching, entry.value)
                 };
                 if is_fetching.get() && value.get().is_none() {
-                    return view! { <Spinner label="Loading user!" /> }.into_any();
+                    return view! { <Spinner label=texts.loading_user /> }.into_any();
                 }
                 let user = match value.get() {
                     Some(user) => user,
                     None => {
                         return throw_error_none_view(
-                            format!("No user with the id {} has been found!", user_id.0),
+                            (texts.user_not_found)(&user_id.0.to_string()),
                         );
                     }
                 };
@@ -74,33 +83,35 @@ pub fn ShowUser() -> impl IntoView {
                 });
                 let custom_money_change = RwSignal::new(String::from_str("0.00").unwrap());
                 let custom_money_is_focused = RwSignal::new(false);
+                let unlocked = RwSignal::new(!user.has_pin);
+                let pin_is_focused = RwSignal::new(false);
                 let toaster = ToasterInjection::expect_context();
                 let scaninput_manager = expect_context::<Store<ScanInputManager>>();
                 scaninput_manager
                     .write()
                     .register(
                         format!("/user/{}", user_id.0),
-                        vec![custom_money_is_focused.read_only()],
+                        vec![custom_money_is_focused.read_only(), pin_is_focused.read_only()],
                         move |scan_input| {
+                            if !unlocked.get_untracked() {
+                                // a locked account can be unlocked with its card
+                                unlock_account(user_id, scan_input, unlocked);
+                                return;
+                            }
+
                             spawn_local(async move {
                                 console_log(&format!("Input {scan_input}"));
                                 let article = get_article_by_barcode(scan_input.clone()).await;
                                 let article = match article {
                                     Ok(value) => value,
                                     Err(e) => {
-                                        throw_error(
-                                            format!("Failed to fetch article from server: {e}"),
-                                        );
+                                        throw_error((texts.failed_to_fetch_article)(&e.to_string()));
                                         return;
                                     }
                                 };
                                 match article {
                                     None => {
-                                        throw_error(
-                                            format!(
-                                                "No article could be found with barcode '{scan_input}'",
-                                            ),
-                                        );
+                                        throw_error((texts.no_article_with_barcode)(&scan_input));
                                     }
                                     Some(value) => {
                                         buy_article(value.id, value.cost, money_args, toaster);
@@ -111,113 +122,138 @@ pub fn ShowUser() -> impl IntoView {
                     );
 
                 view! {
-                    <div class="grid grid-cols-2">
-                        <div class="pt-5">
-                            // left side (show user statistics)
-                            <div class="grid grid-cols-3">
-                                <div class="col-span-2">
-                                    <div class="flex place-content-evenly flex-col gap-[1.5em]">
-                                        <p class="text-center text-white text-[2em]">
-                                            {user.nickname.clone()}
-                                        </p>
-                                        <p
-                                            class="text-center text-[2em]"
-                                            class=(
-                                                "text-red-500",
-                                                move || (money_signal.get()).value < 0,
-                                            )
-                                            class=(
-                                                "text-green-500",
-                                                move || (money_signal.get()).value >= 0,
-                                            )
-                                        >
+                    <PinLock user_id unlocked pin_is_focused>
+                        <div class="grid grid-cols-2">
+                            <div class="pt-5">
+                                // left side (show user statistics)
+                                <div class="grid grid-cols-3">
+                                    <div class="col-span-2">
+                                        <div class="flex place-content-evenly flex-col gap-[1.5em]">
+                                            <p class="text-center text-white text-[2em]">
+                                                {user.nickname.clone()}
+                                            </p>
+                                            <p
+                                                class="text-center text-[2em]"
+                                                class=(
+                                                    "text-red-500",
+                                                    move || (money_signal.get()).value < 0,
+                                                )
+                                                class=(
+                                                    "text-green-500",
+                                                    move || (money_signal.get()).value >= 0,
+                                                )
+                                            >
 
-                                            {move || (money_signal.get()).format_eur_diff()}
-                                        </p>
+                                                {move || (money_signal.get()).format_diff(&current_currency())}
+                                            </p>
+                                        </div>
+                                    </div>
+                                    <div class="col-span-1">
+                                        <div class="flex justify-evenly">
+                                            <a
+                                                href=format!("/user/{}/settings", user_id)
+                                                class="text-white pt-[5px] flex flex-col items-center"
+                                            >
+                                                <SettingsIcon />
+                                                <p class="text-center">{texts.settings}</p>
+                                            </a>
+                                            <a
+                                                href=format!("/user/{}/stats", user_id)
+                                                class="text-white w-[3rem] flex flex-col items-center"
+                                            >
+                                                <StatsIcon />
+                                                <p class="text-center">{texts.statistics}</p>
+                                            </a>
+                                            <a
+                                                href=format!("/user/{}/statements", user_id)
+                                                class="text-white w-[3rem] flex flex-col items-center"
+                                            >
+                                                <StatementIcon />
+                                                <p class="text-center">{texts.statements}</p>
+                                            </a>
+                                            <a
+                                                href=format!("/user/{}/send_money", user_id)
+                                                class="text-white w-[3rem] flex flex-col items-center"
+                                            >
+                                                <SendMoneyIcon />
+                                                <p class="text-center">{texts.send_money}</p>
+                                            </a>
+                                        </div>
                                     </div>
                                 </div>
-                                <div class="col-span-1">
-                                    <div class="flex justify-evenly">
-                                        <a
-                                            href=format!("/user/{}/settings", user_id)
-                                            class="text-white pt-[5px] flex flex-col items-center"
+                                <BuyArticle args=money_args />
+                            </div>
+                            <div>
+                                // right side (put in money)
+                                <div class="flex flex-col gap-3 bg-gray-500 p-3 rounded-[10px]">
+                                    <div class="grid grid-cols-3 gap-5 rounded-[10px]">
+                                        <Suspense>
+                                            {move || {
+                                                kiosk_settings
+                                                    .get()
+                                                    .deposit_buttons
+                                                    .into_iter()
+                                                    .map(|money| {
+                                                        view! { <ChangeMoneyButton money args=money_args /> }
+                                                    })
+                                                    .collect_view()
+                                            }}
+                                        </Suspense>
+                                    </div>
+                                    <div class="grid grid-cols-3 gap-3">
+                                        <Button
+                                            class="bg-red-400 text-white rounded-full p-5"
+                                            on_click=move |_| on_custom_money_button_click(
+                                                false,
+                                                custom_money_change,
+                                                money_args,
+                                            )
                                         >
-                                            <SettingsIcon />
-                                            <p class="text-center">"Settings"</p>
-                                        </a>
-                                        <a
-                                            href=format!("/user/{}/send_money", user_id)
-                                            class="text-white w-[3rem] flex flex-col items-center"
+                                            <div class="pad-5 text-center">"-"</div>
+                                        </Button>
+                                        <Input
+                                            // class="text-center"
+                                            placeholder=texts.euros
+                                            // cannot autofocus, since we might want to scan a barcode
+                                            autofocus=false
+                                            value=custom_money_change
+                                            on:focus=move |_| { custom_money_is_focused.set(true) }
+                                            on:blur=move |_| { custom_money_is_focused.set(false) }
+                                        >// has some stupid border for some reason
+                                        // <InputSuffix slot>"€"</InputSuffix>
+                                        </Input>
+                                        <Button
+                                            class="bg-emerald-600 text-white rounded-full p-5"
+                                            on_click=move |_| on_custom_money_button_click(
+                                                true,
+                                                custom_money_change,
+                                                money_args,
+                                            )
                                         >
-                                            <SendMoneyIcon />
-                                            <p class="text-center">"Send money"</p>
-                                        </a>
+                                            <div class="pad-5 text-center">"+"</div>
+                                        </Button>
+                                    </div>
+                                    <div class="grid grid-cols-3 gap-5 rounded-[10px]">
+                                        <Suspense>
+                                            {move || {
+                                                kiosk_settings
+                                                    .get()
+                                                    .withdraw_buttons
+                                                    .into_iter()
+                                                    .map(|money| {
+                                                        view! { <ChangeMoneyButton money={-money} args=money_args /> }
+                                                    })
+                                                    .collect_view()
+                                            }}
+                                        </Suspense>
                                     </div>
                                 </div>
                             </div>
-                            <BuyArticle args=money_args />
                         </div>
-                        <div>
-                            // right side (put in money)
-                            <div class="flex flex-col gap-3 bg-gray-500 p-3 rounded-[10px]">
-                                <div class="grid grid-cols-3 gap-5 rounded-[10px]">
-                                    <ChangeMoneyButton money=50 args=money_args />
-                                    <ChangeMoneyButton money=100 args=money_args />
-                                    <ChangeMoneyButton money=200 args=money_args />
-                                    <ChangeMoneyButton money=500 args=money_args />
-                                    <ChangeMoneyButton money=1000 args=money_args />
-                                    <ChangeMoneyButton money=2000 args=money_args />
-                                    <ChangeMoneyButton money=5000 args=money_args />
-                                </div>
-                                <div class="grid grid-cols-3 gap-3">
-                                    <Button
-                                        class="bg-red-400 text-white rounded-full p-5"
-                                        on_click=move |_| on_custom_money_button_click(
-                                            false,
-                                            custom_money_change,
-                                            money_args,
-                                        )
-                                    >
-                                        <div class="pad-5 text-center">"-"</div>
-                                    </Button>
-                                    <Input
-                                        // class="text-center"
-                                        placeholder="Euros"
-                                        // cannot autofocus, since we might want to scan a barcode
-                                        autofocus=false
-                                        value=custom_money_change
-                                        on:focus=move |_| { custom_money_is_focused.set(true) }
-                                        on:blur=move |_| { custom_money_is_focused.set(false) }
-                                    >// has some stupid border for some reason
-                                    // <InputSuffix slot>"€"</InputSuffix>
-                                    </Input>
-                                    <Button
-                                        class="bg-emerald-600 text-white rounded-full p-5"
-                                        on_click=move |_| on_custom_money_button_click(
-                                            true,
-                                            custom_money_change,
-                                            money_args,
-                                        )
-                                    >
-                                        <div class="pad-5 text-center">"+"</div>
-                                    </Button>
-                                </div>
-                                <div class="grid grid-cols-3 gap-5 rounded-[10px]">
-                                    <ChangeMoneyButton money=-50 args=money_args />
-                                    <ChangeMoneyButton money=-100 args=money_args />
-                                    <ChangeMoneyButton money=-200 args=money_args />
-                                    <ChangeMoneyButton money=-500 args=money_args />
-                                    <ChangeMoneyButton money=-1000 args=money_args />
-                                    <ChangeMoneyButton money=-2000 args=money_args />
-                                    <ChangeMoneyButton money=-5000 args=money_args />
-
-                                </div>
-                            </div>
+                        <div class="pt-5">
+                            <ShowTransactions arguments=money_args />
                         </div>
-                    </div>
-                    <div class="pt-5">
-                        <ShowTransactions arguments=money_args />
-                    </div>
+                    </PinLock>
                 }
                     .into_any()
             }}
@@ -0,0 +1,27 @@
+use leptos::prelude::*;
+use leptos_router::hooks::use_params_map;
+
+use crate::{
+    frontend::{
+        component::user::StatementList,
+        shared::{throw_error_none_view, use_texts},
+    },
+    model::UserId,
+};
+
+#[component]
+pub fn Show() -> impl IntoView {
+    let texts = use_texts();
+
+    let params = use_params_map();
+    let user_id_string = params.read_untracked().get("id").unwrap_or_default();
+
+    let user_id = match user_id_string.parse::<i64>() {
+        Ok(user_id) => UserId(user_id),
+        Err(_) => {
+            return throw_error_none_view((texts.invalid_id)(&user_id_string));
+        }
+    };
+
+    view! { <StatementList user_id /> }.into_any()
+}
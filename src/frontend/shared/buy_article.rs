@@ -2,7 +2,10 @@ use leptos::{prelude::RwSignal, view};
 use thaw::{Toast, ToastBody, ToastTitle, ToasterInjection};
 
 use crate::{
-    frontend::{model::money_args::MoneyArgs, shared::create_transaction},
+    frontend::{
+        model::money_args::MoneyArgs,
+        shared::{create_transaction, current_currency, use_texts},
+    },
     model::Money,
 };
 
@@ -12,6 +15,7 @@ pub fn buy_article(
     args: RwSignal<MoneyArgs>,
     toaster: ToasterInjection,
 ) {
+    let texts = use_texts();
     create_transaction(
         args,
         money,
@@ -21,10 +25,12 @@ pub fn buy_article(
                 move || {
                     view! {
                         <Toast>
-                            <ToastTitle>"Item Bought"</ToastTitle>
+                            <ToastTitle>{texts.item_bought}</ToastTitle>
                             <ToastBody>
-                                "You bought "{transaction.description}" for "
-                                {transaction.money.format_eur()}
+                                {(texts.you_bought)(
+                                    &transaction.description.unwrap_or_default(),
+                                    &transaction.money.format(&current_currency()),
+                                )}
                             </ToastBody>
                         </Toast>
                     }
@@ -1,4 +1,4 @@
-use leptos::prelude::RwSignal;
+use leptos::{leptos_dom::logging::console_log, prelude::RwSignal};
 
 use crate::{
     frontend::{model::money_args::MoneyArgs, shared::create_transaction},
@@ -11,7 +11,13 @@ pub fn change_money(money: Money, args: RwSignal<MoneyArgs>) {
     let (t_type, money) = if money.value > 0 {
         (TransactionType::Deposit, money)
     } else {
-        (TransactionType::Withdraw, -money)
+        match money.checked_neg() {
+            Ok(money) => (TransactionType::Withdraw, money),
+            Err(e) => {
+                console_log(&format!("Invalid amount: {e}"));
+                return;
+            }
+        }
     };
 
     create_transaction(args, money, t_type, None::<fn(Transaction)>);
@@ -2,13 +2,15 @@ use leptos::{
     leptos_dom::logging::console_log,
     prelude::{GetUntracked, RwSignal, Update, Write},
     reactive::spawn_local,
+    view,
 };
+use thaw::{Toast, ToastBody, ToastIntent, ToastOptions, ToastTitle, ToasterInjection};
 
 use crate::{
     backend::core::behaviour::transaction_create::create_transaction as server_create_transaction,
     frontend::{
         model::money_args::MoneyArgs,
-        shared::{play_sound, throw_error},
+        shared::{play_sound, play_sound_by_id, throw_error, use_texts},
     },
     model::{AudioPlayback, CreateTransactionError, Money, Transaction, TransactionType},
 };
@@ -19,20 +21,25 @@ pub fn create_transaction(
     transaction_type: TransactionType,
     on_success: Option<impl Fn(Transaction) + Send + Sync + 'static>,
 ) {
+    let texts = use_texts();
+    let toaster = ToasterInjection::expect_context();
+
     if (money.value) < 0 {
         console_log("Money may not be negative!");
         return;
     }
 
     spawn_local(async move {
-        match server_create_transaction(user_args.get_untracked().user_id, money, transaction_type)
-            .await
-        {
-            Ok((transaction, user_diff)) => {
-                user_args
-                    .get_untracked()
-                    .money
-                    .update(|money_prev| *money_prev += user_diff);
+        let user_id = user_args.get_untracked().user_id;
+
+        match server_create_transaction(user_id, money, transaction_type).await {
+            Ok((transaction, user_diff, milestones)) => {
+                user_args.get_untracked().money.update(|money_prev| {
+                    match money_prev.checked_add(user_diff) {
+                        Ok(money) => *money_prev = money,
+                        Err(e) => console_log(&format!("Failed to update the balance: {e}")),
+                    }
+                });
 
                 user_args
                     .get_untracked()
@@ -40,14 +47,36 @@ pub fn create_transaction(
                     .write()
                     .insert(0, transaction.clone());
 
-                play_sound(match transaction.t_type {
-                    TransactionType::Bought(id) => AudioPlayback::Bought(id),
-                    TransactionType::Deposit => AudioPlayback::Deposit(transaction.money),
-                    TransactionType::Withdraw => AudioPlayback::Withdraw(transaction.money),
-                    TransactionType::Received(_) => return,
-                    TransactionType::SentAndReceived(_) => return,
-                    TransactionType::Sent(_) => AudioPlayback::Sent(transaction.money),
-                });
+                for milestone in milestones.iter() {
+                    let message = milestone.message.clone();
+                    toaster.dispatch_toast(
+                        move || {
+                            view! {
+                                <Toast>
+                                    <ToastTitle>{texts.milestone_reached}</ToastTitle>
+                                    <ToastBody>{message}</ToastBody>
+                                </Toast>
+                            }
+                        },
+                        ToastOptions::default().with_intent(ToastIntent::Success),
+                    );
+                }
+
+                // a milestone sound replaces the usual one
+                match milestones.iter().find_map(|milestone| milestone.sound_id) {
+                    Some(sound_id) => play_sound_by_id(sound_id),
+                    None => play_sound(
+                        match transaction.t_type {
+                            TransactionType::Bought(id) => AudioPlayback::Bought(id),
+                            TransactionType::Deposit => AudioPlayback::Deposit(transaction.money),
+                            TransactionType::Withdraw => AudioPlayback::Withdraw(transaction.money),
+                            TransactionType::Received(_) => return,
+                            TransactionType::SentAndReceived(_) => return,
+                            TransactionType::Sent(_) => AudioPlayback::Sent(transaction.money),
+                        },
+                        Some(user_id),
+                    ),
+                }
 
                 if let Some(on_success_fn) = on_success {
                     on_success_fn(transaction)
@@ -56,17 +85,31 @@ pub fn create_transaction(
 
             Err(e) => {
                 let msg = match e {
-                    CreateTransactionError::TooLittleMoneyError(_) => {
-                        "You have too little money!".to_string()
-                    }
-                    CreateTransactionError::TooMuchMoneyError(_) => {
-                        "You have too much money!".to_string()
-                    }
+                    CreateTransactionError::TooLittleMoneyError(ref users) => match users.first() {
+                        Some(violation) => {
+                            (texts.you_have_too_little_money)(&violation.localize_limit(texts))
+                        }
+                        None => e.localize(texts),
+                    },
+                    CreateTransactionError::TooMuchMoneyError(ref users) => match users.first() {
+                        Some(violation) => {
+                            (texts.you_have_too_much_money)(&violation.localize_limit(texts))
+                        }
+                        None => e.localize(texts),
+                    },
 
-                    _ => e.to_string(),
+                    _ => e.localize(texts),
                 };
                 throw_error(msg);
-                play_sound(AudioPlayback::Failed);
+                play_sound(
+                    match e {
+                        CreateTransactionError::ConsumptionRuleViolated(_) => {
+                            AudioPlayback::Refused
+                        }
+                        _ => AudioPlayback::Failed,
+                    },
+                    Some(user_id),
+                );
             }
         }
     })
@@ -0,0 +1,53 @@
+use crate::model::Currency;
+
+/// The currency format of the server. The server writes it into data attributes of the html element,
+/// where the client picks it up.
+pub fn current_currency() -> Currency {
+    #[cfg(feature = "ssr")]
+    {
+        use crate::backend::core::ServerState;
+        use leptos::prelude::use_context;
+
+        use_context::<ServerState>()
+            .map(|state| state.settings.load().currency.clone())
+            .unwrap_or_default()
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::model::SymbolPosition;
+
+        thread_local! {
+            static CURRENCY: Currency = {
+                let default = Currency::default();
+                let element = leptos::prelude::document().document_element();
+                let attribute = |name: &str| {
+                    element
+                        .as_ref()
+                        .and_then(|element| element.get_attribute(name))
+                };
+
+                Currency {
+                    symbol: attribute("data-currency-symbol").unwrap_or(default.symbol),
+                    symbol_position: match attribute("data-currency-symbol-position").as_deref() {
+                        Some("before") => SymbolPosition::Before,
+                        Some("after") => SymbolPosition::After,
+                        _ => default.symbol_position,
+                    },
+                    symbol_spacing: attribute("data-currency-symbol-spacing")
+                        .map(|value| value == "true")
+                        .unwrap_or(default.symbol_spacing),
+                    decimal_separator: attribute("data-currency-decimal-separator")
+                        .unwrap_or(default.decimal_separator),
+                    thousands_separator: attribute("data-currency-thousands-separator")
+                        .unwrap_or(default.thousands_separator),
+                    minor_digits: attribute("data-currency-minor-digits")
+                        .and_then(|value| value.parse().ok())
+                        .unwrap_or(default.minor_digits),
+                }
+            };
+        }
+
+        CURRENCY.with(|currency| currency.clone())
+    }
+}
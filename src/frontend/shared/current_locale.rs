@@ -0,0 +1,38 @@
+use crate::model::Locale;
+
+/// The locale of the ui. The server takes it from the config or the Accept-Language header
+/// and writes it into the lang attribute of the html element, where the client picks it up.
+pub fn current_locale() -> Locale {
+    #[cfg(feature = "ssr")]
+    {
+        use crate::backend::core::ServerState;
+        use axum::http::{header, request::Parts};
+        use leptos::prelude::use_context;
+
+        if let Some(locale) =
+            use_context::<ServerState>().and_then(|state| state.settings.load().locale)
+        {
+            return locale;
+        }
+
+        use_context::<Parts>()
+            .and_then(|parts| {
+                let value = parts.headers.get(header::ACCEPT_LANGUAGE)?.to_str().ok()?;
+                Locale::from_accept_language(value)
+            })
+            .unwrap_or_default()
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        thread_local! {
+            static LOCALE: Locale = leptos::prelude::document()
+                .document_element()
+                .and_then(|element| element.get_attribute("lang"))
+                .and_then(|lang| lang.parse().ok())
+                .unwrap_or_default();
+        }
+
+        LOCALE.with(|locale| *locale)
+    }
+}
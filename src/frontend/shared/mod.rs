@@ -1,13 +1,23 @@
 pub mod buy_article;
 pub mod change_money;
 pub mod create_transaction;
+pub mod current_currency;
+pub mod current_locale;
 pub mod on_custom_money_button_clicked;
 pub mod play_sound;
 pub mod throw_error;
+pub mod unlock_account;
+pub mod use_kiosk_settings;
+pub mod use_texts;
 
 pub use buy_article::*;
 pub use change_money::*;
 pub use create_transaction::*;
+pub use current_currency::*;
+pub use current_locale::*;
 pub use on_custom_money_button_clicked::*;
 pub use play_sound::*;
 pub use throw_error::*;
+pub use unlock_account::*;
+pub use use_kiosk_settings::*;
+pub use use_texts::*;
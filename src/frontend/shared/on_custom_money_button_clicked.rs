@@ -3,7 +3,7 @@ use leptos::prelude::*;
 use crate::{
     frontend::{
         model::money_args::MoneyArgs,
-        shared::{change_money, throw_error},
+        shared::{change_money, current_currency, throw_error, use_texts},
     },
     model::Money,
 };
@@ -15,10 +15,11 @@ pub fn on_custom_money_button_click(add: bool, value: RwSignal<String>, args: Rw
         return;
     }
 
-    let mut money: Money = match string.try_into() {
+    let mut money: Money = match (string.as_str(), &current_currency()).try_into() {
         Ok(value) => value,
         Err(e) => {
-            throw_error(format!("Failed to parse money: {e}"));
+            let texts = use_texts();
+            throw_error((texts.failed_to_parse_money)(&e.localize(texts)));
             return;
         }
     };
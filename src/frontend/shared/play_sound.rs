@@ -1,89 +1,156 @@
+use std::future::Future;
+
 use leptos::{
     leptos_dom::logging::console_log,
-    prelude::{expect_context, GetUntracked},
+    prelude::{expect_context, window, GetUntracked, ServerFnError, WithUntracked},
     task::spawn_local,
 };
 use reactive_stores::{Store, StoreField};
 
 use crate::{
-    backend::core::behaviour::get_item_sound::{get_item_sound_data, get_item_sound_name},
+    backend::core::behaviour::get_item_sound::{get_item_sound, get_item_sound_data},
     frontend::{
         model::frontend_store::{FrontendStore, FrontendStoreStoreFields},
-        shared::throw_error,
+        shared::{current_locale, throw_error, use_texts},
     },
-    model::AudioPlayback,
+    model::{AudioPlayback, AudioSource, DatabaseId, UserId},
 };
 
-/*
-   How to use a callback
-
-   let error_callback = Closure::once(Box::new(move |_: web_sys::Event| {
-       // Error playing audio, fall back to TTS
-       tts_play(&tts_text);
-    }));
+/// Plays a sound for the playback, `user_id` is the user it is played for and picks their own sounds
+pub fn play_sound(audio_playback: AudioPlayback, user_id: Option<UserId>) {
+    play(get_item_sound(audio_playback, user_id));
+}
 
-    // Attempt to add the error event listener
-    let _ = audio.add_event_listener_with_callback("error", error_callback.as_ref().unchecked_ref());
-*/
+/// Plays a specific sound, e.g. to preview it or for a milestone
+pub fn play_sound_by_id(sound_id: DatabaseId) {
+    play(async move {
+        Ok(AudioSource::Sound {
+            sound_id,
+            speech: None,
+        })
+    });
+}
 
-pub fn play_sound(audio_playback: AudioPlayback) {
+fn play(source: impl Future<Output = Result<AudioSource, ServerFnError>> + 'static) {
+    let texts = use_texts();
     let store = expect_context::<Store<FrontendStore>>();
     let audio = match store.audio_ref().try_get_untracked() {
         Some(v) => match v.get_untracked() {
             Some(v) => v,
             None => {
-                throw_error("Failed to get audio node");
+                throw_error(texts.failed_to_get_audio_node);
                 return;
             }
         },
         None => {
-            throw_error("Failed to get audio node");
+            throw_error(texts.failed_to_get_audio_node);
             return;
         }
     };
 
-    use leptos::web_sys::{js_sys, Url};
+    use leptos::web_sys::Url;
     spawn_local(async move {
-        let mut cached_sounds = store.cached_sounds().writer().unwrap();
-
-        let audio_file = match get_item_sound_name(audio_playback).await {
+        let source = match source.await {
             Ok(value) => value,
             Err(e) => {
-                throw_error(format!("Failed to fetch sound: {e}"));
+                throw_error((texts.failed_to_fetch_sound)(&e.to_string()));
                 return;
             }
         };
 
-        let url = match cached_sounds.get(&audio_file) {
-            Some(value) => value,
-            None => {
-                let sound = match get_item_sound_data(audio_file.clone()).await {
-                    Ok(value) => value,
-                    Err(e) => {
-                        throw_error(format!("Failed to fetch sound: {e}"));
-                        return;
-                    }
-                };
-
-                let sound: &[u8] = &sound;
-
-                let blob = leptos::web_sys::Blob::new_with_u8_array_sequence(&js_sys::Array::of1(
-                    &js_sys::Uint8Array::from(sound),
-                ))
-                .unwrap();
-
-                let url = Url::create_object_url_with_blob(&blob).unwrap();
+        let url = match source {
+            AudioSource::Speech(text) => {
+                speak(&text);
+                return;
+            }
+            AudioSource::RenderedSpeech(data) => {
+                let url = object_url(&data);
 
-                _ = cached_sounds.insert(audio_file.clone(), url);
+                let previous = store.speech_url().writer().unwrap().replace(url.clone());
+                if let Some(previous) = previous {
+                    _ = Url::revoke_object_url(&previous);
+                }
 
-                cached_sounds.get(&audio_file).unwrap()
+                url
             }
+            AudioSource::Sound { sound_id, speech } => match sound_url(store, sound_id).await {
+                Ok(value) => value,
+                Err(e) => match speech {
+                    Some(text) => {
+                        console_log(&format!("Failed to fetch sound, speaking instead: {e}"));
+                        speak(&text);
+                        return;
+                    }
+                    None => {
+                        throw_error((texts.failed_to_fetch_sound)(&e.to_string()));
+                        return;
+                    }
+                },
+            },
         };
 
-        audio.set_src(url);
+        audio.set_src(&url);
         match audio.play() {
             Ok(_) => {}
             Err(e) => console_log(&format!("Failed to play audio: {e:#?}")),
         }
     });
 }
+
+/// Returns the object url of a stored sound, it is only fetched the first time
+async fn sound_url(
+    store: Store<FrontendStore>,
+    sound_id: DatabaseId,
+) -> Result<String, ServerFnError> {
+    let cached = store
+        .cached_sounds()
+        .with_untracked(|sounds| sounds.get(&sound_id).cloned());
+
+    if let Some(url) = cached {
+        return Ok(url);
+    }
+
+    let url = object_url(&get_item_sound_data(sound_id).await?);
+
+    _ = store
+        .cached_sounds()
+        .writer()
+        .unwrap()
+        .insert(sound_id, url.clone());
+
+    Ok(url)
+}
+
+fn object_url(data: &[u8]) -> String {
+    use leptos::web_sys::{js_sys, Blob, Url};
+
+    let blob =
+        Blob::new_with_u8_array_sequence(&js_sys::Array::of1(&js_sys::Uint8Array::from(data)))
+            .unwrap();
+
+    Url::create_object_url_with_blob(&blob).unwrap()
+}
+
+/// Speaks the text with the speech synthesis of the browser, in the language of the ui
+fn speak(text: &str) {
+    use leptos::web_sys::SpeechSynthesisUtterance;
+
+    let synthesis = match window().speech_synthesis() {
+        Ok(value) => value,
+        Err(e) => {
+            console_log(&format!("Speech synthesis is not available: {e:#?}"));
+            return;
+        }
+    };
+
+    let utterance = match SpeechSynthesisUtterance::new_with_text(text) {
+        Ok(value) => value,
+        Err(e) => {
+            console_log(&format!("Failed to create the utterance: {e:#?}"));
+            return;
+        }
+    };
+
+    utterance.set_lang(current_locale().code());
+    synthesis.speak(&utterance);
+}
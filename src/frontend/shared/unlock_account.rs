@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use leptos::{
+    prelude::{set_timeout, RwSignal, ServerFnError, Set},
+    task::spawn_local,
+};
+
+use crate::{
+    backend::core::behaviour::pin_session_create::create_pin_session,
+    frontend::shared::{play_sound, throw_error, use_texts},
+    model::{AudioPlayback, PinSession, UserId},
+};
+
+/// Sets `unlocked` while the session is valid and locks the account again once it expires
+pub fn lock_after_session(session: PinSession, unlocked: RwSignal<bool>) {
+    unlocked.set(session.is_unlocked());
+
+    if session.pin_required && session.unlocked_for_sec > 0 {
+        set_timeout(
+            move || unlocked.set(false),
+            Duration::from_secs(session.unlocked_for_sec),
+        );
+    }
+}
+
+/// Unlocks the account with the PIN or a scanned card number
+pub fn unlock_account(user_id: UserId, secret: String, unlocked: RwSignal<bool>) {
+    let texts = use_texts();
+
+    spawn_local(async move {
+        match create_pin_session(user_id, secret).await {
+            Ok(session) => lock_after_session(session, unlocked),
+            Err(e) => {
+                let msg = match e {
+                    ServerFnError::ServerError(msg) => msg,
+                    _ => e.to_string(),
+                };
+                throw_error((texts.failed_to_unlock)(&msg));
+                play_sound(AudioPlayback::Failed, Some(user_id));
+            }
+        }
+    });
+}
@@ -0,0 +1,16 @@
+use leptos::prelude::*;
+
+use crate::{
+    frontend::model::kiosk_settings_resource::KioskSettingsResource, model::KioskSettings,
+};
+
+/// The kiosk settings of the server, the defaults until they have been fetched.
+/// Read them inside a Suspense if they change the rendered view, so the server waits for them.
+pub fn use_kiosk_settings() -> Signal<KioskSettings> {
+    let resource = expect_context::<KioskSettingsResource>();
+
+    Signal::derive(move || match resource.get() {
+        Some(Ok(settings)) => settings,
+        _ => KioskSettings::default(),
+    })
+}
@@ -0,0 +1,6 @@
+use crate::frontend::{model::texts::Texts, shared::current_locale};
+
+/// Returns the ui texts in the language of the current locale
+pub fn use_texts() -> &'static Texts {
+    Texts::get(current_locale())
+}
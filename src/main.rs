@@ -2,13 +2,23 @@
 use std::path::PathBuf;
 
 #[cfg(feature = "ssr")]
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
+
+#[cfg(feature = "ssr")]
+use strichliste_rs::{
+    backend::core::{ExportFormat, StatementFormat},
+    model::StatementMonth,
+};
 
 #[cfg(feature = "ssr")]
 #[derive(Parser)]
 struct Args {
-    #[arg(short = 'd', long = "db", help = "The path to the sqlite db")]
-    db_path: std::path::PathBuf,
+    #[arg(
+        short = 'd',
+        long = "db",
+        help = "The path to the sqlite db, required unless checking the config"
+    )]
+    db_path: Option<PathBuf>,
     #[arg(short = 'q', long = "create", help = "Create the database and exit", action = clap::ArgAction::SetTrue)]
     create: bool,
     #[arg(short='v', long, action = clap::ArgAction::Count, help="Sets the verbose level. More v's more output")]
@@ -16,6 +26,275 @@ struct Args {
 
     #[arg(short = 'c', long = "config", help = "The config file to use")]
     config: PathBuf,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Clone, Copy, ValueEnum)]
+enum ExportKind {
+    Transactions,
+    Balances,
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Subcommand)]
+enum Command {
+    /// Export transactions or balances instead of starting the server
+    Export {
+        #[arg(value_enum)]
+        kind: ExportKind,
+        #[arg(long, help = "First day to export (inclusive), format: YYYY-MM-DD")]
+        from: Option<chrono::NaiveDate>,
+        #[arg(
+            long,
+            help = "Last day to export (inclusive), format: YYYY-MM-DD. Balances are taken at the end of this day"
+        )]
+        until: Option<chrono::NaiveDate>,
+        #[arg(long, value_enum, default_value_t = ExportFormat::Csv)]
+        format: ExportFormat,
+        #[arg(short = 'o', long, help = "Write to this file instead of stdout")]
+        output: Option<PathBuf>,
+    },
+    /// Write the monthly statements of all users into a directory
+    Statements {
+        #[arg(
+            long,
+            help = "The month to generate, format: YYYY-MM. Defaults to the last month"
+        )]
+        month: Option<StatementMonth>,
+        #[arg(long, value_enum, default_value_t = StatementFormat::Pdf)]
+        format: StatementFormat,
+        #[arg(short = 'o', long, help = "The directory to write the statements to")]
+        output_dir: PathBuf,
+    },
+    /// Check once for due debt reminders and send them
+    SendReminders,
+    /// Fill an empty database with demo users, articles and months of transactions
+    Seed {
+        #[arg(long, default_value_t = 40, help = "How many users to create")]
+        users: usize,
+        #[arg(
+            long,
+            default_value_t = 6,
+            help = "How many months of transactions to create"
+        )]
+        months: u32,
+        #[arg(
+            long,
+            default_value_t = 0,
+            help = "The seed of the random generator, the same seed creates the same data"
+        )]
+        seed: u64,
+    },
+    /// Write a backup into the configured backup directory
+    Backup,
+    /// Check the config for invalid values and missing sound files and exit
+    CheckConfig,
+    /// Replace the database with a backup after verifying it. The server must not be running
+    Restore {
+        #[arg(help = "The backup to restore, may be gzipped")]
+        file: PathBuf,
+    },
+    /// Set the personal account limits of a user, they can't be changed on the kiosk
+    SetLimits {
+        #[arg(help = "The nickname of the user")]
+        nickname: String,
+        #[arg(
+            long,
+            allow_hyphen_values = true,
+            help = "The lower limit in cents, leave it out for the global one"
+        )]
+        lower_limit: Option<i64>,
+        #[arg(
+            long,
+            help = "The upper limit in cents, leave it out for the global one"
+        )]
+        upper_limit: Option<i64>,
+    },
+    /// Flag a user as under 18 for the consumption rules, it can't be changed on the kiosk
+    SetMinor {
+        #[arg(help = "The nickname of the user")]
+        nickname: String,
+        #[arg(action = clap::ArgAction::Set, help = "Whether the user is under 18")]
+        is_minor: bool,
+    },
+}
+
+#[cfg(feature = "ssr")]
+async fn run_command(
+    db: &tokio::sync::Mutex<strichliste_rs::backend::database::DB>,
+    settings: &strichliste_rs::backend::core::Settings,
+    command: Command,
+) -> Result<(), String> {
+    use strichliste_rs::backend::core::{
+        misc::{
+            create_backup, encode_export, seed_demo_data, send_debt_reminders, Mailer, SeedOptions,
+        },
+        ExportBalance, ExportParams, ExportTransaction, Statement, User,
+    };
+
+    match command {
+        Command::Export {
+            kind,
+            from,
+            until,
+            format,
+            output,
+        } => {
+            let db = db.lock().await;
+            let params = ExportParams {
+                from,
+                until,
+                format,
+            };
+            let (from, until) = params.range();
+
+            let body = match kind {
+                ExportKind::Transactions => {
+                    let transactions = ExportTransaction::get_in_range(&db, from, until)
+                        .await
+                        .map_err(|e| format!("Failed to fetch transactions: {e}"))?;
+                    encode_export(format, &transactions)
+                }
+                ExportKind::Balances => {
+                    let balances = ExportBalance::get_at(&db, until)
+                        .await
+                        .map_err(|e| format!("Failed to fetch balances: {e}"))?;
+                    encode_export(format, &balances)
+                }
+            }
+            .map_err(|e| format!("Failed to encode export: {e}"))?;
+
+            match output {
+                None => print!("{body}"),
+                Some(path) => std::fs::write(&path, body)
+                    .map_err(|e| format!("Failed to write '{}': {e}", path.display()))?,
+            }
+        }
+        Command::Statements {
+            month,
+            format,
+            output_dir,
+        } => {
+            let month = month.unwrap_or(StatementMonth::of(chrono::Utc::now()).previous());
+
+            let statements = Statement::get_all(&*db.lock().await, month)
+                .await
+                .map_err(|e| format!("Failed to create statements: {e}"))?;
+
+            std::fs::create_dir_all(&output_dir).map_err(|e| {
+                format!("Failed to create directory '{}': {e}", output_dir.display())
+            })?;
+
+            for statement in statements.iter() {
+                let path =
+                    output_dir.join(format!("{}.{}", statement.file_name(), format.extension()));
+
+                let body = match format {
+                    StatementFormat::Html => statement.to_html(&settings.currency).into_bytes(),
+                    StatementFormat::Pdf => statement.to_pdf(&settings.currency),
+                };
+
+                std::fs::write(&path, body)
+                    .map_err(|e| format!("Failed to write '{}': {e}", path.display()))?;
+            }
+        }
+        Command::SendReminders => {
+            let (smtp, reminders) = match (&settings.smtp, &settings.reminders) {
+                (Some(smtp), Some(reminders)) => (smtp, reminders),
+                _ => return Err(String::from("smtp and reminders need to be configured")),
+            };
+
+            let mailer = Mailer::new(smtp)?;
+            let sent = send_debt_reminders(db, reminders, &settings.currency, &mailer).await?;
+
+            println!("Sent {sent} debt reminders");
+        }
+        Command::Seed {
+            users,
+            months,
+            seed,
+        } => {
+            let options = SeedOptions {
+                users,
+                months,
+                seed,
+            };
+            let summary = seed_demo_data(&*db.lock().await, settings, options).await?;
+
+            println!("{summary}");
+        }
+        Command::Backup => {
+            let backups = match &settings.backups {
+                Some(backups) => backups,
+                None => return Err(String::from("backups need to be configured")),
+            };
+
+            let path = create_backup(db, backups).await?;
+
+            println!("Wrote backup '{}'", path.display());
+        }
+        Command::SetLimits {
+            nickname,
+            lower_limit,
+            upper_limit,
+        } => {
+            if lower_limit.is_some_and(|limit| limit > 0) {
+                return Err(String::from("The lower limit may not be positive"));
+            }
+
+            // the same as accounts.upper_limit, 0 would refuse every deposit
+            if upper_limit.is_some_and(|limit| limit <= 0) {
+                return Err(String::from("The upper limit has to be positive"));
+            }
+
+            let db = db.lock().await;
+            let mut conn = db
+                .get_conn()
+                .await
+                .map_err(|e| format!("Failed to get a database connection: {e}"))?;
+
+            let mut user = User::get_by_nick(&mut *conn, &nickname)
+                .await
+                .map_err(|e| format!("Failed to fetch the user: {e}"))?
+                .ok_or_else(|| format!("No user with the nickname '{nickname}' exists"))?;
+
+            user.set_limits(
+                &mut *conn,
+                lower_limit.map(Into::into),
+                upper_limit.map(Into::into),
+            )
+            .await
+            .map_err(|e| format!("Failed to set the limits: {e}"))?;
+
+            println!("Set the limits of '{nickname}'");
+        }
+        Command::SetMinor { nickname, is_minor } => {
+            let db = db.lock().await;
+            let mut conn = db
+                .get_conn()
+                .await
+                .map_err(|e| format!("Failed to get a database connection: {e}"))?;
+
+            let mut user = User::get_by_nick(&mut *conn, &nickname)
+                .await
+                .map_err(|e| format!("Failed to fetch the user: {e}"))?
+                .ok_or_else(|| format!("No user with the nickname '{nickname}' exists"))?;
+
+            user.set_is_minor(&mut *conn, is_minor)
+                .await
+                .map_err(|e| format!("Failed to set the minor flag: {e}"))?;
+
+            println!("Set the minor flag of '{nickname}' to {is_minor}");
+        }
+        Command::Restore { .. } | Command::CheckConfig => {
+            unreachable!("runs before the database is opened")
+        }
+    }
+
+    Ok(())
 }
 
 #[cfg(feature = "ssr")]
@@ -24,18 +303,40 @@ async fn main() {
     use std::process::exit;
     use std::sync::Arc;
 
-    use axum::Router;
+    use arc_swap::ArcSwap;
+    use axum::{middleware, routing::get, Extension, Router};
     use leptos::logging::log;
     use leptos::prelude::*;
     use leptos_axum::{generate_route_list, LeptosRoutes};
     use strichliste_rs::app::*;
 
     use strichliste_rs::backend::database::DB;
-    use strichliste_rs::backend::{core::ServerState, core::Settings, core::State};
+    use strichliste_rs::backend::{
+        core::behaviour::debt_reminder_routes::{
+            confirm_opt_out_of_reminders, opt_out_of_reminders,
+        },
+        core::behaviour::event_routes::event_stream,
+        core::behaviour::export_routes::{export_balances, export_transactions},
+        core::behaviour::metrics_routes::{get_metrics, track_server_fn_metrics},
+        core::behaviour::statement_routes::get_statement,
+        core::misc::restore_backup,
+        core::misc::run_backups,
+        core::misc::run_debt_reminders,
+        core::misc::run_mqtt,
+        core::misc::run_settings_reload,
+        core::misc::run_webhooks,
+        core::misc::seed_sounds,
+        core::misc::MetricsLayer,
+        core::EventBus,
+        core::PinSessions,
+        core::ServerState,
+        core::Settings,
+        core::State,
+    };
 
     use tokio::sync::Mutex;
     use tracing::error;
-    use tracing_subscriber::EnvFilter;
+    use tracing_subscriber::{filter::filter_fn, prelude::*, EnvFilter};
 
     let args = Args::parse();
 
@@ -45,36 +346,60 @@ async fn main() {
         _ => "trace",
     };
 
-    tracing_subscriber::fmt()
-        .with_line_number(true)
-        .with_env_filter(EnvFilter::new(level))
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_line_number(true)
+                .with_filter(EnvFilter::new(level)),
+        )
+        .with(MetricsLayer.with_filter(filter_fn(|metadata| metadata.target() == "sqlx::query")))
         .init();
 
-    let settings = match Settings::new(args.config) {
-        Ok(mut settings) => {
-            if settings.accounts.lower_limit > 0 {
-                error!("Failed to parse config: accounts.lower_limit may not be positive!");
-                exit(1);
-            }
+    let config_path = args.config;
 
-            if settings.accounts.upper_limit < 0 {
-                error!("Failed to parse config: accounts.upper_limit may not be negative!");
-                exit(1);
+    let settings = match Settings::load(&config_path) {
+        Ok(settings) => settings,
+        Err(problems) => {
+            for problem in problems.iter() {
+                error!("Invalid config: {problem}");
             }
+            exit(1);
+        }
+    };
 
-            if settings.accounts.upper_limit == 0 {
-                settings.accounts.upper_limit = i64::MAX;
-            }
+    if let Some(Command::CheckConfig) = &args.command {
+        use tracing::info;
 
-            settings
-        }
-        Err(e) => {
-            error!("Failed to parse config: {e}");
+        info!("The config '{}' is valid", config_path.display());
+        exit(0);
+    }
+
+    let path = match args.db_path {
+        Some(value) => value,
+        None => {
+            error!("The path to the database (--db) is required");
             exit(1);
         }
     };
 
-    let path = args.db_path;
+    if let Some(Command::Restore { file }) = &args.command {
+        use tracing::info;
+
+        match restore_backup(&path, file).await {
+            Ok(Some(kept)) => info!(
+                "Restored '{}', the previous database was moved to '{}'",
+                file.display(),
+                kept.display()
+            ),
+            Ok(None) => info!("Restored '{}'", file.display()),
+            Err(e) => {
+                error!("{e}");
+                exit(1);
+            }
+        }
+
+        exit(0);
+    }
 
     let db = match DB::new(path.to_str().unwrap()).await {
         Ok(db) => db,
@@ -92,11 +417,56 @@ async fn main() {
         exit(0);
     }
 
+    if let Some(command) = args.command {
+        let db = Mutex::new(db);
+        let result = run_command(&db, &settings, command).await;
+        db.into_inner().close().await;
+
+        if let Err(e) = result {
+            error!("{e}");
+            exit(1);
+        }
+
+        exit(0);
+    }
+
+    match seed_sounds(&db, &settings.sounds).await {
+        Ok(0) => {}
+        Ok(imported) => {
+            use tracing::info;
+
+            info!("Imported {imported} sounds from the config");
+        }
+        Err(e) => {
+            error!("Failed to import the sounds: {e}");
+            exit(1);
+        }
+    }
+
+    match settings.check_articles(&db).await {
+        Ok(problems) => {
+            use tracing::warn;
+
+            for problem in problems.iter() {
+                warn!("Invalid config: {problem}");
+            }
+        }
+        Err(e) => error!("Failed to check the articles of the config: {e}"),
+    }
+
     let server_state: ServerState = Arc::new(State {
         db: Mutex::new(db),
-        settings,
+        settings: ArcSwap::from_pointee(settings),
+        events: EventBus::new(),
+        pin_sessions: PinSessions::default(),
     });
 
+    tokio::spawn(run_debt_reminders(server_state.clone()));
+    tokio::spawn(run_webhooks(server_state.clone()));
+    tokio::spawn(run_mqtt(server_state.clone()));
+    tokio::spawn(run_backups(server_state.clone()));
+    tokio::spawn(run_settings_reload(server_state.clone(), config_path));
+
     let conf = get_configuration(None).unwrap();
     let addr = conf.leptos_options.site_addr;
     let leptos_options = conf.leptos_options;
@@ -104,6 +474,16 @@ async fn main() {
     let routes = generate_route_list(App);
 
     let app = Router::new()
+        .route("/export/transactions", get(export_transactions))
+        .route("/export/balances", get(export_balances))
+        .route("/statement/{user_id}/{month}", get(get_statement))
+        .route(
+            "/reminders/opt_out/{token}",
+            get(confirm_opt_out_of_reminders).post(opt_out_of_reminders),
+        )
+        .route("/events", get(event_stream))
+        .route("/metrics", get(get_metrics))
+        .layer(Extension(server_state.clone()))
         .leptos_routes_with_context(
             &leptos_options,
             routes,
@@ -114,6 +494,7 @@ async fn main() {
             },
         )
         .fallback(leptos_axum::file_and_error_handler(shell))
+        .layer(middleware::from_fn(track_server_fn_metrics))
         .with_state(leptos_options);
 
     // run our app with hyper
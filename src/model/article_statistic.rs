@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::{DatabaseId, Money};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ArticleStatistic {
+    pub article_id: DatabaseId,
+    pub name: String,
+    pub amount: i64,
+    /// summed up effective prices at the time of purchase
+    pub value: Money,
+}
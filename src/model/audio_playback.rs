@@ -5,6 +5,8 @@ use crate::model::Money;
 #[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub enum AudioPlayback {
     Failed,
+    /// a consumption rule refused the purchase
+    Refused,
     Undo,
     Deposit(Money),
     Sent(Money),
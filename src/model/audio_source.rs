@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::DatabaseId;
+
+/// Where the kiosk takes the audio for an [`AudioPlayback`](crate::model::AudioPlayback) from
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum AudioSource {
+    /// a stored sound, its data is fetched separately so that it can be cached.
+    /// `speech` is spoken by the browser instead if the fetch fails
+    Sound {
+        sound_id: DatabaseId,
+        speech: Option<String>,
+    },
+    /// spoken by the speech synthesis of the browser
+    Speech(String),
+    /// speech rendered by the tts engine of the server, a wav file
+    RenderedSpeech(Vec<u8>),
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::UserId;
+
+/// How a transaction changed the balance of a user. All amounts are in cents.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BalanceChange {
+    pub user_id: UserId,
+    pub nickname: String,
+    pub delta: i64,
+    /// the balance after the transaction
+    pub balance: i64,
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::{Money, UserId};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ConsumerStatistic {
+    pub user_id: UserId,
+    pub nickname: String,
+    pub amount: i64,
+    pub spent: Money,
+}
@@ -0,0 +1,25 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// Why a consumption rule from the config refused a purchase
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ConsumptionRuleViolation {
+    /// the user already bought `max_per_day` articles of the rule today
+    DailyLimitReached { rule: String, max_per_day: i64 },
+    /// the articles of the rule may not be sold to users under 18
+    AdultsOnly { rule: String },
+}
+
+impl Display for ConsumptionRuleViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConsumptionRuleViolation::DailyLimitReached { rule, max_per_day } => {
+                write!(f, "only {max_per_day} of '{rule}' may be bought per day")
+            }
+            ConsumptionRuleViolation::AdultsOnly { rule } => {
+                write!(f, "'{rule}' may only be bought by adults")
+            }
+        }
+    }
+}
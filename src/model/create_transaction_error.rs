@@ -2,15 +2,24 @@ use leptos::prelude::ServerFnErrorErr;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::model::{DatabaseId, UserId};
+use crate::model::{ConsumptionRuleViolation, DatabaseId, LimitViolation, MoneyError, UserId};
 
 #[derive(Error, Debug, Clone, Deserialize, Serialize)]
 pub enum CreateTransactionError {
-    #[error("the following users have too little money: {}", .0.join(", "))]
-    TooLittleMoneyError(Vec<String>),
+    #[error("the following users have too little money: {}", join_violations(.0))]
+    TooLittleMoneyError(Vec<LimitViolation>),
 
-    #[error("the following users have too much money: {}", .0.join(", "))]
-    TooMuchMoneyError(Vec<String>),
+    #[error("the following users have too much money: {}", join_violations(.0))]
+    TooMuchMoneyError(Vec<LimitViolation>),
+
+    #[error("the purchase was refused: {0}")]
+    ConsumptionRuleViolated(ConsumptionRuleViolation),
+
+    #[error("invalid amount: {0}")]
+    MoneyError(MoneyError),
+
+    #[error("The account of user {0} is locked, the PIN is required!")]
+    PinRequired(UserId),
 
     #[error("Failed to create transaction: {0}")]
     StringMessage(String),
@@ -25,6 +34,14 @@ pub enum CreateTransactionError {
     UserDoesNotExist(UserId),
 }
 
+fn join_violations(violations: &[LimitViolation]) -> String {
+    violations
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
 impl CreateTransactionError {
     pub fn new(value: &str) -> Self {
         Self::StringMessage(value.to_string())
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+/// Where the currency symbol is placed relative to the amount
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SymbolPosition {
+    Before,
+    #[default]
+    After,
+}
+
+impl SymbolPosition {
+    pub fn code(&self) -> &'static str {
+        match self {
+            SymbolPosition::Before => "before",
+            SymbolPosition::After => "after",
+        }
+    }
+}
+
+/// How amounts of money are formatted and parsed.
+/// All amounts are stored in minor units (e.g. cents), `minor_digits` says how many of them make up a major unit.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(default)]
+pub struct Currency {
+    pub symbol: String,
+    pub symbol_position: SymbolPosition,
+    /// puts a space between the symbol and the amount, e.g. `12.50 kr`
+    pub symbol_spacing: bool,
+    pub decimal_separator: String,
+    /// groups the major units by thousands, disabled if empty
+    pub thousands_separator: String,
+    pub minor_digits: u32,
+}
+
+impl Default for Currency {
+    fn default() -> Self {
+        Self {
+            symbol: "€".to_string(),
+            symbol_position: SymbolPosition::After,
+            symbol_spacing: false,
+            decimal_separator: ".".to_string(),
+            thousands_separator: String::new(),
+            minor_digits: 2,
+        }
+    }
+}
+
+impl Currency {
+    /// how many minor units make up a major unit, e.g. 100 cents per euro
+    pub fn minor_units(&self) -> i64 {
+        10_i64.pow(self.minor_digits)
+    }
+
+    /// Formats the amount without the symbol, e.g. `-1234.50`
+    pub fn format_amount(&self, value: i64) -> String {
+        let sign = if value < 0 { "-" } else { "" };
+        let minor_units = self.minor_units().unsigned_abs();
+        let major = (value.unsigned_abs() / minor_units).to_string();
+        let minor = value.unsigned_abs() % minor_units;
+
+        let major = match self.thousands_separator.is_empty() {
+            true => major,
+            false => major
+                .as_bytes()
+                .rchunks(3)
+                .rev()
+                .map(|chunk| String::from_utf8_lossy(chunk).to_string())
+                .collect::<Vec<String>>()
+                .join(&self.thousands_separator),
+        };
+
+        match self.minor_digits {
+            0 => format!("{sign}{major}"),
+            digits => format!(
+                "{sign}{major}{}{minor:0width$}",
+                self.decimal_separator,
+                width = digits as usize
+            ),
+        }
+    }
+
+    /// Formats the amount with the symbol, e.g. `-1234.50€`
+    pub fn format(&self, value: i64) -> String {
+        let sign = if value < 0 { "-" } else { "" };
+        let amount = self.format_amount(value.abs());
+        let space = if self.symbol_spacing { " " } else { "" };
+
+        match self.symbol_position {
+            SymbolPosition::Before => format!("{sign}{}{space}{amount}", self.symbol),
+            SymbolPosition::After => format!("{sign}{amount}{space}{}", self.symbol),
+        }
+    }
+
+    /// Like [Currency::format], but positive amounts get a `+`
+    pub fn format_diff(&self, value: i64) -> String {
+        match value > 0 {
+            true => format!("+{}", self.format(value)),
+            false => self.format(value),
+        }
+    }
+}
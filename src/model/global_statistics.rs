@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::{ArticleStatistic, ConsumerStatistic, Money, StatisticPoint};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GlobalStatistics {
+    pub top_articles: Vec<ArticleStatistic>,
+    /// only contains users that opted in
+    pub top_consumers: Vec<ConsumerStatistic>,
+    pub revenue_per_day: Vec<StatisticPoint<Money>>,
+    pub revenue_per_week: Vec<StatisticPoint<Money>>,
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+/// Settings of the kiosk ui, sent to the client by `get_kiosk_settings`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(default)]
+pub struct KioskSettings {
+    /// the amounts of the quick deposit buttons, in minor units
+    pub deposit_buttons: Vec<i64>,
+    /// the amounts of the quick withdraw buttons, in minor units
+    pub withdraw_buttons: Vec<i64>,
+    /// the user view returns to the user list after this many seconds of inactivity
+    pub return_to_main_view_timeout_sec: u64,
+    /// the prefix filter of the user list is cleared after this many seconds of inactivity
+    pub prefix_filter_clear_timeout_sec: u64,
+    /// scanned input is discarded if nothing was typed for this many seconds
+    pub input_clear_timeout_sec: u64,
+}
+
+impl Default for KioskSettings {
+    fn default() -> Self {
+        Self {
+            deposit_buttons: vec![50, 100, 200, 500, 1000, 2000, 5000],
+            withdraw_buttons: vec![50, 100, 200, 500, 1000, 2000, 5000],
+            return_to_main_view_timeout_sec: 15,
+            prefix_filter_clear_timeout_sec: 15,
+            input_clear_timeout_sec: 30,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::Money;
+
+/// A user whose balance would leave the allowed range with a transaction
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct LimitViolation {
+    pub nickname: String,
+    /// the limit that was hit
+    pub limit: Money,
+    /// whether the limit was set for this user, instead of the global one from the config
+    pub personal: bool,
+}
+
+impl Display for LimitViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = if self.personal { "personal" } else { "global" };
+
+        write!(
+            f,
+            "{} ({} limit of {} cents)",
+            self.nickname, kind, self.limit.value
+        )
+    }
+}
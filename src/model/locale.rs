@@ -0,0 +1,59 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// The languages the ui is available in
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    En,
+    De,
+}
+
+impl Locale {
+    /// the language tag, as used in the lang attribute of the html element
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::De => "de",
+        }
+    }
+
+    /// Picks the preferred supported language of an Accept-Language header, e.g. `de-DE,de;q=0.9,en;q=0.8`
+    pub fn from_accept_language(value: &str) -> Option<Self> {
+        let mut languages = value
+            .split(',')
+            .filter_map(|entry| {
+                let mut parts = entry.trim().split(';');
+                let tag = parts.next()?.trim();
+                let quality = parts
+                    .find_map(|part| part.trim().strip_prefix("q="))
+                    .and_then(|quality| quality.parse::<f32>().ok())
+                    .unwrap_or(1.0);
+
+                Some((tag, quality))
+            })
+            .collect::<Vec<(&str, f32)>>();
+
+        // stable, so languages with the same quality keep their order
+        languages.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        languages.into_iter().find_map(|(tag, _)| tag.parse().ok())
+    }
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    /// Accepts language tags like `de`, `de-AT` or `en_US`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let language = s.split(['-', '_']).next().unwrap_or_default();
+
+        match language.to_lowercase().as_str() {
+            "en" => Ok(Locale::En),
+            "de" => Ok(Locale::De),
+            _ => Err(format!("Unsupported locale '{s}'")),
+        }
+    }
+}
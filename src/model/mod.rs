@@ -1,25 +1,63 @@
+pub mod article_statistic;
 pub mod audio_playback;
+pub mod audio_source;
+pub mod balance_change;
+pub mod consumer_statistic;
+pub mod consumption_rule_violation;
 pub mod create_transaction_error;
+pub mod currency;
+pub mod global_statistics;
 pub mod group_id;
+pub mod kiosk_settings;
+pub mod limit_violation;
+pub mod locale;
 pub mod money;
+pub mod money_error;
 pub mod money_parse_error;
 pub mod page;
 pub mod page_request_params;
 pub mod page_response_params;
+pub mod pin_session;
+pub mod reached_milestone;
+pub mod server_event;
+pub mod sound_event;
 pub mod split_cost_error;
+pub mod statement_month;
+pub mod statistic_point;
 pub mod transaction;
+pub mod transaction_kind;
 pub mod user_id;
+pub mod user_statistics;
 
+pub use article_statistic::*;
 pub use audio_playback::*;
+pub use audio_source::*;
+pub use balance_change::*;
+pub use consumer_statistic::*;
+pub use consumption_rule_violation::*;
 pub use create_transaction_error::*;
+pub use currency::*;
+pub use global_statistics::*;
 pub use group_id::*;
+pub use kiosk_settings::*;
+pub use limit_violation::*;
+pub use locale::*;
 pub use money::*;
+pub use money_error::*;
 pub use money_parse_error::*;
 pub use page::*;
 pub use page_request_params::*;
 pub use page_response_params::*;
+pub use pin_session::*;
+pub use reached_milestone::*;
+pub use server_event::*;
+pub use sound_event::*;
 pub use split_cost_error::*;
+pub use statement_month::*;
+pub use statistic_point::*;
 pub use transaction::*;
+pub use transaction_kind::*;
 pub use user_id::*;
+pub use user_statistics::*;
 
 pub type DatabaseId = i64;
@@ -1,7 +1,7 @@
-use std::ops::{AddAssign, Neg, Sub};
-
 use serde::{Deserialize, Serialize};
 
+use crate::model::{Currency, MoneyError};
+
 #[derive(
     Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default,
 )]
@@ -10,54 +10,174 @@ pub struct Money {
 }
 
 impl Money {
-    pub fn format_value(value: i64) -> String {
-        format!("{:.2}", value as f64 / 100.0)
+    pub fn format_amount(&self, currency: &Currency) -> String {
+        currency.format_amount(self.value)
     }
 
-    pub fn format(&self) -> String {
-        Money::format_value(self.value)
+    pub fn format(&self, currency: &Currency) -> String {
+        currency.format(self.value)
     }
 
-    pub fn format_eur_value(value: i64) -> String {
-        format!("{:.2}€", value as f64 / 100.0)
+    pub fn format_diff(&self, currency: &Currency) -> String {
+        currency.format_diff(self.value)
     }
 
-    pub fn format_eur_diff_value(value: i64) -> String {
-        match value > 0 {
-            true => format!("+{}", Money::format_eur_value(value)),
-            false => Money::format_eur_value(value).to_string(),
-        }
-        .to_string()
+    pub fn checked_add(self, rhs: Money) -> Result<Money, MoneyError> {
+        self.value
+            .checked_add(rhs.value)
+            .map(|value| Money { value })
+            .ok_or(MoneyError::Overflow)
     }
 
-    pub fn format_eur(&self) -> String {
-        Money::format_eur_value(self.value)
+    pub fn checked_sub(self, rhs: Money) -> Result<Money, MoneyError> {
+        self.value
+            .checked_sub(rhs.value)
+            .map(|value| Money { value })
+            .ok_or(MoneyError::Overflow)
     }
 
-    pub fn format_eur_diff(&self) -> String {
-        Money::format_eur_diff_value(self.value)
+    pub fn checked_neg(self) -> Result<Money, MoneyError> {
+        self.value
+            .checked_neg()
+            .map(|value| Money { value })
+            .ok_or(MoneyError::Overflow)
     }
-}
-impl Sub for Money {
-    type Output = Self;
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        Money {
-            value: self.value - rhs.value,
+    /// The price of `quantity` pieces
+    pub fn checked_mul(self, quantity: i64) -> Result<Money, MoneyError> {
+        self.value
+            .checked_mul(quantity)
+            .map(|value| Money { value })
+            .ok_or(MoneyError::Overflow)
+    }
+
+    /// Splits the amount into `shares` parts that add up to the amount and differ by at most one minor unit.
+    /// The first parts get the remainder, e.g. 100 cents into 3 shares are 34, 33 and 33 cents.
+    pub fn allocate(self, shares: usize) -> Result<Vec<Money>, MoneyError> {
+        if shares == 0 {
+            return Err(MoneyError::NoShares);
         }
+
+        let count = i64::try_from(shares).map_err(|_| MoneyError::Overflow)?;
+        let share = self.value / count;
+        // has the sign of the amount, so negative amounts are split the same way
+        let remainder = self.value % count;
+        let step = remainder.signum();
+
+        Ok((0..count)
+            .map(|index| Money {
+                value: match index < remainder.abs() {
+                    true => share + step,
+                    false => share,
+                },
+            })
+            .collect())
     }
 }
 
-impl Neg for Money {
-    type Output = Self;
+impl TryFrom<u64> for Money {
+    type Error = MoneyError;
 
-    fn neg(self) -> Self::Output {
-        Money { value: -self.value }
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        i64::try_from(value)
+            .map(|value| Money { value })
+            .map_err(|_| MoneyError::Overflow)
     }
 }
 
-impl AddAssign for Money {
-    fn add_assign(&mut self, rhs: Self) {
-        self.value += rhs.value
+impl TryFrom<Money> for u64 {
+    type Error = MoneyError;
+
+    fn try_from(value: Money) -> Result<Self, Self::Error> {
+        u64::try_from(value.value).map_err(|_| MoneyError::Negative)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::model::{Money, MoneyError};
+
+    fn money(value: i64) -> Money {
+        Money { value }
+    }
+
+    fn values(shares: Vec<Money>) -> Vec<i64> {
+        shares.into_iter().map(|share| share.value).collect()
+    }
+
+    #[test]
+    fn checked_operations() {
+        assert_eq!(money(5).checked_add(money(7)), Ok(money(12)));
+        assert_eq!(money(5).checked_sub(money(7)), Ok(money(-2)));
+        assert_eq!(money(5).checked_neg(), Ok(money(-5)));
+        assert_eq!(money(150).checked_mul(3), Ok(money(450)));
+    }
+
+    #[test]
+    fn checked_operations_detect_overflow() {
+        assert_eq!(
+            money(i64::MAX).checked_add(money(1)),
+            Err(MoneyError::Overflow)
+        );
+        assert_eq!(
+            money(i64::MIN).checked_sub(money(1)),
+            Err(MoneyError::Overflow)
+        );
+        assert_eq!(money(i64::MIN).checked_neg(), Err(MoneyError::Overflow));
+        assert_eq!(
+            money(i64::MAX / 2 + 1).checked_mul(2),
+            Err(MoneyError::Overflow)
+        );
+    }
+
+    #[test]
+    fn converts_from_and_to_u64() {
+        assert_eq!(Money::try_from(500_u64), Ok(money(500)));
+        assert_eq!(Money::try_from(u64::MAX), Err(MoneyError::Overflow));
+        assert_eq!(u64::try_from(money(500)), Ok(500));
+        assert_eq!(u64::try_from(money(-1)), Err(MoneyError::Negative));
+    }
+
+    #[test]
+    fn allocates_remainder_to_first_shares() {
+        assert_eq!(values(money(100).allocate(3).unwrap()), vec![34, 33, 33]);
+        assert_eq!(values(money(101).allocate(3).unwrap()), vec![34, 34, 33]);
+        assert_eq!(values(money(99).allocate(3).unwrap()), vec![33, 33, 33]);
+        assert_eq!(values(money(2).allocate(3).unwrap()), vec![1, 1, 0]);
+        assert_eq!(
+            values(money(-100).allocate(3).unwrap()),
+            vec![-34, -33, -33]
+        );
+        assert_eq!(money(100).allocate(0), Err(MoneyError::NoShares));
+    }
+
+    proptest! {
+        #[test]
+        fn allocation_adds_up(value in any::<i64>(), shares in 1_usize..64) {
+            let allocation = money(value).allocate(shares).unwrap();
+
+            prop_assert_eq!(allocation.len(), shares);
+            prop_assert_eq!(allocation.iter().map(|share| share.value as i128).sum::<i128>(), value as i128);
+
+            let max = allocation.iter().max().unwrap().value;
+            let min = allocation.iter().min().unwrap().value;
+            prop_assert!(max - min <= 1);
+        }
+
+        #[test]
+        fn allocation_is_symmetric(value in -i64::MAX..=i64::MAX, shares in 1_usize..64) {
+            let positive = values(money(value).allocate(shares).unwrap());
+            let negative = values(money(-value).allocate(shares).unwrap());
+
+            prop_assert_eq!(positive.into_iter().map(|share| -share).collect::<Vec<i64>>(), negative);
+        }
+
+        #[test]
+        fn checked_operations_never_panic(a in any::<i64>(), b in any::<i64>()) {
+            prop_assert_eq!(money(a).checked_add(money(b)).ok(), a.checked_add(b).map(money));
+            prop_assert_eq!(money(a).checked_sub(money(b)).ok(), a.checked_sub(b).map(money));
+            prop_assert_eq!(money(a).checked_mul(b).ok(), a.checked_mul(b).map(money));
+        }
     }
 }
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Why an operation on [Money](crate::model::Money) failed
+#[derive(Error, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MoneyError {
+    #[error("the amount doesn't fit into 64 bits")]
+    Overflow,
+
+    #[error("the amount may not be negative")]
+    Negative,
+
+    #[error("money can't be split into zero shares")]
+    NoShares,
+}
@@ -1,16 +1,43 @@
 use serde::{Deserialize, Serialize};
 
+/// Why a string could not be parsed into [Money](crate::model::Money)
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub enum MoneyParseError {
-    InvalidEuros(String),
-    InvalidCents(String),
+    /// nothing but whitespace, a sign or the currency symbol
+    Empty,
+    /// a character that is neither a digit, a separator, a sign nor the currency symbol
+    InvalidCharacter(char),
+    /// the sign is only allowed once and in front of the number
+    MisplacedSign,
+    MultipleDecimalSeparators,
+    /// the thousands separator has to split the major units into groups of three digits
+    MisplacedThousandsSeparator,
+    /// more digits after the decimal separator than the currency has minor units
+    TooManyDecimalPlaces {
+        max: u32,
+    },
+    /// the amount doesn't fit into 64 bits of minor units
+    Overflow,
 }
 
 impl std::fmt::Display for MoneyParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            MoneyParseError::InvalidEuros(err) => write!(f, "Invalid Euros: {err}"),
-            MoneyParseError::InvalidCents(err) => write!(f, "Invalid Cents: {err}"),
+            MoneyParseError::Empty => write!(f, "The amount is empty"),
+            MoneyParseError::InvalidCharacter(c) => write!(f, "Invalid character '{c}'"),
+            MoneyParseError::MisplacedSign => {
+                write!(f, "The sign has to be in front of the amount")
+            }
+            MoneyParseError::MultipleDecimalSeparators => {
+                write!(f, "The amount contains more than one decimal separator")
+            }
+            MoneyParseError::MisplacedThousandsSeparator => {
+                write!(f, "The thousands separator has to group three digits")
+            }
+            MoneyParseError::TooManyDecimalPlaces { max } => {
+                write!(f, "The amount may have at most {max} decimal places")
+            }
+            MoneyParseError::Overflow => write!(f, "The amount is too large"),
         }
     }
 }
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// Whether the current browser may act on behalf of a user
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PinSession {
+    pub pin_required: bool,
+    /// 0 if the account is locked
+    pub unlocked_for_sec: u64,
+}
+
+impl PinSession {
+    pub fn is_unlocked(&self) -> bool {
+        !self.pin_required || self.unlocked_for_sec > 0
+    }
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::DatabaseId;
+
+/// A milestone rule of the config that a transaction fulfilled
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReachedMilestone {
+    pub message: String,
+    /// played instead of the usual sound, `None` if no sound fits
+    pub sound_id: Option<DatabaseId>,
+}
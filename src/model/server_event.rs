@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::{BalanceChange, DatabaseId, TransactionKind, UserId};
+
+/// Changes that are broadcast to every connected client, so cached entries can be refreshed
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerEvent {
+    TransactionCreated {
+        transaction_id: DatabaseId,
+        kind: TransactionKind,
+        /// the bought article, if this was a purchase
+        article_id: Option<DatabaseId>,
+        description: Option<String>,
+        /// the full amount in cents
+        money: i64,
+        /// all affected users, without the system users
+        users: Vec<BalanceChange>,
+    },
+    TransactionUndone {
+        transaction_id: DatabaseId,
+        users: Vec<UserId>,
+    },
+    UserCreated {
+        user_id: UserId,
+        nickname: String,
+    },
+    UserUpdated {
+        user_id: UserId,
+    },
+    /// a transaction moved the balance of a user below `accounts.low_balance_threshold`
+    LowBalance {
+        user_id: UserId,
+        nickname: String,
+        balance: i64,
+    },
+    ArticleUpdated {
+        article_id: DatabaseId,
+    },
+}
+
+impl ServerEvent {
+    pub fn affected_users(&self) -> Vec<UserId> {
+        match self {
+            ServerEvent::TransactionCreated { users, .. } => {
+                users.iter().map(|change| change.user_id).collect()
+            }
+            ServerEvent::TransactionUndone { users, .. } => users.clone(),
+            ServerEvent::UserCreated { user_id, .. } => vec![*user_id],
+            ServerEvent::UserUpdated { user_id } => vec![*user_id],
+            ServerEvent::LowBalance { .. } => Vec::new(),
+            ServerEvent::ArticleUpdated { .. } => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+/// The occasions a sound can be assigned to, besides buying a specific article
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum SoundEvent {
+    /// undo, sending money and articles without an own sound
+    Generic,
+    Failed,
+    /// a consumption rule refused the purchase, falls back to `Failed`
+    Refused,
+    Deposit,
+    Withdraw,
+    /// a milestone rule without an own sound was reached
+    Milestone,
+}
+
+impl SoundEvent {
+    pub const ALL: [SoundEvent; 6] = [
+        SoundEvent::Generic,
+        SoundEvent::Failed,
+        SoundEvent::Refused,
+        SoundEvent::Deposit,
+        SoundEvent::Withdraw,
+        SoundEvent::Milestone,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SoundEvent::Generic => "generic",
+            SoundEvent::Failed => "failed",
+            SoundEvent::Refused => "refused",
+            SoundEvent::Deposit => "deposit",
+            SoundEvent::Withdraw => "withdraw",
+            SoundEvent::Milestone => "milestone",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        SoundEvent::ALL
+            .into_iter()
+            .find(|event| event.name() == name)
+    }
+}
@@ -2,6 +2,8 @@ use leptos::prelude::*;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::model::{CreateTransactionError, MoneyParseError};
+
 #[derive(Error, Debug, Clone, Deserialize, Serialize)]
 pub enum SplitCostError {
     #[error("Server function error: {0}")]
@@ -11,7 +13,7 @@ pub enum SplitCostError {
     DatabaseError(String),
 
     #[error("Failed to parse money: {0}")]
-    MoneyParseError(String),
+    MoneyParseError(MoneyParseError),
 
     #[error("Money error: {0}")]
     MoneyError(String),
@@ -20,7 +22,7 @@ pub enum SplitCostError {
     UserDoesNotExistError(String),
 
     #[error("Failed to create transaction: {0}")]
-    CreateTransactionError(String),
+    CreateTransactionError(CreateTransactionError),
 
     #[error("{0} may not be empty")]
     MayNotBeEmptyError(String),
@@ -0,0 +1,88 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A calendar month, formatted as `YYYY-MM`
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StatementMonth {
+    pub year: i32,
+    pub month: u32,
+}
+
+impl StatementMonth {
+    pub fn of(date: DateTime<Utc>) -> Self {
+        StatementMonth {
+            year: date.year(),
+            month: date.month(),
+        }
+    }
+
+    pub fn previous(&self) -> Self {
+        match self.month {
+            1 => StatementMonth {
+                year: self.year - 1,
+                month: 12,
+            },
+            month => StatementMonth {
+                year: self.year,
+                month: month - 1,
+            },
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self.month {
+            12 => StatementMonth {
+                year: self.year + 1,
+                month: 1,
+            },
+            month => StatementMonth {
+                year: self.year,
+                month: month + 1,
+            },
+        }
+    }
+
+    pub fn first_day(&self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.year, self.month, 1)
+    }
+
+    /// Returns the month as `[first day 00:00, first day of the next month 00:00)` in utc
+    pub fn range(&self) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let from = self.first_day()?.and_hms_opt(0, 0, 0)?.and_utc();
+        let until = self.next().first_day()?.and_hms_opt(0, 0, 0)?.and_utc();
+
+        Some((from, until))
+    }
+}
+
+impl fmt::Display for StatementMonth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}", self.year, self.month)
+    }
+}
+
+impl FromStr for StatementMonth {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, month) = s
+            .split_once('-')
+            .ok_or_else(|| format!("Invalid month '{s}', expected YYYY-MM"))?;
+
+        let year = year
+            .parse::<i32>()
+            .map_err(|e| format!("Invalid year '{year}': {e}"))?;
+
+        let month = month
+            .parse::<u32>()
+            .map_err(|e| format!("Invalid month '{month}': {e}"))?;
+
+        if !(1..=12).contains(&month) {
+            return Err(format!("Invalid month '{month}', expected 1-12"));
+        }
+
+        Ok(StatementMonth { year, month })
+    }
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+/// A single value of a time series, e.g. the revenue of "2025-10-19"
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StatisticPoint<T> {
+    pub label: String,
+    pub value: T,
+}
@@ -42,7 +42,12 @@ pub struct TransactionDelta {
 
 #[cfg(feature = "ssr")]
 impl TransactionDelta {
+    /// The balance after the transaction, saturating at the bounds of i64
     pub fn post_amount(&self) -> i64 {
-        self.amount_pre + self.delta
+        self.amount_pre.saturating_add(self.delta)
+    }
+
+    pub fn checked_post_amount(&self) -> Result<Money, crate::model::MoneyError> {
+        Money::from(self.amount_pre).checked_add(self.delta.into())
     }
 }
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// A coarse classification of a transaction, as seen from the outside
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionKind {
+    Deposit,
+    Withdraw,
+    Purchase,
+    Transfer,
+}
+
+impl TransactionKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TransactionKind::Deposit => "deposit",
+            TransactionKind::Withdraw => "withdraw",
+            TransactionKind::Purchase => "purchase",
+            TransactionKind::Transfer => "transfer",
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::{ArticleStatistic, Money, StatisticPoint};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UserStatistics {
+    pub total_spent: Money,
+    pub average_per_week: Money,
+    pub spent_per_month: Vec<StatisticPoint<Money>>,
+    /// amount of articles per week
+    pub consumption_per_week: Vec<StatisticPoint<i64>>,
+    pub top_articles: Vec<ArticleStatistic>,
+}
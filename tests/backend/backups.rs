@@ -0,0 +1,103 @@
+use std::path::PathBuf;
+
+use chrono::{Duration, TimeZone, Utc};
+use strichliste_rs::backend::{
+    core::misc::{expired_backups, restore_backup},
+    database::DB,
+};
+
+use crate::harness::{settings, Harness};
+
+/// A fresh directory below the system temp directory
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "strichliste-{name}-{}",
+        Utc::now().timestamp_nanos_opt().unwrap()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[tokio::test]
+async fn a_backup_can_be_restored() {
+    let dir = temp_dir("restore");
+    let backup = dir.join("backup.sqlite");
+    let db_path = dir.join("db.sqlite");
+
+    // `vacuum into` opens the copy like the source, so an in-memory database can't be backed up
+    let source = DB::new(dir.join("source.sqlite").to_str().unwrap())
+        .await
+        .unwrap();
+    let harness = Harness {
+        db: source,
+        settings: settings(-5000, Some(10000)),
+    };
+    let alice = harness.user("alice").await;
+    harness.deposit(alice, 1234).await.unwrap();
+
+    harness.db.backup_into(&backup).await.unwrap();
+    assert!(DB::verify_file(&backup).await.is_ok());
+
+    let kept = restore_backup(&db_path, &backup).await.unwrap();
+    assert_eq!(kept, None);
+
+    let restored = DB::new(db_path.to_str().unwrap()).await.unwrap();
+    let restored = Harness {
+        db: restored,
+        settings: harness.settings,
+    };
+    assert_eq!(restored.balance(alice).await, 1234);
+
+    restored.db.close().await;
+    harness.db.close().await;
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn broken_backups_are_not_restored() {
+    let dir = temp_dir("broken");
+    let backup = dir.join("backup.sqlite");
+    let db_path = dir.join("db.sqlite");
+
+    std::fs::write(&backup, "not a database").unwrap();
+    std::fs::write(&db_path, "the current database").unwrap();
+
+    assert!(restore_backup(&db_path, &backup).await.is_err());
+    assert_eq!(
+        std::fs::read_to_string(&db_path).unwrap(),
+        "the current database"
+    );
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn retention_keeps_the_newest_and_one_per_day() {
+    let noon = Utc.with_ymd_and_hms(2025, 6, 10, 12, 0, 0).unwrap();
+    let backup = |hours_ago: i64| {
+        (
+            noon - Duration::hours(hours_ago),
+            PathBuf::from(hours_ago.to_string()),
+        )
+    };
+
+    let backups = vec![
+        backup(0),
+        backup(6),
+        backup(10),
+        backup(24),
+        backup(30),
+        backup(48),
+        backup(72),
+    ];
+
+    assert_eq!(
+        expired_backups(backups.clone(), 2, 0),
+        ["10", "24", "30", "48", "72"].map(PathBuf::from)
+    );
+    assert_eq!(
+        expired_backups(backups.clone(), 1, 3),
+        ["6", "10", "30", "72"].map(PathBuf::from)
+    );
+    assert_eq!(expired_backups(backups, 0, 0).len(), 6);
+}
@@ -0,0 +1,159 @@
+use strichliste_rs::{
+    backend::{
+        core::{Article, ConsumptionRule},
+        database::{DBError, DBGROUP_SNACKBAR_ID},
+    },
+    model::{
+        ConsumptionRuleViolation, CreateTransactionError, DatabaseId, Transaction, TransactionType,
+        UserId,
+    },
+};
+
+use crate::harness::Harness;
+
+fn rule(articles: &[DatabaseId], max_per_day: Option<i64>, adults_only: bool) -> ConsumptionRule {
+    ConsumptionRule {
+        name: String::from("Beer"),
+        articles: articles.to_vec(),
+        max_per_day,
+        adults_only,
+    }
+}
+
+/// Buys the article and checks the rules before committing, like `create_for_user` does
+async fn buy(
+    harness: &Harness,
+    user_id: UserId,
+    article_id: DatabaseId,
+    money: i64,
+) -> Result<DatabaseId, CreateTransactionError> {
+    let group = harness.group(&[user_id]).await;
+    let user = harness.get_user(user_id).await;
+
+    let mut trans = harness.db.get_conn_transaction().await?;
+
+    let (transaction_id, _) = Transaction::create(
+        &mut *trans,
+        group,
+        DBGROUP_SNACKBAR_ID,
+        TransactionType::Bought(article_id),
+        None,
+        money.into(),
+        &harness.settings,
+    )
+    .await?;
+
+    Transaction::check_consumption_rules(
+        &mut *trans,
+        transaction_id,
+        &user,
+        &harness.settings.consumption_rules,
+    )
+    .await?;
+
+    trans.commit().await.map_err(DBError::from)?;
+
+    Ok(transaction_id)
+}
+
+#[tokio::test]
+async fn the_daily_limit_refuses_further_purchases() {
+    let mut harness = Harness::new().await;
+    let alice = harness.user("alice").await;
+    let pils = harness.article("Pils", 150).await;
+    let mate = harness.article("Mate", 150).await;
+
+    harness.settings.consumption_rules = vec![rule(&[pils], Some(2), false)];
+
+    buy(&harness, alice, pils, 150).await.unwrap();
+    buy(&harness, alice, pils, 150).await.unwrap();
+
+    match buy(&harness, alice, pils, 150).await {
+        Err(CreateTransactionError::ConsumptionRuleViolated(
+            ConsumptionRuleViolation::DailyLimitReached { rule, max_per_day },
+        )) => {
+            assert_eq!(rule, "Beer");
+            assert_eq!(max_per_day, 2);
+        }
+        other => panic!("expected the daily limit to be reached, got {other:?}"),
+    }
+
+    // articles outside of the rule are not limited
+    buy(&harness, alice, mate, 150).await.unwrap();
+
+    assert_eq!(harness.balance(alice).await, -450);
+}
+
+#[tokio::test]
+async fn adults_only_articles_are_refused_for_minors() {
+    let mut harness = Harness::new().await;
+    let alice = harness.user("alice").await;
+    let bob = harness.user("bob").await;
+    let pils = harness.article("Pils", 150).await;
+
+    harness.settings.consumption_rules = vec![rule(&[pils], None, true)];
+
+    let mut user = harness.get_user(bob).await;
+    let mut conn = harness.db.get_conn().await.unwrap();
+    user.set_is_minor(&mut *conn, true).await.unwrap();
+    drop(conn);
+
+    buy(&harness, alice, pils, 150).await.unwrap();
+
+    match buy(&harness, bob, pils, 150).await {
+        Err(CreateTransactionError::ConsumptionRuleViolated(
+            ConsumptionRuleViolation::AdultsOnly { rule },
+        )) => assert_eq!(rule, "Beer"),
+        other => panic!("expected the purchase to be refused, got {other:?}"),
+    }
+
+    assert_eq!(harness.balance(alice).await, -150);
+    assert_eq!(harness.balance(bob).await, 0);
+}
+
+#[tokio::test]
+async fn renamed_articles_keep_their_rules() {
+    let mut harness = Harness::new().await;
+    let alice = harness.user("alice").await;
+    let pils = harness.article("Pils", 150).await;
+
+    harness.settings.consumption_rules = vec![rule(&[pils], Some(1), false)];
+
+    let mut conn = harness.db.get_conn().await.unwrap();
+    let mut article = Article::get_single(&mut *conn, pils)
+        .await
+        .unwrap()
+        .expect("the article should exist");
+    article
+        .set_name(&mut *conn, String::from("Bier"))
+        .await
+        .unwrap();
+    drop(conn);
+
+    buy(&harness, alice, pils, 150).await.unwrap();
+
+    assert!(matches!(
+        buy(&harness, alice, pils, 150).await,
+        Err(CreateTransactionError::ConsumptionRuleViolated(
+            ConsumptionRuleViolation::DailyLimitReached { .. }
+        ))
+    ));
+}
+
+#[tokio::test]
+async fn rules_with_unknown_articles_are_reported() {
+    let mut harness = Harness::new().await;
+    let pils = harness.article("Pils", 150).await;
+
+    harness.settings.consumption_rules = vec![rule(&[pils, pils + 100], Some(1), false)];
+
+    let problems = harness.settings.check_articles(&harness.db).await.unwrap();
+
+    assert_eq!(
+        problems,
+        vec![format!(
+            "the consumption rule 'Beer' names the article {} that does not exist",
+            pils + 100
+        )]
+    );
+}
@@ -0,0 +1,223 @@
+use chrono::{DateTime, Utc};
+use config::{Config, File, FileFormat};
+use strichliste_rs::{
+    backend::{
+        core::{Article, Group, Settings, User},
+        database::{DBError, TransactionDB, DB, DBGROUP_AUFLADUNG_ID, DBGROUP_SNACKBAR_ID},
+    },
+    model::{
+        CreateTransactionError, DatabaseId, GroupId, Money, Transaction, TransactionType, UserId,
+    },
+};
+
+/// The same settings as config_example.yaml, the limits are in cents, no upper limit if it is `None`
+pub fn settings(lower_limit: i64, upper_limit: Option<i64>) -> Settings {
+    let upper_limit = upper_limit
+        .map(|limit| format!("upper_limit: {limit}"))
+        .unwrap_or_default();
+
+    let yaml = format!(
+        "
+accounts:
+  {upper_limit}
+  lower_limit: {lower_limit}
+sounds:
+  failed: [failed.wav]
+  deposit: [deposit.wav]
+  withdraw: [withdraw.wav]
+  generic: [generic.wav]
+  articles: {{}}
+"
+    );
+
+    Config::builder()
+        .add_source(File::from_str(&yaml, FileFormat::Yaml))
+        .build()
+        .and_then(Config::try_deserialize)
+        .expect("the settings fixture should be valid")
+}
+
+/// An in-memory database with all migrations applied
+pub struct Harness {
+    pub db: DB,
+    pub settings: Settings,
+}
+
+impl Harness {
+    /// Accounts may go down to -50.00 and up to 100.00
+    pub async fn new() -> Self {
+        Self::with_limits(-5000, Some(10000)).await
+    }
+
+    pub async fn with_limits(lower_limit: i64, upper_limit: Option<i64>) -> Self {
+        Self {
+            db: DB::new_in_memory()
+                .await
+                .expect("the database should be created"),
+            settings: settings(lower_limit, upper_limit),
+        }
+    }
+
+    pub async fn user(&self, nickname: &str) -> UserId {
+        User::create(&self.db, nickname.to_string(), None)
+            .await
+            .expect("the user should be created")
+    }
+
+    pub async fn get_user(&self, user_id: UserId) -> User {
+        let mut conn = self.db.get_conn().await.unwrap();
+
+        User::get(&mut *conn, user_id)
+            .await
+            .unwrap()
+            .expect("the user should exist")
+    }
+
+    pub async fn balance(&self, user_id: UserId) -> i64 {
+        self.get_user(user_id).await.money.value
+    }
+
+    pub async fn article(&self, name: &str, cost: i64) -> DatabaseId {
+        Article::new(&self.db, name.to_string(), cost.into())
+            .await
+            .expect("the article should be created")
+            .id
+    }
+
+    pub async fn group(&self, users: &[UserId]) -> GroupId {
+        let mut conn = self.db.get_conn().await.unwrap();
+
+        match users {
+            [user] => Group::get_user_group_id(&mut *conn, *user).await,
+            users => Group::get_group_id_for_multiple_users(&mut *conn, users).await,
+        }
+        .expect("the group should exist")
+    }
+
+    /// Creates the transaction like the server functions do, nothing is stored if it fails
+    pub async fn create(
+        &self,
+        sender: GroupId,
+        receiver: GroupId,
+        t_type: TransactionType,
+        money: i64,
+    ) -> Result<DatabaseId, CreateTransactionError> {
+        let mut trans = self.db.get_conn_transaction().await?;
+
+        let (transaction_id, _) = Transaction::create(
+            &mut *trans,
+            sender,
+            receiver,
+            t_type,
+            None,
+            money.into(),
+            &self.settings,
+        )
+        .await?;
+
+        trans.commit().await.map_err(DBError::from)?;
+
+        Ok(transaction_id)
+    }
+
+    pub async fn deposit(
+        &self,
+        user_id: UserId,
+        money: i64,
+    ) -> Result<DatabaseId, CreateTransactionError> {
+        let group = self.group(&[user_id]).await;
+
+        self.create(DBGROUP_AUFLADUNG_ID, group, TransactionType::Deposit, money)
+            .await
+    }
+
+    pub async fn withdraw(
+        &self,
+        user_id: UserId,
+        money: i64,
+    ) -> Result<DatabaseId, CreateTransactionError> {
+        let group = self.group(&[user_id]).await;
+
+        self.create(
+            group,
+            DBGROUP_AUFLADUNG_ID,
+            TransactionType::Withdraw,
+            money,
+        )
+        .await
+    }
+
+    pub async fn buy(
+        &self,
+        user_id: UserId,
+        article_id: DatabaseId,
+        money: i64,
+    ) -> Result<DatabaseId, CreateTransactionError> {
+        let group = self.group(&[user_id]).await;
+
+        self.create(
+            group,
+            DBGROUP_SNACKBAR_ID,
+            TransactionType::Bought(article_id),
+            money,
+        )
+        .await
+    }
+
+    pub async fn split(
+        &self,
+        primary_user: UserId,
+        secondary_users: &[UserId],
+        money: i64,
+    ) -> Result<DatabaseId, CreateTransactionError> {
+        let mut trans = self.db.get_conn_transaction().await?;
+
+        let (transaction_id, _, _) = Transaction::split_cost(
+            &mut *trans,
+            primary_user,
+            secondary_users,
+            Money::from(money),
+            None,
+            &self.settings,
+        )
+        .await?;
+
+        trans.commit().await.map_err(DBError::from)?;
+
+        Ok(transaction_id)
+    }
+
+    pub async fn undo(&self, transaction_id: DatabaseId) {
+        let mut trans = self.db.get_conn_transaction().await.unwrap();
+
+        let transaction_db = TransactionDB::get(&mut *trans, transaction_id)
+            .await
+            .unwrap()
+            .expect("the transaction should exist");
+
+        _ = Transaction::undo(&mut *trans, &transaction_db)
+            .await
+            .expect("the transaction should be undone");
+
+        trans.commit().await.unwrap();
+    }
+
+    /// Moves the transaction to the given point in time
+    pub async fn set_timestamp(&self, transaction_id: DatabaseId, timestamp: DateTime<Utc>) {
+        let mut conn = self.db.get_conn().await.unwrap();
+
+        TransactionDB::set_timestamp(&mut *conn, transaction_id, timestamp)
+            .await
+            .unwrap();
+    }
+
+    pub async fn is_undone(&self, transaction_id: DatabaseId) -> bool {
+        let mut conn = self.db.get_conn().await.unwrap();
+
+        TransactionDB::get(&mut *conn, transaction_id)
+            .await
+            .unwrap()
+            .expect("the transaction should exist")
+            .is_undone
+    }
+}
@@ -0,0 +1,123 @@
+use strichliste_rs::model::{CreateTransactionError, Money, TransactionType};
+
+use crate::harness::Harness;
+
+#[tokio::test]
+async fn deposits_above_the_upper_limit_are_refused() {
+    let harness = Harness::new().await;
+    let alice = harness.user("alice").await;
+
+    harness.deposit(alice, 10000).await.unwrap();
+
+    match harness.deposit(alice, 1).await {
+        Err(CreateTransactionError::TooMuchMoneyError(violations)) => {
+            assert_eq!(violations.len(), 1);
+            assert_eq!(violations[0].nickname, "alice");
+            assert_eq!(violations[0].limit, Money::from(10000));
+            assert!(!violations[0].personal);
+        }
+        other => panic!("expected the upper limit to be hit, got {other:?}"),
+    }
+
+    assert_eq!(harness.balance(alice).await, 10000);
+}
+
+#[tokio::test]
+async fn purchases_below_the_lower_limit_are_refused() {
+    let harness = Harness::new().await;
+    let alice = harness.user("alice").await;
+    let article = harness.article("Mate", 150).await;
+
+    harness.buy(alice, article, 4950).await.unwrap();
+
+    match harness.buy(alice, article, 150).await {
+        Err(CreateTransactionError::TooLittleMoneyError(violations)) => {
+            assert_eq!(violations.len(), 1);
+            assert_eq!(violations[0].limit, Money::from(-5000));
+        }
+        other => panic!("expected the lower limit to be hit, got {other:?}"),
+    }
+
+    assert_eq!(harness.balance(alice).await, -4950);
+}
+
+#[tokio::test]
+async fn moving_back_towards_the_limits_is_allowed() {
+    let harness = Harness::with_limits(0, Some(0)).await;
+    let alice = harness.user("alice").await;
+
+    // a balance outside of the limits, e.g. after the limits were tightened
+    let mut user = harness.get_user(alice).await;
+    let mut conn = harness.db.get_conn().await.unwrap();
+    user.set_money(&mut *conn, 500).await.unwrap();
+    drop(conn);
+
+    harness.withdraw(alice, 200).await.unwrap();
+
+    assert_eq!(harness.balance(alice).await, 300);
+}
+
+#[tokio::test]
+async fn personal_limits_take_precedence() {
+    let harness = Harness::new().await;
+    let alice = harness.user("alice").await;
+    let bob = harness.user("bob").await;
+
+    let mut user = harness.get_user(alice).await;
+    let mut conn = harness.db.get_conn().await.unwrap();
+    user.set_limits(
+        &mut *conn,
+        Some(Money::from(-1000)),
+        Some(Money::from(20000)),
+    )
+    .await
+    .unwrap();
+    drop(conn);
+
+    harness.deposit(alice, 15000).await.unwrap();
+
+    match harness.deposit(bob, 15000).await {
+        Err(CreateTransactionError::TooMuchMoneyError(violations)) => {
+            assert!(!violations[0].personal);
+        }
+        other => panic!("expected the global limit to be hit, got {other:?}"),
+    }
+
+    harness.withdraw(alice, 16000).await.unwrap();
+
+    match harness.withdraw(alice, 1).await {
+        Err(CreateTransactionError::TooLittleMoneyError(violations)) => {
+            assert_eq!(violations[0].limit, Money::from(-1000));
+            assert!(violations[0].personal);
+        }
+        other => panic!("expected the personal limit to be hit, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn every_member_of_a_group_is_checked() {
+    let harness = Harness::new().await;
+    let alice = harness.user("alice").await;
+    let bob = harness.user("bob").await;
+    let carol = harness.user("carol").await;
+
+    harness.withdraw(bob, 4900).await.unwrap();
+
+    let senders = harness.group(&[alice, bob]).await;
+    let receiver = harness.group(&[carol]).await;
+
+    match harness
+        .create(senders, receiver, TransactionType::Sent(receiver), 400)
+        .await
+    {
+        Err(CreateTransactionError::TooLittleMoneyError(violations)) => {
+            assert_eq!(violations.len(), 1);
+            assert_eq!(violations[0].nickname, "bob");
+        }
+        other => panic!("expected bob to hit the lower limit, got {other:?}"),
+    }
+
+    assert_eq!(harness.balance(alice).await, 0);
+    assert_eq!(harness.balance(bob).await, -4900);
+    assert_eq!(harness.balance(carol).await, 0);
+}
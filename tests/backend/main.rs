@@ -0,0 +1,18 @@
+//! Runs the core behaviours against an in-memory database with the real migrations.
+//! Needs the `ssr` feature: `cargo test --features ssr`
+
+mod backups;
+mod consumption_rules;
+mod harness;
+mod limits;
+mod milestones;
+mod pins;
+mod prices;
+mod seed;
+mod settings;
+mod sounds;
+mod speech;
+mod splits;
+mod statements;
+mod undo;
+mod webhooks;
@@ -0,0 +1,168 @@
+use strichliste_rs::{
+    backend::{
+        core::{MilestoneRule, MilestoneTrigger, Sound},
+        database::{DBGROUP_AUFLADUNG_ID, DBGROUP_SNACKBAR_ID},
+    },
+    model::{ReachedMilestone, SoundEvent, Transaction, TransactionType, UserId},
+};
+
+use crate::harness::Harness;
+
+fn rule(message: &str, trigger: MilestoneTrigger, sound: Option<&str>) -> MilestoneRule {
+    MilestoneRule {
+        message: message.to_string(),
+        trigger,
+        sound: sound.map(str::to_string),
+    }
+}
+
+/// Creates the transaction and checks the rules afterwards, like `create_for_user` does
+async fn reached(
+    harness: &Harness,
+    user_id: UserId,
+    t_type: TransactionType,
+    money: i64,
+    rules: &[MilestoneRule],
+) -> Vec<ReachedMilestone> {
+    let group = harness.group(&[user_id]).await;
+    let (sender, receiver) = match t_type {
+        TransactionType::Deposit => (DBGROUP_AUFLADUNG_ID, group),
+        _ => (group, DBGROUP_SNACKBAR_ID),
+    };
+
+    let mut trans = harness.db.get_conn_transaction().await.unwrap();
+
+    let (transaction_id, deltas) = Transaction::create(
+        &mut *trans,
+        sender,
+        receiver,
+        t_type,
+        None,
+        money.into(),
+        &harness.settings,
+    )
+    .await
+    .unwrap();
+
+    trans.commit().await.unwrap();
+
+    let delta = deltas
+        .iter()
+        .find(|(user, _)| user.id == user_id)
+        .map(|(_, delta)| delta)
+        .expect("the user should be part of the transaction");
+
+    let mut conn = harness.db.get_conn().await.unwrap();
+
+    Transaction::check_milestones(&mut *conn, transaction_id, t_type, user_id, delta, rules)
+        .await
+        .unwrap()
+}
+
+fn messages(reached: &[ReachedMilestone]) -> Vec<&str> {
+    reached.iter().map(|elem| elem.message.as_str()).collect()
+}
+
+#[tokio::test]
+async fn every_nth_purchase_of_an_article_is_a_milestone() {
+    let harness = Harness::new().await;
+    let alice = harness.user("alice").await;
+    let bob = harness.user("bob").await;
+    let mate = harness.article("Mate", 150).await;
+    let cola = harness.article("Cola", 150).await;
+
+    let rules = [rule(
+        "Your {count}th Mate!",
+        MilestoneTrigger::ArticleCount {
+            article: String::from("Mate"),
+            every: 2,
+        },
+        None,
+    )];
+
+    let bought = TransactionType::Bought(mate);
+
+    assert!(reached(&harness, alice, bought, 150, &rules)
+        .await
+        .is_empty());
+    // purchases of other users and articles do not count
+    assert!(reached(&harness, bob, bought, 150, &rules).await.is_empty());
+    assert!(
+        reached(&harness, alice, TransactionType::Bought(cola), 150, &rules)
+            .await
+            .is_empty()
+    );
+
+    let second = reached(&harness, alice, bought, 150, &rules).await;
+    assert_eq!(messages(&second), vec!["Your 2th Mate!"]);
+    assert_eq!(second[0].sound_id, None);
+
+    assert!(reached(&harness, alice, bought, 150, &rules)
+        .await
+        .is_empty());
+}
+
+#[tokio::test]
+async fn deposits_and_balances_reach_milestones() {
+    let harness = Harness::new().await;
+    let alice = harness.user("alice").await;
+    let mate = harness.article("Mate", 150).await;
+
+    let rules = [
+        rule("First deposit", MilestoneTrigger::FirstDepositOfMonth, None),
+        rule("Back above zero", MilestoneTrigger::BalanceAboveZero, None),
+    ];
+
+    assert!(
+        reached(&harness, alice, TransactionType::Bought(mate), 150, &rules)
+            .await
+            .is_empty()
+    );
+
+    let first = reached(&harness, alice, TransactionType::Deposit, 500, &rules).await;
+    assert_eq!(messages(&first), vec!["First deposit", "Back above zero"]);
+
+    // the balance was not negative before
+    let second = reached(&harness, alice, TransactionType::Deposit, 500, &rules).await;
+    assert!(second.is_empty());
+}
+
+#[tokio::test]
+async fn milestones_play_the_named_or_a_milestone_sound() {
+    let harness = Harness::new().await;
+    let alice = harness.user("alice").await;
+
+    let ids = Sound::create_all(
+        &harness.db,
+        &[
+            (String::from("fanfare.wav"), vec![1]),
+            (String::from("tada.wav"), vec![2]),
+        ],
+    )
+    .await
+    .unwrap();
+    let (fanfare, tada) = (ids[0], ids[1]);
+
+    Sound::set_assignments(&harness.db, tada, &[SoundEvent::Milestone], &[])
+        .await
+        .unwrap();
+
+    let rules = [
+        rule(
+            "Named",
+            MilestoneTrigger::FirstDepositOfMonth,
+            Some("fanfare.wav"),
+        ),
+        rule("Any", MilestoneTrigger::FirstDepositOfMonth, None),
+        rule(
+            "Unknown",
+            MilestoneTrigger::FirstDepositOfMonth,
+            Some("missing.wav"),
+        ),
+    ];
+
+    let reached = reached(&harness, alice, TransactionType::Deposit, 500, &rules).await;
+    let sounds: Vec<_> = reached.iter().map(|elem| elem.sound_id).collect();
+
+    assert_eq!(sounds, vec![Some(fanfare), Some(tada), Some(tada)]);
+}
@@ -0,0 +1,36 @@
+use strichliste_rs::{
+    backend::core::{PinSessions, MAX_PIN_ATTEMPTS},
+    model::UserId,
+};
+
+#[test]
+fn too_many_attempts_lock_the_user_out() {
+    let sessions = PinSessions::default();
+    let alice = UserId(1);
+    let bob = UserId(2);
+
+    for _ in 0..MAX_PIN_ATTEMPTS {
+        assert_eq!(sessions.start_attempt(alice), None);
+    }
+
+    assert!(sessions.start_attempt(alice).is_some());
+
+    // other users are not affected
+    assert_eq!(sessions.start_attempt(bob), None);
+}
+
+#[test]
+fn unlocking_resets_the_attempts() {
+    let sessions = PinSessions::default();
+    let alice = UserId(1);
+
+    for _ in 0..MAX_PIN_ATTEMPTS - 1 {
+        assert_eq!(sessions.start_attempt(alice), None);
+    }
+
+    sessions.reset_attempts(alice);
+
+    for _ in 0..MAX_PIN_ATTEMPTS {
+        assert_eq!(sessions.start_attempt(alice), None);
+    }
+}
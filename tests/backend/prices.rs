@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use chrono::Utc;
+use strichliste_rs::backend::{
+    core::{misc::EffectivePriceCache, Article},
+    database::ArticleDB,
+};
+
+use crate::harness::Harness;
+
+/// Price changes are stored with the current time, so the tests have to wait between them
+async fn tick() {
+    tokio::time::sleep(Duration::from_millis(10)).await;
+}
+
+#[tokio::test]
+async fn the_price_at_the_time_of_the_purchase_is_used() {
+    let harness = Harness::new().await;
+
+    let before_creation = Utc::now();
+    tick().await;
+    let article_id = harness.article("Mate", 150).await;
+    tick().await;
+    let before_change = Utc::now();
+    tick().await;
+
+    let mut conn = harness.db.get_conn().await.unwrap();
+    ArticleDB::set_price(&mut *conn, article_id, 200)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        ArticleDB::get_effective_cost(&mut *conn, article_id, before_change)
+            .await
+            .unwrap(),
+        150
+    );
+    assert_eq!(
+        ArticleDB::get_effective_cost(&mut *conn, article_id, Utc::now())
+            .await
+            .unwrap(),
+        200
+    );
+    assert!(
+        ArticleDB::get_effective_cost(&mut *conn, article_id, before_creation)
+            .await
+            .is_err()
+    );
+
+    let mut prices = EffectivePriceCache::default();
+
+    assert_eq!(
+        prices
+            .get(&mut *conn, article_id, before_change)
+            .await
+            .unwrap(),
+        150
+    );
+    assert_eq!(
+        prices
+            .get(&mut *conn, article_id, Utc::now())
+            .await
+            .unwrap(),
+        200
+    );
+    // older transactions fall back to the first known price
+    assert_eq!(
+        prices
+            .get(&mut *conn, article_id, before_creation)
+            .await
+            .unwrap(),
+        150
+    );
+
+    drop(conn);
+
+    let article = Article::get(&harness.db, article_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(article.cost.value, 200);
+}
@@ -0,0 +1,70 @@
+use strichliste_rs::backend::core::{
+    misc::{seed_demo_data, SeedOptions},
+    Article, User,
+};
+
+use crate::harness::Harness;
+
+fn options() -> SeedOptions {
+    SeedOptions {
+        users: 5,
+        months: 1,
+        seed: 7,
+    }
+}
+
+#[tokio::test]
+async fn seeding_stays_within_the_limits() {
+    let harness = Harness::new().await;
+
+    let summary = seed_demo_data(&harness.db, &harness.settings, options())
+        .await
+        .unwrap();
+
+    assert_eq!(summary.users, 5);
+    assert!(summary.transactions > 0);
+
+    let users = User::get_all(&harness.db).await.unwrap();
+    assert_eq!(users.len(), 5);
+
+    for user in users.iter() {
+        assert!((-5000..=10000).contains(&user.money.value));
+    }
+
+    let articles = Article::get_all(&harness.db, None).await.unwrap();
+    assert_eq!(articles.len(), summary.articles);
+}
+
+#[tokio::test]
+async fn the_same_seed_creates_the_same_balances() {
+    let first = Harness::new().await;
+    let second = Harness::new().await;
+
+    for harness in [&first, &second] {
+        seed_demo_data(&harness.db, &harness.settings, options())
+            .await
+            .unwrap();
+    }
+
+    let balances = |users: Vec<User>| {
+        users
+            .into_iter()
+            .map(|user| (user.nickname, user.money.value))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        balances(User::get_all(&first.db).await.unwrap()),
+        balances(User::get_all(&second.db).await.unwrap())
+    );
+}
+
+#[tokio::test]
+async fn seeding_refuses_a_database_with_users() {
+    let harness = Harness::new().await;
+    harness.user("alice").await;
+
+    assert!(seed_demo_data(&harness.db, &harness.settings, options())
+        .await
+        .is_err());
+}
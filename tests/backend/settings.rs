@@ -0,0 +1,51 @@
+use strichliste_rs::backend::core::Settings;
+
+use crate::harness::settings;
+
+#[test]
+fn invalid_values_and_missing_sounds_are_listed() {
+    let problems = settings(100, Some(-1)).validate();
+
+    assert!(problems.contains(&"accounts.lower_limit may not be positive".to_string()));
+    assert!(problems.contains(&"accounts.upper_limit may not be negative".to_string()));
+    assert!(problems.contains(&"the sound file 'failed.wav' does not exist".to_string()));
+}
+
+#[test]
+fn an_upper_limit_of_zero_is_reported() {
+    let problems = settings(0, Some(0)).validate();
+
+    assert!(problems.contains(
+        &"accounts.upper_limit of 0 refuses every deposit, leave it out for no upper limit"
+            .to_string()
+    ));
+}
+
+#[test]
+fn config_files_without_an_extension_are_read_as_yaml() {
+    let path = std::env::temp_dir().join(format!(
+        "strichliste-config-{}",
+        chrono::Utc::now().timestamp_nanos_opt().unwrap()
+    ));
+
+    std::fs::write(
+        &path,
+        "
+accounts:
+  lower_limit: -5000
+sounds:
+  failed: [Cargo.toml]
+  deposit: [Cargo.toml]
+  withdraw: [Cargo.toml]
+  generic: [Cargo.toml]
+  articles: {}
+",
+    )
+    .unwrap();
+
+    let settings = Settings::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(settings.accounts.lower_limit, -5000);
+    assert_eq!(settings.accounts.upper_limit, None);
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use strichliste_rs::{
+    backend::core::{misc::seed_sounds, Sound, SoundSettings},
+    model::{AudioPlayback, DatabaseId, SoundEvent},
+};
+
+use crate::harness::Harness;
+
+/// Any existing files do, the sounds are not decoded
+fn sound_settings() -> SoundSettings {
+    SoundSettings {
+        articles: HashMap::from([
+            (String::from("Mate"), vec![String::from("Cargo.toml")]),
+            (String::from("Missing"), vec![String::from("Cargo.toml")]),
+        ]),
+        generic: vec![String::from("Cargo.toml"), String::from("README.md")],
+        failed: vec![String::from("LICENSE")],
+        refused: Vec::new(),
+        withdraw: vec![String::from("LICENSE")],
+        deposit: vec![String::from("README.md")],
+    }
+}
+
+fn id_of(sounds: &[Sound], name: &str) -> DatabaseId {
+    sounds
+        .iter()
+        .find(|sound| sound.name == name)
+        .expect("the sound should exist")
+        .id
+}
+
+async fn choose(harness: &Harness, audio: AudioPlayback) -> Option<DatabaseId> {
+    Sound::choose_for(&harness.db, audio, None).await.unwrap()
+}
+
+#[tokio::test]
+async fn config_sounds_are_imported_once() {
+    let harness = Harness::new().await;
+    let mate = harness.article("Mate", 150).await;
+
+    assert_eq!(seed_sounds(&harness.db, &sound_settings()).await, Ok(3));
+    assert_eq!(seed_sounds(&harness.db, &sound_settings()).await, Ok(0));
+
+    let sounds = Sound::get_all(&harness.db).await.unwrap();
+    assert_eq!(sounds.len(), 3);
+
+    let cargo = sounds
+        .iter()
+        .find(|sound| sound.name == "Cargo.toml")
+        .unwrap();
+    assert_eq!(cargo.events, vec![SoundEvent::Generic]);
+    assert_eq!(cargo.article_ids, vec![mate]);
+
+    let license = sounds.iter().find(|sound| sound.name == "LICENSE").unwrap();
+    assert!(license.events.contains(&SoundEvent::Failed));
+    assert!(license.events.contains(&SoundEvent::Withdraw));
+    assert!(license.article_ids.is_empty());
+}
+
+#[tokio::test]
+async fn playback_falls_back_to_generic_and_failed_sounds() {
+    let harness = Harness::new().await;
+    let mate = harness.article("Mate", 150).await;
+    let cola = harness.article("Cola", 150).await;
+
+    seed_sounds(&harness.db, &sound_settings()).await.unwrap();
+
+    let sounds = Sound::get_all(&harness.db).await.unwrap();
+    let cargo = id_of(&sounds, "Cargo.toml");
+    let readme = id_of(&sounds, "README.md");
+    let license = id_of(&sounds, "LICENSE");
+
+    assert_eq!(
+        choose(&harness, AudioPlayback::Bought(mate)).await,
+        Some(cargo)
+    );
+    assert_eq!(
+        choose(&harness, AudioPlayback::Refused).await,
+        Some(license)
+    );
+    let generic = choose(&harness, AudioPlayback::Bought(cola)).await;
+    assert!(generic == Some(cargo) || generic == Some(readme));
+
+    Sound::set_assignments(&harness.db, cargo, &[], &[cola])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        choose(&harness, AudioPlayback::Bought(cola)).await,
+        Some(cargo)
+    );
+    assert_eq!(
+        choose(&harness, AudioPlayback::Bought(mate)).await,
+        Some(readme)
+    );
+
+    Sound::delete(&harness.db, license).await.unwrap();
+
+    assert_eq!(choose(&harness, AudioPlayback::Failed).await, None);
+}
+
+#[tokio::test]
+async fn own_sounds_of_a_user_come_first() {
+    let harness = Harness::new().await;
+    let alice = harness.user("alice").await;
+    let mate = harness.article("Mate", 150).await;
+
+    seed_sounds(&harness.db, &sound_settings()).await.unwrap();
+
+    let sounds = Sound::get_all(&harness.db).await.unwrap();
+    let cargo = id_of(&sounds, "Cargo.toml");
+    let license = id_of(&sounds, "LICENSE");
+
+    let mut user = harness.get_user(alice).await;
+    let mut conn = harness.db.get_conn().await.unwrap();
+    user.set_sound_ids(&mut *conn, Some(license), None)
+        .await
+        .unwrap();
+    drop(conn);
+
+    let chosen = Sound::choose_for(&harness.db, AudioPlayback::Bought(mate), Some(alice))
+        .await
+        .unwrap();
+    assert_eq!(chosen, Some(license));
+
+    // other users and events keep the assigned sounds
+    assert_eq!(
+        choose(&harness, AudioPlayback::Bought(mate)).await,
+        Some(cargo)
+    );
+
+    Sound::delete(&harness.db, license).await.unwrap();
+
+    assert_eq!(harness.get_user(alice).await.purchase_sound_id, None);
+    let chosen = Sound::choose_for(&harness.db, AudioPlayback::Bought(mate), Some(alice))
+        .await
+        .unwrap();
+    assert_eq!(chosen, Some(cargo));
+}
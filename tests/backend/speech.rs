@@ -0,0 +1,100 @@
+use strichliste_rs::{
+    backend::core::{
+        misc::{spoken_money, SpeechValues},
+        Sound, SpeechEngine, SpeechSettings, SpeechTemplates,
+    },
+    model::{AudioPlayback, Currency, Money},
+};
+
+use crate::harness::Harness;
+
+fn speech_settings() -> SpeechSettings {
+    SpeechSettings {
+        engine: SpeechEngine::Browser,
+        always: false,
+        command: String::from("espeak-ng"),
+        args: Vec::new(),
+        templates: SpeechTemplates::default(),
+    }
+}
+
+#[test]
+fn money_is_spoken_without_needless_digits() {
+    let currency = Currency::default();
+
+    assert_eq!(spoken_money(Money::from(-300), &currency), "minus 3€");
+    assert_eq!(spoken_money(Money::from(-350), &currency), "minus 3.50€");
+    assert_eq!(spoken_money(Money::from(0), &currency), "0€");
+    assert_eq!(spoken_money(Money::from(5), &currency), "0.05€");
+}
+
+#[test]
+fn missing_values_are_left_empty() {
+    let values = SpeechValues {
+        user: Some(String::from("Alice")),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        values.render("{user} bought {article}", &Currency::default()),
+        "Alice bought "
+    );
+}
+
+#[tokio::test]
+async fn templates_are_filled_with_the_user_and_article() {
+    let mut harness = Harness::new().await;
+    let alice = harness.user("Alice").await;
+    let mate = harness.article("Club Mate", 300).await;
+
+    harness.buy(alice, mate, 300).await.unwrap();
+
+    let speech = Sound::speech_for(
+        &harness.db,
+        &harness.settings,
+        AudioPlayback::Bought(mate),
+        Some(alice),
+    )
+    .await
+    .unwrap();
+    assert_eq!(speech, None);
+
+    harness.settings.speech = Some(speech_settings());
+
+    let speech = Sound::speech_for(
+        &harness.db,
+        &harness.settings,
+        AudioPlayback::Bought(mate),
+        Some(alice),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        speech.as_deref(),
+        Some("Alice bought Club Mate, balance minus 3€")
+    );
+
+    let speech = Sound::speech_for(
+        &harness.db,
+        &harness.settings,
+        AudioPlayback::Deposit(Money::from(1000)),
+        Some(alice),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        speech.as_deref(),
+        Some("Alice deposited 10€, balance minus 3€")
+    );
+
+    // empty templates are not spoken
+    let speech = Sound::speech_for(
+        &harness.db,
+        &harness.settings,
+        AudioPlayback::Failed,
+        Some(alice),
+    )
+    .await
+    .unwrap();
+    assert_eq!(speech, None);
+}
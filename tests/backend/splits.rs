@@ -0,0 +1,88 @@
+use strichliste_rs::model::TransactionType;
+
+use crate::harness::Harness;
+
+#[tokio::test]
+async fn group_transfers_add_up_to_the_amount() {
+    let harness = Harness::new().await;
+    let alice = harness.user("alice").await;
+    let bob = harness.user("bob").await;
+    let carol = harness.user("carol").await;
+    let dave = harness.user("dave").await;
+
+    let senders = harness.group(&[alice, bob, carol]).await;
+    let receiver = harness.group(&[dave]).await;
+
+    harness
+        .create(senders, receiver, TransactionType::Sent(receiver), 100)
+        .await
+        .unwrap();
+
+    let mut balances = vec![
+        harness.balance(alice).await,
+        harness.balance(bob).await,
+        harness.balance(carol).await,
+    ];
+    balances.sort();
+
+    assert_eq!(balances, vec![-34, -33, -33]);
+    assert_eq!(harness.balance(dave).await, 100);
+}
+
+#[tokio::test]
+async fn group_receivers_share_the_amount() {
+    let harness = Harness::new().await;
+    let alice = harness.user("alice").await;
+    let bob = harness.user("bob").await;
+    let carol = harness.user("carol").await;
+
+    let sender = harness.group(&[alice]).await;
+    let receivers = harness.group(&[bob, carol]).await;
+
+    harness
+        .create(sender, receivers, TransactionType::Sent(receivers), 101)
+        .await
+        .unwrap();
+
+    assert_eq!(harness.balance(alice).await, -101);
+    assert_eq!(
+        harness.balance(bob).await + harness.balance(carol).await,
+        101
+    );
+    assert!((harness.balance(bob).await - harness.balance(carol).await).abs() <= 1);
+}
+
+#[tokio::test]
+async fn split_costs_are_shared_evenly() {
+    let harness = Harness::new().await;
+    let alice = harness.user("alice").await;
+    let bob = harness.user("bob").await;
+    let carol = harness.user("carol").await;
+
+    // alice payed 1.00 for all three of them
+    harness.split(alice, &[bob, carol], 100).await.unwrap();
+
+    let (alice, bob, carol) = (
+        harness.balance(alice).await,
+        harness.balance(bob).await,
+        harness.balance(carol).await,
+    );
+
+    assert_eq!(alice + bob + carol, 0);
+    assert_eq!(alice, 67);
+    assert_eq!(bob.min(carol), -34);
+    assert_eq!(bob.max(carol), -33);
+}
+
+#[tokio::test]
+async fn split_costs_respect_the_limits() {
+    let harness = Harness::new().await;
+    let alice = harness.user("alice").await;
+    let bob = harness.user("bob").await;
+
+    harness.withdraw(bob, 5000).await.unwrap();
+
+    assert!(harness.split(alice, &[bob], 200).await.is_err());
+    assert_eq!(harness.balance(alice).await, 0);
+    assert_eq!(harness.balance(bob).await, -5000);
+}
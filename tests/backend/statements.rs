@@ -0,0 +1,61 @@
+use chrono::{Duration, Utc};
+use strichliste_rs::{backend::core::Statement, model::StatementMonth};
+
+use crate::harness::Harness;
+
+fn counted(statement: &Statement) -> i64 {
+    statement
+        .entries
+        .iter()
+        .filter(|entry| !entry.is_undone)
+        .map(|entry| entry.delta)
+        .sum()
+}
+
+#[tokio::test]
+async fn undos_in_a_later_month_keep_the_statements_consistent() {
+    let harness = Harness::new().await;
+    let alice = harness.user("alice").await;
+    let article = harness.article("Mate", 150).await;
+
+    let this_month = StatementMonth::of(Utc::now());
+    let last_month = this_month.previous();
+    let (from, _) = last_month.range().unwrap();
+
+    let deposit = harness.deposit(alice, 1000).await.unwrap();
+    let purchase = harness.buy(alice, article, 150).await.unwrap();
+
+    harness
+        .set_timestamp(deposit, from + Duration::days(1))
+        .await;
+    harness
+        .set_timestamp(purchase, from + Duration::days(2))
+        .await;
+
+    // undone this month, so it still counts for last month
+    harness.undo(purchase).await;
+
+    let last = Statement::get(&harness.db, alice, last_month)
+        .await
+        .unwrap()
+        .expect("alice should have a statement");
+
+    assert_eq!(last.opening_balance, 0);
+    assert_eq!(last.closing_balance, 850);
+    assert_eq!(last.opening_balance + counted(&last), last.closing_balance);
+
+    let current = Statement::get(&harness.db, alice, this_month)
+        .await
+        .unwrap()
+        .expect("alice should have a statement");
+
+    assert_eq!(current.opening_balance, last.closing_balance);
+    assert_eq!(current.closing_balance, 1000);
+    assert_eq!(current.entries.len(), 1);
+    assert_eq!(current.entries[0].transaction_id, purchase);
+    assert_eq!(current.entries[0].delta, 150);
+    assert_eq!(
+        current.opening_balance + counted(&current),
+        current.closing_balance
+    );
+}
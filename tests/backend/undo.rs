@@ -0,0 +1,97 @@
+use chrono::Utc;
+use strichliste_rs::{backend::core::ExportBalance, model::TransactionType};
+
+use crate::harness::Harness;
+
+#[tokio::test]
+async fn undoing_a_purchase_restores_the_balance() {
+    let harness = Harness::new().await;
+    let alice = harness.user("alice").await;
+    let article = harness.article("Mate", 150).await;
+
+    harness.deposit(alice, 1000).await.unwrap();
+    let purchase = harness.buy(alice, article, 150).await.unwrap();
+
+    assert_eq!(harness.balance(alice).await, 850);
+
+    harness.undo(purchase).await;
+
+    assert_eq!(harness.balance(alice).await, 1000);
+    assert!(harness.is_undone(purchase).await);
+}
+
+#[tokio::test]
+async fn undoing_a_group_transfer_restores_every_balance() {
+    let harness = Harness::new().await;
+    let alice = harness.user("alice").await;
+    let bob = harness.user("bob").await;
+    let carol = harness.user("carol").await;
+    let dave = harness.user("dave").await;
+
+    harness.deposit(alice, 7).await.unwrap();
+    harness.deposit(bob, 11).await.unwrap();
+
+    let senders = harness.group(&[alice, bob, carol]).await;
+    let receivers = harness.group(&[carol, dave]).await;
+
+    let transfer = harness
+        .create(senders, receivers, TransactionType::Sent(receivers), 100)
+        .await
+        .unwrap();
+
+    harness.undo(transfer).await;
+
+    assert_eq!(harness.balance(alice).await, 7);
+    assert_eq!(harness.balance(bob).await, 11);
+    assert_eq!(harness.balance(carol).await, 0);
+    assert_eq!(harness.balance(dave).await, 0);
+}
+
+#[tokio::test]
+async fn undoing_a_split_restores_every_balance() {
+    let harness = Harness::new().await;
+    let alice = harness.user("alice").await;
+    let bob = harness.user("bob").await;
+    let carol = harness.user("carol").await;
+
+    let split = harness.split(alice, &[bob, carol], 1000).await.unwrap();
+
+    harness.undo(split).await;
+
+    assert_eq!(harness.balance(alice).await, 0);
+    assert_eq!(harness.balance(bob).await, 0);
+    assert_eq!(harness.balance(carol).await, 0);
+}
+
+#[tokio::test]
+async fn balances_before_an_undo_still_contain_the_transaction() {
+    let harness = Harness::new().await;
+    let alice = harness.user("alice").await;
+    let article = harness.article("Mate", 150).await;
+
+    harness.deposit(alice, 1000).await.unwrap();
+    let purchase = harness.buy(alice, article, 150).await.unwrap();
+
+    let before_undo = Utc::now();
+    harness.undo(purchase).await;
+
+    let balances = ExportBalance::get_at(&harness.db, before_undo)
+        .await
+        .unwrap();
+    let balance = balances
+        .iter()
+        .find(|balance| balance.user_id == alice.0)
+        .expect("alice should have a balance");
+
+    assert_eq!(balance.balance, 850);
+
+    let balances = ExportBalance::get_at(&harness.db, Utc::now())
+        .await
+        .unwrap();
+    let balance = balances
+        .iter()
+        .find(|balance| balance.user_id == alice.0)
+        .expect("alice should have a balance");
+
+    assert_eq!(balance.balance, 1000);
+}
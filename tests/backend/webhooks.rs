@@ -0,0 +1,177 @@
+use std::sync::{Arc, Mutex};
+
+use arc_swap::ArcSwap;
+use axum::{extract, http::HeaderMap, http::StatusCode, routing::post, Router};
+use chrono::{Duration, Utc};
+use strichliste_rs::{
+    backend::{
+        core::{
+            misc::{deliver_webhooks, sign_webhook_payload, webhook_backoff},
+            EventBus, PinSessions, ServerState, State, WebhookEvent, WebhookSettings,
+        },
+        database::{WebhookDeliveryDB, DB, WEBHOOK_STATUS_PENDING},
+    },
+    model::DatabaseId,
+};
+
+use crate::harness::settings;
+
+const SECRET: &str = "secret";
+
+const PAYLOAD: &str = r#"{"event":"deposit"}"#;
+
+/// A local http stand-in that records every request
+#[derive(Clone, Default)]
+struct StandIn {
+    requests: Arc<Mutex<Vec<(HeaderMap, String)>>>,
+    /// answered to the next requests from the back, 204 once they are used up
+    statuses: Arc<Mutex<Vec<u16>>>,
+}
+
+async fn receive(
+    extract::State(stand_in): extract::State<StandIn>,
+    headers: HeaderMap,
+    body: String,
+) -> StatusCode {
+    stand_in.requests.lock().unwrap().push((headers, body));
+
+    let status = stand_in.statuses.lock().unwrap().pop().unwrap_or(204);
+    StatusCode::from_u16(status).unwrap()
+}
+
+/// Starts the stand-in and returns its url
+async fn serve(stand_in: StandIn) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+
+    let app = Router::new()
+        .route("/hook", post(receive))
+        .with_state(stand_in);
+
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    url
+}
+
+async fn state(url: &str) -> ServerState {
+    let mut settings = settings(-5000, Some(10000));
+    settings.webhooks = vec![WebhookSettings {
+        url: url.to_string(),
+        secret: SECRET.to_string(),
+        events: vec![WebhookEvent::Deposit],
+    }];
+
+    Arc::new(State {
+        db: tokio::sync::Mutex::new(DB::new_in_memory().await.unwrap()),
+        settings: ArcSwap::from_pointee(settings),
+        events: EventBus::new(),
+        pin_sessions: PinSessions::default(),
+    })
+}
+
+async fn queue(state: &ServerState, url: &str) -> DatabaseId {
+    let db = state.db.lock().await;
+    let mut conn = db.get_conn().await.unwrap();
+
+    WebhookDeliveryDB::create(&mut *conn, url, "deposit", PAYLOAD)
+        .await
+        .unwrap()
+}
+
+async fn due(state: &ServerState, at: chrono::DateTime<Utc>) -> Vec<WebhookDeliveryDB> {
+    let db = state.db.lock().await;
+    let mut conn = db.get_conn().await.unwrap();
+
+    WebhookDeliveryDB::get_due(&mut *conn, at, 50)
+        .await
+        .unwrap()
+}
+
+#[test]
+fn signatures_are_the_hex_hmac_of_the_payload() {
+    // test case 2 of RFC 4231
+    assert_eq!(
+        sign_webhook_payload("Jefe", "what do ya want for nothing?"),
+        "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+    );
+}
+
+#[test]
+fn the_backoff_doubles_until_it_is_capped() {
+    assert_eq!(webhook_backoff(1), Duration::seconds(10));
+    assert_eq!(webhook_backoff(2), Duration::seconds(20));
+    assert_eq!(webhook_backoff(3), Duration::seconds(40));
+    assert_eq!(webhook_backoff(11), Duration::seconds(10240));
+    assert_eq!(webhook_backoff(20), Duration::seconds(10240));
+}
+
+#[tokio::test]
+async fn deliveries_are_signed_and_marked_as_delivered() {
+    let stand_in = StandIn::default();
+    let url = serve(stand_in.clone()).await;
+    let state = state(&url).await;
+
+    let delivery_id = queue(&state, &url).await;
+
+    let client = reqwest::Client::new();
+    assert_eq!(deliver_webhooks(&state, &client).await, Ok(1));
+
+    let requests = stand_in.requests.lock().unwrap().clone();
+    assert_eq!(requests.len(), 1);
+
+    let (headers, body) = &requests[0];
+    assert_eq!(body, PAYLOAD);
+    assert_eq!(headers["X-Strichliste-Event"], "deposit");
+    assert_eq!(
+        headers["X-Strichliste-Delivery"],
+        delivery_id.to_string().as_str()
+    );
+    assert_eq!(
+        headers["X-Strichliste-Signature"],
+        format!("sha256={}", sign_webhook_payload(SECRET, PAYLOAD)).as_str()
+    );
+
+    assert!(due(&state, Utc::now() + Duration::days(1)).await.is_empty());
+}
+
+#[tokio::test]
+async fn failed_deliveries_are_retried_after_the_backoff() {
+    let stand_in = StandIn::default();
+    stand_in.statuses.lock().unwrap().push(500);
+    let url = serve(stand_in.clone()).await;
+    let state = state(&url).await;
+
+    queue(&state, &url).await;
+
+    let client = reqwest::Client::new();
+    assert_eq!(deliver_webhooks(&state, &client).await, Ok(0));
+
+    // not due again before the backoff has passed
+    assert!(due(&state, Utc::now()).await.is_empty());
+
+    let mut retries = due(
+        &state,
+        Utc::now() + webhook_backoff(1) + Duration::seconds(1),
+    )
+    .await;
+    assert_eq!(retries.len(), 1);
+
+    let mut retry = retries.remove(0);
+    assert_eq!(retry.status, WEBHOOK_STATUS_PENDING);
+    assert_eq!(retry.attempts, 1);
+    assert_eq!(retry.response_status, Some(500));
+    assert!(retry.last_error.is_some());
+
+    // skip the wait
+    retry.next_attempt = Utc::now() - Duration::seconds(1);
+    {
+        let db = state.db.lock().await;
+        let mut conn = db.get_conn().await.unwrap();
+        retry.set_result(&mut *conn).await.unwrap();
+    }
+
+    assert_eq!(deliver_webhooks(&state, &client).await, Ok(1));
+    assert_eq!(stand_in.requests.lock().unwrap().len(), 2);
+
+    assert!(due(&state, Utc::now() + Duration::days(1)).await.is_empty());
+}